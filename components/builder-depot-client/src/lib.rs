@@ -29,22 +29,24 @@ extern crate serde;
 extern crate serde_derive;
 extern crate serde_json;
 extern crate tee;
+#[cfg(test)]
+extern crate tempdir;
 extern crate url;
 
 pub mod error;
 pub use error::{Error, Result};
 
-use std::fs::{self, File};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::string::ToString;
 
 use broadcast::BroadcastWriter;
-use hab_core::package::{Identifiable, PackageArchive};
+use hab_core::package::{Identifiable, PackageArchive, PackageTarget};
 use hab_http::ApiClient;
 use hyper::client::{Body, IntoUrl, Response, RequestBuilder};
 use hyper::status::StatusCode;
-use hyper::header::{Authorization, Bearer};
+use hyper::header::{Authorization, Bearer, ByteRangeSpec, Range};
 use hyper::Url;
 use protobuf::core::ProtobufEnum;
 use protocol::{originsrv, net};
@@ -156,6 +158,9 @@ pub trait DisplayProgress: Write {
 
 pub struct Client {
     inner: ApiClient,
+    /// The target packages are shown and downloaded for, when it isn't that of the running
+    /// system.
+    target: Option<PackageTarget>,
 }
 
 impl Client {
@@ -165,7 +170,21 @@ impl Client {
                            fs_root_path: Option<&Path>)
                            -> Result<Self> {
         let url = try!(hab_depot_url.into_url());
-        Ok(Client { inner: try!(ApiClient::new(&url, product, version, fs_root_path)) })
+        Ok(Client {
+               inner: try!(ApiClient::new(&url, product, version, fs_root_path)),
+               target: None,
+           })
+    }
+
+    /// Shows and downloads packages built for the given target rather than for the target of the
+    /// running system, which the Depot otherwise infers from the `User-Agent` header.
+    pub fn set_target(&mut self, target: PackageTarget) {
+        self.target = Some(target);
+    }
+
+    /// Returns the target set with `set_target`, if any.
+    pub fn target(&self) -> Option<&PackageTarget> {
+        self.target.as_ref()
     }
 
     /// Download a public key from a remote Depot to the given filepath.
@@ -333,6 +352,10 @@ impl Client {
     /// the latest release of a given version. Specifying both a version and a release will
     /// retrieve that exact package.
     ///
+    /// The download of an exact package is written to a partial file in `dst_path` first. If the
+    /// download is interrupted, the partial file is kept and the next download of the package
+    /// resumes from where it stopped.
+    ///
     /// # Failures
     ///
     /// * Package cannot be found
@@ -347,9 +370,19 @@ impl Client {
               I: Identifiable,
               D: DisplayProgress + Sized
     {
-        match self.download(&format!("pkgs/{}/download", ident),
-                            dst_path.as_ref(),
-                            progress) {
+        let path = format!("pkgs/{}/download", ident);
+        let result = if ident.fully_qualified() {
+            let partial_name = format!("{}-{}-{}-{}-{}.part",
+                                       ident.origin(),
+                                       ident.name(),
+                                       ident.version().unwrap(),
+                                       ident.release().unwrap(),
+                                       self.target.clone().unwrap_or_default());
+            self.download_resumable(&path, dst_path.as_ref(), &partial_name, progress)
+        } else {
+            self.download(&path, dst_path.as_ref(), progress)
+        };
+        match result {
             Ok(file) => Ok(PackageArchive::new(PathBuf::from(file))),
             Err(e) => Err(e),
         }
@@ -358,14 +391,18 @@ impl Client {
     /// Returns a package struct for the latest package.
     ///
     /// An optional version can be specified which will scope the release returned to the latest
-    /// release of that package.
+    /// release of that package. When a channel is given, only packages promoted to that channel
+    /// are considered.
     ///
     /// # Failures
     ///
     /// * Package cannot be found
     /// * Remote Depot is not available
-    pub fn show_package<I: Identifiable>(&self, ident: &I) -> Result<originsrv::OriginPackage> {
-        let mut res = try!(self.inner.get(&self.path_show_package(ident)).send());
+    pub fn show_package<I: Identifiable>(&self,
+                                         ident: &I,
+                                         channel: Option<&str>)
+                                         -> Result<originsrv::OriginPackage> {
+        let mut res = try!(self.get_for_target(&self.path_show_package(ident, channel)).send());
 
         if res.status != StatusCode::Ok {
             return Err(err_from_response(res));
@@ -508,18 +545,41 @@ impl Client {
         rb.header(Authorization(Bearer { token: token.to_string() }))
     }
 
-    fn path_show_package<I: Identifiable>(&self, package: &I) -> String {
+    fn path_show_package<I: Identifiable>(&self, package: &I, channel: Option<&str>) -> String {
+        let path = match channel {
+            Some(channel) => {
+                let mut path =
+                    format!("channels/{}/{}/pkgs/{}", package.origin(), channel, package.name());
+                if let Some(version) = package.version() {
+                    path.push_str(&format!("/{}", version));
+                    if let Some(release) = package.release() {
+                        path.push_str(&format!("/{}", release));
+                    }
+                }
+                path
+            }
+            None => format!("pkgs/{}", package),
+        };
         if package.fully_qualified() {
-            format!("pkgs/{}", package)
+            path
         } else {
-            format!("pkgs/{}/latest", package)
+            format!("{}/latest", path)
         }
     }
 
+    /// Builds a GET request which asks for the client's target, if it has one.
+    fn get_for_target(&self, path: &str) -> RequestBuilder {
+        self.inner
+            .get_with_custom_url(path, |url| if let Some(ref target) = self.target {
+                url.query_pairs_mut()
+                    .append_pair("target", &target.to_string());
+            })
+    }
+
     fn download<D>(&self, path: &str, dst_path: &Path, progress: Option<D>) -> Result<PathBuf>
         where D: DisplayProgress + Sized
     {
-        let mut res = try!(self.inner.get(path).send());
+        let mut res = try!(self.get_for_target(path).send());
         debug!("Response: {:?}", res);
 
         if res.status != hyper::status::StatusCode::Ok {
@@ -557,6 +617,70 @@ impl Client {
         try!(fs::rename(&tmp_file_path, &dst_file_path));
         Ok(dst_file_path)
     }
+
+    /// Downloads a file like `download`, but through a partial file with the given name which is
+    /// kept when the download is interrupted. The next download through the same partial file
+    /// asks only for the part of the file it is missing.
+    fn download_resumable<D>(&self,
+                             path: &str,
+                             dst_path: &Path,
+                             partial_name: &str,
+                             progress: Option<D>)
+                             -> Result<PathBuf>
+        where D: DisplayProgress + Sized
+    {
+        try!(fs::create_dir_all(&dst_path));
+        let partial_path = dst_path.join(partial_name);
+        let offset = fs::metadata(&partial_path).map(|m| m.len()).unwrap_or(0);
+        let mut req = self.get_for_target(path);
+        if offset > 0 {
+            req = req.header(Range::Bytes(vec![ByteRangeSpec::AllFrom(offset)]));
+        }
+        let mut res = try!(req.send());
+        debug!("Response: {:?}", res);
+
+        let mut f = match res.status {
+            StatusCode::PartialContent => {
+                debug!("Resuming download of {} from byte {}",
+                       &partial_path.display(),
+                       offset);
+                try!(OpenOptions::new().append(true).open(&partial_path))
+            }
+            // The depot sends the whole file when it doesn't support ranges.
+            StatusCode::Ok => try!(File::create(&partial_path)),
+            StatusCode::RangeNotSatisfiable => {
+                // The partial file is no shorter than the file itself, so it can't be a part of
+                // it; start over.
+                debug!("Discarding {}, which can't be resumed",
+                       &partial_path.display());
+                try!(fs::remove_file(&partial_path));
+                return self.download_resumable(path, dst_path, partial_name, progress);
+            }
+            _ => return Err(err_from_response(res)),
+        };
+        let file_name = match res.headers.get::<XFileName>() {
+            Some(filename) => format!("{}", filename),
+            None => return Err(Error::NoXFilename),
+        };
+        let dst_file_path = dst_path.join(file_name);
+        debug!("Writing to {}", &partial_path.display());
+        match progress {
+            Some(mut progress) => {
+                let size: u64 = res.headers
+                    .get::<hyper::header::ContentLength>()
+                    .map_or(0, |v| **v);
+                progress.size(size);
+                let mut writer = BroadcastWriter::new(&mut f, progress);
+                try!(io::copy(&mut res, &mut writer))
+            }
+            None => try!(io::copy(&mut res, &mut f)),
+        };
+        debug!("Moving {} to {}",
+               &partial_path.display(),
+               &dst_file_path.display());
+        try!(fs::rename(&partial_path, &dst_file_path));
+        Ok(dst_file_path)
+    }
 }
 
 fn err_from_response(mut response: hyper::client::Response) -> Error {
@@ -574,3 +698,136 @@ fn err_from_response(mut response: hyper::client::Response) -> Error {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::{self, Read, Write};
+    use std::mem;
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+
+    use hab_core::package::{PackageIdent, PackageTarget};
+    use hyper::header::{ByteRangeSpec, Range};
+    use hyper::server::{Request, Response, Server};
+    use hyper::status::StatusCode;
+    use tempdir::TempDir;
+
+    use super::{Client, DisplayProgress, XFileName};
+
+    const IDENT: &'static str = "core/foo/1.0.0/20170101000000";
+    const FILE_NAME: &'static str = "core-foo-1.0.0-20170101000000-x86_64-linux.hart";
+
+    struct NoProgress;
+
+    impl Write for NoProgress {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl DisplayProgress for NoProgress {
+        fn size(&mut self, _size: u64) {}
+        fn finish(&mut self) {}
+    }
+
+    /// Starts a depot which serves `artifact` for every request, honouring `Range` headers like
+    /// the real depot when `ranges` is true. Returns its URL along with the `Range` header of
+    /// each request it received.
+    fn fake_depot(artifact: &'static [u8],
+                  ranges: bool)
+                  -> (String, Arc<Mutex<Vec<Option<String>>>>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        let listening = Server::http("127.0.0.1:0")
+            .unwrap()
+            .handle(move |req: Request, mut res: Response| {
+                let range = req.headers.get::<Range>().cloned();
+                seen.lock().unwrap().push(range.as_ref().map(|r| r.to_string()));
+                let start = match range {
+                    Some(Range::Bytes(ref specs)) if ranges => {
+                        match specs[0] {
+                            ByteRangeSpec::AllFrom(start) => Some(start as usize),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                res.headers_mut().set(XFileName(FILE_NAME.to_string()));
+                match start {
+                    Some(start) if start >= artifact.len() => {
+                        *res.status_mut() = StatusCode::RangeNotSatisfiable;
+                        res.send(b"").unwrap();
+                    }
+                    Some(start) => {
+                        *res.status_mut() = StatusCode::PartialContent;
+                        res.send(&artifact[start..]).unwrap();
+                    }
+                    None => res.send(artifact).unwrap(),
+                }
+            })
+            .unwrap();
+        let url = format!("http://{}/v1/depot", listening.socket);
+        // Dropping the listener joins the server thread, which never returns.
+        mem::forget(listening);
+        (url, requests)
+    }
+
+    fn partial_path(dst: &Path) -> PathBuf {
+        dst.join(format!("core-foo-1.0.0-20170101000000-{}.part",
+                         PackageTarget::default()))
+    }
+
+    fn fetch(url: &str, dst: &Path) -> Vec<u8> {
+        let client = Client::new(url, "hab", "0.0.0", None).unwrap();
+        let ident = PackageIdent::from_str(IDENT).unwrap();
+        let archive = client.fetch_package(&ident, dst, None::<NoProgress>).unwrap();
+        let mut content = Vec::new();
+        File::open(&archive.path).unwrap().read_to_end(&mut content).unwrap();
+        content
+    }
+
+    #[test]
+    fn fetch_package_resumes_partial_download() {
+        let artifact = b"HART-1\nthe rest of the artifact";
+        let (url, requests) = fake_depot(artifact, true);
+        let tmp = TempDir::new("depot-client").unwrap();
+        File::create(partial_path(tmp.path())).unwrap().write_all(&artifact[..5]).unwrap();
+
+        assert_eq!(fetch(&url, tmp.path()), artifact.to_vec());
+        assert_eq!(*requests.lock().unwrap(), vec![Some("bytes=5-".to_string())]);
+        assert!(!partial_path(tmp.path()).exists());
+        assert!(tmp.path().join(FILE_NAME).is_file());
+    }
+
+    #[test]
+    fn fetch_package_starts_over_when_depot_sends_whole_artifact() {
+        let artifact = b"HART-1\nthe rest of the artifact";
+        let (url, requests) = fake_depot(artifact, false);
+        let tmp = TempDir::new("depot-client").unwrap();
+        File::create(partial_path(tmp.path())).unwrap().write_all(b"stale").unwrap();
+
+        assert_eq!(fetch(&url, tmp.path()), artifact.to_vec());
+        assert_eq!(*requests.lock().unwrap(), vec![Some("bytes=5-".to_string())]);
+    }
+
+    #[test]
+    fn fetch_package_discards_partial_download_which_cannot_be_resumed() {
+        let artifact = b"HART-1\nthe rest of the artifact";
+        let (url, requests) = fake_depot(artifact, true);
+        let tmp = TempDir::new("depot-client").unwrap();
+        let mut partial = artifact.to_vec();
+        partial.extend_from_slice(b"and more");
+        File::create(partial_path(tmp.path())).unwrap().write_all(&partial).unwrap();
+
+        assert_eq!(fetch(&url, tmp.path()), artifact.to_vec());
+        assert_eq!(*requests.lock().unwrap(),
+                   vec![Some(format!("bytes={}-", partial.len())), None]);
+        assert!(fs::metadata(partial_path(tmp.path())).is_err());
+    }
+}
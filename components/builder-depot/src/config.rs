@@ -61,6 +61,8 @@ impl Default for Config {
             builds_enabled: false,
             log_dir: env::temp_dir().to_string_lossy().into_owned(),
             targets: vec![PackageTarget::new(Platform::Linux, Architecture::X86_64),
+                          PackageTarget::new(Platform::Linux, Architecture::Aarch64),
+                          PackageTarget::new(Platform::Linux, Architecture::Armv7),
                           PackageTarget::new(Platform::Windows, Architecture::X86_64)],
         }
     }
@@ -115,6 +117,8 @@ impl ToSocketAddrs for HttpCfg {
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use super::*;
 
     #[test]
@@ -178,5 +182,10 @@ mod tests {
 
         let config = Config::from_raw(&content).unwrap();
         assert_eq!(config.http.port, 9000);
+        assert_eq!(config.targets,
+                   vec![PackageTarget::from_str("x86_64-linux").unwrap(),
+                        PackageTarget::from_str("aarch64-linux").unwrap(),
+                        PackageTarget::from_str("armv7-linux").unwrap(),
+                        PackageTarget::from_str("x86_64-windows").unwrap()]);
     }
 }
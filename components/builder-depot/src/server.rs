@@ -13,10 +13,11 @@
 // limitations under the License.
 
 use std::any::TypeId;
+use std::cmp;
 use std::collections::HashMap;
 use std::fs::{self, File};
-use std::path::PathBuf;
-use std::io::{Read, Write, BufWriter};
+use std::path::{Path, PathBuf};
+use std::io::{self, Read, Seek, SeekFrom, Write, BufWriter};
 use std::result;
 use std::str::FromStr;
 
@@ -31,13 +32,16 @@ use hab_net::http::controller::*;
 use hab_net::privilege;
 use hab_net::routing::{Broker, RouteResult};
 use hab_net::server::NetIdent;
-use hyper::header::{Charset, ContentDisposition, DispositionType, DispositionParam};
+use hyper::header::{AcceptRanges, ByteRangeSpec, Charset, ContentDisposition, ContentLength,
+                    ContentRange, ContentRangeSpec, DispositionType, DispositionParam, Range,
+                    RangeUnit};
 use hyper::mime::{Mime, TopLevel, SubLevel, Attr, Value};
 use iron::{status, headers, typemap};
 use iron::headers::{ContentType, UserAgent};
 use iron::middleware::BeforeMiddleware;
 use iron::prelude::*;
 use iron::request::Body;
+use iron::response::{BodyReader, WriteBody};
 use iron::typemap::Key;
 use mount::Mount;
 use persistent;
@@ -946,7 +950,10 @@ fn download_package(req: &mut Request) -> IronResult<Response> {
         let params = req.extensions.get::<Router>().unwrap();
         ident_req.set_ident(ident_from_params(params));
     };
-    let agent_target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
+    let agent_target = match target_from_req(req) {
+        Ok(target) => target,
+        Err(response) => return Ok(response),
+    };
     if !depot.config.targets.contains(&agent_target) {
         error!("Unsupported client platform ({}) for this depot.",
                agent_target);
@@ -957,8 +964,37 @@ fn download_package(req: &mut Request) -> IronResult<Response> {
         Ok(package) => {
             if let Some(archive) = depot.archive(package.get_ident(), &agent_target) {
                 match fs::metadata(&archive.path) {
-                    Ok(_) => {
-                        let mut response = Response::with((status::Ok, archive.path.clone()));
+                    Ok(metadata) => {
+                        let len = metadata.len();
+                        let mut response = match ArchiveRange::from_req(req, len) {
+                            ArchiveRange::Whole => {
+                                let mut response = Response::with((status::Ok,
+                                                                   archive.path.clone()));
+                                response
+                                    .headers
+                                    .set(AcceptRanges(vec![RangeUnit::Bytes]));
+                                response
+                            }
+                            ArchiveRange::Part(start, end) => {
+                                match archive_part_response(&archive.path, start, end, len) {
+                                    Ok(response) => response,
+                                    Err(err) => {
+                                        error!("download_package:2, err={:?}", err);
+                                        return Ok(Response::with(status::InternalServerError));
+                                    }
+                                }
+                            }
+                            ArchiveRange::Unsatisfiable => {
+                                let mut response = Response::with(status::RangeNotSatisfiable);
+                                response
+                                    .headers
+                                    .set(ContentRange(ContentRangeSpec::Bytes {
+                                                          range: None,
+                                                          instance_length: Some(len),
+                                                      }));
+                                return Ok(response);
+                            }
+                        };
                         do_cache_response(&mut response);
                         let disp = ContentDisposition {
                             disposition: DispositionType::Attachment,
@@ -1330,9 +1366,10 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
 
     if let Some(channel) = channel {
         if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
+            let target = match target_from_req(req) {
+                Ok(target) => target.to_string(),
+                Err(response) => return Ok(response),
+            };
             let mut request = OriginChannelPackageLatestGet::new();
             request.set_name(channel.clone());
             request.set_ident(ident);
@@ -1369,9 +1406,10 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
         }
     } else {
         if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
+            let target = match target_from_req(req) {
+                Ok(target) => target.to_string(),
+                Err(response) => return Ok(response),
+            };
             let mut request = OriginPackageLatestGet::new();
             request.set_ident(ident);
             request.set_target(target);
@@ -1589,15 +1627,85 @@ fn ident_from_params(params: &Params) -> OriginPackageIdent {
     ident
 }
 
+/// The part of a package archive requested for download through a `Range` header.
+#[derive(Debug, PartialEq)]
+enum ArchiveRange {
+    Whole,
+    /// The first and last byte of the part.
+    Part(u64, u64),
+    Unsatisfiable,
+}
+
+impl ArchiveRange {
+    /// Returns the part of an archive of the given length requested by the request. Only
+    /// requests for a single range of bytes are honoured; the whole archive is sent for any
+    /// other request.
+    fn from_req(req: &Request, len: u64) -> Self {
+        let spec = match req.headers.get::<Range>() {
+            Some(&Range::Bytes(ref specs)) if specs.len() == 1 => specs[0].clone(),
+            _ => return ArchiveRange::Whole,
+        };
+        if len == 0 {
+            return ArchiveRange::Unsatisfiable;
+        }
+        let (start, end) = match spec {
+            ByteRangeSpec::FromTo(start, end) => (start, cmp::min(end, len - 1)),
+            ByteRangeSpec::AllFrom(start) => (start, len - 1),
+            ByteRangeSpec::Last(count) => (len.saturating_sub(count), len - 1),
+        };
+        if start > end {
+            ArchiveRange::Unsatisfiable
+        } else {
+            ArchiveRange::Part(start, end)
+        }
+    }
+}
+
+/// Returns a response sending the bytes of an archive from `start` to `end`, inclusive.
+fn archive_part_response(path: &Path, start: u64, end: u64, len: u64) -> io::Result<Response> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(start))?;
+    let body: Box<WriteBody> = Box::new(BodyReader(file.take(end - start + 1)));
+    let mut response = Response::with((status::PartialContent, body));
+    response.headers.set(ContentLength(end - start + 1));
+    response
+        .headers
+        .set(ContentRange(ContentRangeSpec::Bytes {
+                              range: Some((start, end)),
+                              instance_length: Some(len),
+                          }));
+    Ok(response)
+}
+
+/// Returns the target a package is requested for: the one given by the `target` query parameter,
+/// or else the one of the client, taken from its `User-Agent` header.
+fn target_from_req(req: &mut Request) -> result::Result<PackageTarget, Response> {
+    match extract_query_value("target", req) {
+        Some(target) => {
+            match PackageTarget::from_str(&target) {
+                Ok(target) => Ok(target),
+                Err(_) => Err(Response::with(status::BadRequest)),
+            }
+        }
+        None => {
+            match req.headers.get::<UserAgent>() {
+                Some(user_agent) => target_from_headers(user_agent),
+                None => Err(Response::with(status::BadRequest)),
+            }
+        }
+    }
+}
+
 fn target_from_headers(user_agent_header: &UserAgent) -> result::Result<PackageTarget, Response> {
     let user_agent = user_agent_header.as_str();
     debug!("Headers = {}", &user_agent);
 
     let user_agent_regex = Regex::new(r"(?P<client>\.*)\s\((?P<target>\w+-\w+); (?P<kernel>.*)\)")
         .unwrap();
-    let user_agent_capture = user_agent_regex
-        .captures(user_agent)
-        .expect("Invalid user agent supplied.");
+    let user_agent_capture = match user_agent_regex.captures(user_agent) {
+        Some(capture) => capture,
+        None => return Err(Response::with(status::BadRequest)),
+    };
     match PackageTarget::from_str(&user_agent_capture["target"]) {
         Ok(target) => Ok(target),
         Err(_) => Err(Response::with(status::BadRequest)),
@@ -2052,6 +2160,131 @@ mod test {
         assert_eq!(result_body, body);
     }
 
+    /// Uploads the windows cacerts fixture, returning its content.
+    fn upload_cacerts() -> Vec<u8> {
+        let mut upload_broker: TestableBroker = Default::default();
+        let mut access_res = CheckOriginAccessResponse::new();
+        access_res.set_has_access(true);
+        upload_broker.setup::<CheckOriginAccessRequest, CheckOriginAccessResponse>(&access_res);
+        upload_broker.setup_error::<OriginPackageGet>(net::err(ErrCode::ENTITY_NOT_FOUND, ""));
+        upload_broker.setup::<OriginPackageCreate, OriginPackage>(&OriginPackage::new());
+
+        let mut body: Vec<u8> = Vec::new();
+        let path = hart_file("core-cacerts-2017.01.17-20170209064045-x86_64-windows.hart");
+        File::open(&path)
+            .unwrap()
+            .read_to_end(&mut body)
+            .unwrap();
+        let checksum = hash::hash_file(&path).unwrap();
+
+        iron_request(method::Post,
+                                    format!("http://localhost/pkgs/core/cacerts/2017.01.17/20170209064045?checksum={}", checksum).as_str(),
+                                    &mut body.clone(),
+                                    Headers::new(),
+                                    upload_broker);
+        body
+    }
+
+    /// Downloads the windows cacerts fixture, adding `query` to the download URL and sending the
+    /// given headers.
+    fn download_cacerts(query: &str, headers: Headers) -> Response {
+        let mut download_broker: TestableBroker = Default::default();
+        let mut package = OriginPackage::new();
+        let mut ident = OriginPackageIdent::new();
+        ident.set_origin("core".to_string());
+        ident.set_name("cacerts".to_string());
+        ident.set_version("2017.01.17".to_string());
+        ident.set_release("20170209064045".to_string());
+        package.set_ident(ident);
+        download_broker.setup::<OriginPackageGet, OriginPackage>(&package);
+
+        let (response, _) = iron_request(method::Get,
+                                         format!("http://localhost/pkgs/core/cacerts/2017.01.17/20170209064045/download{}", query).as_str(),
+                                         &mut Vec::new(),
+                                         headers,
+                                         download_broker);
+        response.unwrap()
+    }
+
+    /// Downloads the windows cacerts fixture with the given `Range` header.
+    fn download_cacerts_range(range: hyper::header::Range) -> Response {
+        let mut headers = Headers::new();
+        headers.set(UserAgent("hab/0.20.0-dev/20170326090935 (x86_64-windows; 10.0.14915)"
+                                  .to_string()));
+        headers.set(range);
+        download_cacerts("", headers)
+    }
+
+    #[test]
+    fn download_package_range() {
+        let body = upload_cacerts();
+
+        let response =
+            download_cacerts_range(hyper::header::Range::Bytes(vec![ByteRangeSpec::AllFrom(10)]));
+
+        //assert headers
+        assert_eq!(response.status, Some(status::PartialContent));
+        assert_eq!(response.headers.get::<ContentRange>(),
+                   Some(&ContentRange(ContentRangeSpec::Bytes {
+                                          range: Some((10, body.len() as u64 - 1)),
+                                          instance_length: Some(body.len() as u64),
+                                      })));
+
+        //assert file content
+        let result_body = response::extract_body_to_bytes(response);
+        assert_eq!(result_body, &body[10..]);
+    }
+
+    #[test]
+    fn download_package_unsatisfiable_range() {
+        let body = upload_cacerts();
+
+        let response = download_cacerts_range(hyper::header::Range::Bytes(vec![
+            ByteRangeSpec::AllFrom(body.len() as u64)
+        ]));
+
+        assert_eq!(response.status, Some(status::RangeNotSatisfiable));
+        assert_eq!(response.headers.get::<ContentRange>(),
+                   Some(&ContentRange(ContentRangeSpec::Bytes {
+                                          range: None,
+                                          instance_length: Some(body.len() as u64),
+                                      })));
+    }
+
+    #[test]
+    fn download_package_for_requested_target() {
+        let body = upload_cacerts();
+
+        //download from linux, asking for the windows artifact
+        let mut headers = Headers::new();
+        headers.set(UserAgent("hab/0.20.0-dev/20170326090935 (x86_64-linux; 9.9.9)".to_string()));
+
+        let response = download_cacerts("?target=x86_64-windows", headers);
+
+        //assert headers
+        assert_eq!(response.status, Some(status::Ok));
+        let disp = ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(
+                Charset::Iso_8859_1,
+                None,
+                b"core-cacerts-2017.01.17-20170209064045-x86_64-windows.hart".to_vec()
+            )],
+        };
+        assert_eq!(response.headers.get::<ContentDisposition>(), Some(&disp));
+
+        //assert file content
+        let result_body = response::extract_body_to_bytes(response);
+        assert_eq!(result_body, body);
+    }
+
+    #[test]
+    fn download_package_without_target_or_user_agent() {
+        let response = download_cacerts("", Headers::new());
+
+        assert_eq!(response.status, Some(status::BadRequest));
+    }
+
     #[test]
     fn list_unique_packages() {
         let mut broker: TestableBroker = Default::default();
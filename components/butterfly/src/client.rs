@@ -25,6 +25,7 @@ use message;
 use rumor::Rumor;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
+use rumor::service_secret::ServiceSecret;
 use error::{Result, Error};
 
 /// Holds a ZMQ Push socket, and an optional ring encryption key.
@@ -90,6 +91,18 @@ impl Client {
         self.send(sf)
     }
 
+    /// Send service secrets, already encrypted for the service group, to the server.
+    pub fn send_service_secret(&mut self,
+                               service_group: ServiceGroup,
+                               incarnation: u64,
+                               secrets: Vec<u8>)
+                               -> Result<()> {
+        let mut ss = ServiceSecret::new("butterflyclient", service_group, secrets);
+        ss.set_incarnation(incarnation);
+        ss.set_encrypted(true);
+        self.send(ss)
+    }
+
     /// Send any `Rumor` to the server.
     pub fn send<T: Rumor>(&mut self, rumor: T) -> Result<()> {
         let bytes = try!(rumor.write_to_bytes());
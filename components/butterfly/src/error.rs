@@ -38,6 +38,9 @@ pub enum Error {
     ProtobufError(protobuf::ProtobufError),
     ServiceConfigDecode(String, toml::de::Error),
     ServiceConfigNotUtf8(String, str::Utf8Error),
+    ServiceSecretDecode(String, toml::de::Error),
+    ServiceSecretNotEncrypted(String),
+    ServiceSecretNotUtf8(String, str::Utf8Error),
     SocketSetReadTimeout(io::Error),
     SocketSetWriteTimeout(io::Error),
     SocketCloneError,
@@ -78,6 +81,15 @@ impl fmt::Display for Error {
             Error::ServiceConfigNotUtf8(ref sg, ref err) => {
                 format!("Cannot read service configuration: group={}, {}", sg, err)
             }
+            Error::ServiceSecretDecode(ref sg, ref err) => {
+                format!("Cannot decode service secrets: group={}, {:?}", sg, err)
+            }
+            Error::ServiceSecretNotEncrypted(ref sg) => {
+                format!("Refusing to accept unencrypted service secrets: group={}", sg)
+            }
+            Error::ServiceSecretNotUtf8(ref sg, ref err) => {
+                format!("Cannot read service secrets: group={}, {}", sg, err)
+            }
             Error::SocketSetReadTimeout(ref err) => {
                 format!("Cannot set UDP socket read timeout: {}", err)
             }
@@ -108,6 +120,9 @@ impl error::Error for Error {
             Error::ProtobufError(ref err) => err.description(),
             Error::ServiceConfigDecode(_, _) => "Cannot decode service config into TOML",
             Error::ServiceConfigNotUtf8(_, _) => "Cannot read service config bytes to UTF-8",
+            Error::ServiceSecretDecode(_, _) => "Cannot decode service secrets into TOML",
+            Error::ServiceSecretNotEncrypted(_) => "Service secrets must be encrypted",
+            Error::ServiceSecretNotUtf8(_, _) => "Cannot read service secret bytes to UTF-8",
             Error::SocketSetReadTimeout(_) => "Cannot set UDP socket read timeout",
             Error::SocketSetWriteTimeout(_) => "Cannot set UDP socket write timeout",
             Error::SocketCloneError => "Cannot clone the underlying UDP socket",
@@ -382,6 +382,15 @@ impl MemberList {
         }
     }
 
+    /// Returns the time at which the given member became suspect, if it is currently suspect.
+    pub fn suspect_since(&self, member_id: &str) -> Option<SteadyTime> {
+        self.suspect
+            .read()
+            .expect("Suspect lock is poisoned")
+            .get(member_id)
+            .cloned()
+    }
+
     /// Returns true if the member is alive, suspect, or persistent; used during the target
     /// selection phase of the outbound thread.
     pub fn pingable(&self, member: &Member) -> bool {
@@ -110,6 +110,9 @@ impl Serialize for swim::Rumor {
         if self.has_service_file() {
             try!(strukt.serialize_field("service_file", self.get_service_file()));
         }
+        if self.has_service_secret() {
+            try!(strukt.serialize_field("service_secret", self.get_service_secret()));
+        }
         if self.has_election() {
             try!(strukt.serialize_field("election", self.get_election()));
         }
@@ -151,6 +154,19 @@ impl Serialize for swim::ServiceConfig {
     }
 }
 
+// The secrets themselves are never serialized, even in their encrypted form.
+impl Serialize for swim::ServiceSecret {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("service_secret", 3));
+        try!(strukt.serialize_field("service_group", self.get_service_group()));
+        try!(strukt.serialize_field("incarnation", &self.get_incarnation()));
+        try!(strukt.serialize_field("encrypted", &self.get_encrypted()));
+        strukt.end()
+    }
+}
+
 impl Serialize for swim::ServiceFile {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
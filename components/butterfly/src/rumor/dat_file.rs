@@ -24,10 +24,12 @@ use rand::{Rng, thread_rng};
 use error::{Result, Error};
 use member::{Health, Member, MemberList};
 use message::swim::Membership as ProtoMembership;
-use rumor::{Election, ElectionUpdate, Rumor, RumorStore, Service, ServiceConfig, ServiceFile};
+use rumor::{Election, ElectionUpdate, Rumor, RumorStore, Service, ServiceConfig, ServiceFile,
+            ServiceSecret};
 use server::Server;
 
-const HEADER_VERSION: u8 = 1;
+/// Version 2 added the service secret rumors; version 1 files are still readable.
+const HEADER_VERSION: u8 = 2;
 
 /// A versioned binary file containing rumors exchanged by the butterfly server which have
 /// been periodically persisted to disk.
@@ -42,6 +44,7 @@ const HEADER_VERSION: u8 = 1;
 pub struct DatFile {
     header: Header,
     path: PathBuf,
+    version: u8,
 }
 
 impl DatFile {
@@ -49,6 +52,7 @@ impl DatFile {
         DatFile {
             path: data_path.as_ref().join(format!("{}.rst", member_id)),
             header: Header::default(),
+            version: HEADER_VERSION,
         }
     }
 
@@ -71,7 +75,8 @@ impl DatFile {
             .read_exact(&mut version)
             .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
         debug!("Header Version: {}", version[0]);
-        self.header = Header::from_file(&mut reader)
+        self.version = version[0];
+        self.header = Header::from_file(&mut reader, self.version)
             .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
         debug!("Header: {:?}", self.header);
 
@@ -194,6 +199,26 @@ impl DatFile {
             server.insert_update_election(rumor);
             bytes_read += size_buf.len() as u64 + rumor_size;
         }
+
+        debug!("Reading service-secret rumors from {}",
+               self.path().display());
+        bytes_read = 0;
+        loop {
+            if bytes_read >= self.header.service_secret_len {
+                break;
+            }
+            reader
+                .read_exact(&mut size_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor_size = LittleEndian::read_u64(&size_buf);
+            rumor_buf.resize(rumor_size as usize, 0);
+            reader
+                .read_exact(&mut rumor_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor = ServiceSecret::from_bytes(&rumor_buf)?;
+            server.insert_service_secret(rumor);
+            bytes_read += size_buf.len() as u64 + rumor_size;
+        }
         Ok(())
     }
 
@@ -221,6 +246,8 @@ impl DatFile {
                 self.write_rumor_store(&mut writer, &server.service_file_store)?;
             header.election_len = self.write_rumor_store(&mut writer, &server.election_store)?;
             header.update_len = self.write_rumor_store(&mut writer, &server.update_store)?;
+            header.service_secret_len =
+                self.write_rumor_store(&mut writer, &server.service_secret_store)?;
             writer
                 .seek(SeekFrom::Start(1))
                 .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
@@ -238,7 +265,7 @@ impl DatFile {
         where W: Write
     {
         let mut total = 0;
-        let header_reserve = vec![0; mem::size_of::<Header>()];
+        let header_reserve = vec![0; Header::size(HEADER_VERSION)];
         total += writer
             .write(&[HEADER_VERSION])
             .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
@@ -249,7 +276,7 @@ impl DatFile {
     }
 
     fn member_offset(&self) -> u64 {
-        1 + mem::size_of::<Header>() as u64
+        1 + Header::size(self.version) as u64
     }
 
     #[allow(dead_code)]
@@ -277,6 +304,11 @@ impl DatFile {
         self.election_offset() + self.header.election_len
     }
 
+    #[allow(dead_code)]
+    fn service_secret_offset(&self) -> u64 {
+        self.update_offset() + self.header.update_len
+    }
+
     fn write_header<W>(&self, writer: &mut W, header: &Header) -> Result<usize>
         where W: Write
     {
@@ -365,18 +397,32 @@ pub struct Header {
     pub service_file_len: u64,
     pub election_len: u64,
     pub update_len: u64,
+    pub service_secret_len: u64,
 }
 
 impl Header {
-    pub fn from_file<R>(reader: &mut R) -> io::Result<Self>
+    /// The size in bytes of the header written by the given header version.
+    pub fn size(version: u8) -> usize {
+        match version {
+            1 => 48,
+            _ => mem::size_of::<Self>(),
+        }
+    }
+
+    pub fn from_file<R>(reader: &mut R, version: u8) -> io::Result<Self>
         where R: Read
     {
-        let mut bytes = vec![0; mem::size_of::<Self>()];
+        let mut bytes = vec![0; Self::size(version)];
         reader.read_exact(&mut bytes)?;
         Ok(Self::from_bytes(&bytes))
     }
 
     pub fn from_bytes(bytes: &[u8]) -> Self {
+        let service_secret_len = if bytes.len() >= 56 {
+            LittleEndian::read_u64(&bytes[48..56])
+        } else {
+            0
+        };
         Header {
             member_len: LittleEndian::read_u64(&bytes[0..8]),
             service_len: LittleEndian::read_u64(&bytes[8..16]),
@@ -384,6 +430,7 @@ impl Header {
             service_file_len: LittleEndian::read_u64(&bytes[24..32]),
             election_len: LittleEndian::read_u64(&bytes[32..40]),
             update_len: LittleEndian::read_u64(&bytes[40..48]),
+            service_secret_len: service_secret_len,
         }
     }
 
@@ -395,6 +442,7 @@ impl Header {
         LittleEndian::write_u64(&mut bytes[24..32], self.service_file_len);
         LittleEndian::write_u64(&mut bytes[32..40], self.election_len);
         LittleEndian::write_u64(&mut bytes[40..48], self.update_len);
+        LittleEndian::write_u64(&mut bytes[48..56], self.service_secret_len);
         Ok(bytes)
     }
 }
@@ -414,9 +462,21 @@ mod tests {
         original.service_file_len = rand::random::<u64>();
         original.election_len = rand::random::<u64>();
         original.update_len = rand::random::<u64>();
+        original.service_secret_len = rand::random::<u64>();
         let bytes = original.write_to_bytes().unwrap();
         let restored = Header::from_bytes(&bytes);
         assert_eq!(bytes.len(), mem::size_of::<Header>());
         assert_eq!(original, restored);
     }
+
+    #[test]
+    fn read_version_one_header() {
+        let mut original = Header::default();
+        original.service_len = rand::random::<u64>();
+        original.update_len = rand::random::<u64>();
+        let bytes = original.write_to_bytes().unwrap();
+        let restored = Header::from_file(&mut &bytes[..Header::size(1)], 1).unwrap();
+        assert_eq!(original, restored);
+        assert_eq!(restored.service_secret_len, 0);
+    }
 }
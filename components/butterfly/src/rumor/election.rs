@@ -21,6 +21,10 @@
 //! It uses a particular variant I think of as the "highlander" model. A given election will
 //! devolve to a single, universal rumor, which when it is received by the winner will result in
 //! the election finishing. There can, in the end, be only one.
+//!
+//! When a service group wants more than a single leader, the winner also designates a number of
+//! replicas from the members that voted for it, and records them in the finished election. Every
+//! other member is a follower.
 
 use std::ops::{Deref, DerefMut};
 
@@ -32,6 +36,31 @@ use error::Result;
 use message::swim::{Election as ProtoElection, Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type};
 use rumor::Rumor;
 
+/// The default minimum population of a service group before it can complete an election.
+pub const DEFAULT_QUORUM_SIZE: u64 = 3;
+
+/// The settings a service group's elections are run with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElectionConfig {
+    /// The minimum population of the service group before an election can complete.
+    pub quorum_size: u64,
+    /// The number of members, besides the leader, the leader designates as replicas.
+    pub replicas: u64,
+    /// How long, in seconds, followers wait on a suspect leader before electing a new one. A lease
+    /// of 0 waits until the leader is confirmed dead.
+    pub lease_secs: u64,
+}
+
+impl Default for ElectionConfig {
+    fn default() -> ElectionConfig {
+        ElectionConfig {
+            quorum_size: DEFAULT_QUORUM_SIZE,
+            replicas: 0,
+            lease_secs: 0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Election(ProtoRumor);
 
@@ -120,6 +149,43 @@ impl Election {
     pub fn is_finished(&self) -> bool {
         self.get_status() == Election_Status::Finished
     }
+
+    /// Returns true if the given member has been designated a replica by the leader.
+    pub fn is_replica(&self, member_id: &str) -> bool {
+        self.get_replicas().iter().any(|r| r == member_id)
+    }
+
+    /// Designate up to `count` members of the electorate, other than the leader, as replicas.
+    ///
+    /// Replicas which are still part of the electorate keep their role, so that a change in the
+    /// population only moves the roles it has to; the remainder are filled in member id order.
+    /// Returns true if the replicas changed.
+    pub fn assign_replicas(&mut self, electorate: &[String], count: usize) -> bool {
+        let leader = String::from(self.get_member_id());
+        let mut replicas: Vec<String> = self.get_replicas()
+            .iter()
+            .filter(|r| electorate.contains(*r) && **r != leader)
+            .take(count)
+            .cloned()
+            .collect();
+        let mut candidates: Vec<&String> = electorate
+            .iter()
+            .filter(|m| **m != leader && !replicas.contains(*m))
+            .collect();
+        candidates.sort();
+        for candidate in candidates {
+            if replicas.len() >= count {
+                break;
+            }
+            replicas.push(candidate.clone());
+        }
+        if replicas.as_slice() == self.get_replicas() {
+            false
+        } else {
+            self.set_replicas(RepeatedField::from_vec(replicas));
+            true
+        }
+    }
 }
 
 impl PartialEq for Election {
@@ -129,7 +195,7 @@ impl PartialEq for Election {
         self.get_member_id() == other.get_member_id() &&
         self.get_suitability() == other.get_suitability() &&
         self.get_votes() == other.get_votes() && self.get_status() == other.get_status() &&
-        self.get_term() == other.get_term()
+        self.get_term() == other.get_term() && self.get_replicas() == other.get_replicas()
     }
 }
 
@@ -329,4 +395,39 @@ mod tests {
         assert_eq!(e1.get_member_id(), "d");
         assert_eq!(e1.get_votes().len(), 4);
     }
+
+    #[test]
+    fn assign_replicas_in_member_id_order() {
+        let mut e1 = create_election("b", 0);
+        let electorate = vec![String::from("d"),
+                              String::from("b"),
+                              String::from("a"),
+                              String::from("c")];
+        assert_eq!(e1.assign_replicas(&electorate, 2), true);
+        assert_eq!(e1.get_replicas(), &["a".to_string(), "c".to_string()]);
+        assert!(e1.is_replica("a"));
+        assert!(!e1.is_replica("b"));
+        assert_eq!(e1.assign_replicas(&electorate, 2), false);
+    }
+
+    #[test]
+    fn assign_replicas_keeps_surviving_replicas() {
+        let mut e1 = create_election("b", 0);
+        let electorate = vec![String::from("a"), String::from("b"), String::from("c")];
+        e1.assign_replicas(&electorate, 2);
+        let electorate = vec![String::from("b"), String::from("c"), String::from("d")];
+        assert_eq!(e1.assign_replicas(&electorate, 2), true);
+        assert_eq!(e1.get_replicas(), &["c".to_string(), "d".to_string()]);
+    }
+
+    #[test]
+    fn merge_finished_election_with_new_replicas() {
+        let mut e1 = create_election("a", 0);
+        e1.finish();
+        let mut e2 = e1.clone();
+        e2.assign_replicas(&[String::from("a"), String::from("b")], 1);
+        e2.set_term(1);
+        assert_eq!(e1.merge(e2), true);
+        assert!(e1.is_replica("b"));
+    }
 }
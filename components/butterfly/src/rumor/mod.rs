@@ -28,11 +28,13 @@ pub mod election;
 pub mod service;
 pub mod service_config;
 pub mod service_file;
+pub mod service_secret;
 
 pub use self::election::{Election, ElectionUpdate};
 pub use self::service::Service;
 pub use self::service_config::ServiceConfig;
 pub use self::service_file::ServiceFile;
+pub use self::service_secret::ServiceSecret;
 
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
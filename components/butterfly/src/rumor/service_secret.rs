@@ -0,0 +1,211 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ServiceSecret rumor.
+//!
+//! Holds the toml secrets injected for a service. Unlike a ServiceConfig, a ServiceSecret is
+//! always encrypted for the service group's key and is only ever decrypted in memory.
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::str::{self, FromStr};
+
+use habitat_core::crypto::BoxKeyPair;
+use habitat_core::service::ServiceGroup;
+use protobuf::{self, Message};
+use toml;
+
+use error::{Error, Result};
+use message::swim::{ServiceSecret as ProtoServiceSecret, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceSecret(ProtoRumor);
+
+impl PartialOrd for ServiceSecret {
+    fn partial_cmp(&self, other: &ServiceSecret) -> Option<Ordering> {
+        if self.get_service_group() != other.get_service_group() {
+            None
+        } else {
+            Some(self.get_incarnation().cmp(&other.get_incarnation()))
+        }
+    }
+}
+
+impl PartialEq for ServiceSecret {
+    fn eq(&self, other: &ServiceSecret) -> bool {
+        self.get_service_group() == other.get_service_group() &&
+        self.get_incarnation() == other.get_incarnation() &&
+        self.get_encrypted() == other.get_encrypted() &&
+        self.get_secrets() == other.get_secrets()
+    }
+}
+
+impl From<ProtoRumor> for ServiceSecret {
+    fn from(pr: ProtoRumor) -> ServiceSecret {
+        ServiceSecret(pr)
+    }
+}
+
+impl From<ServiceSecret> for ProtoRumor {
+    fn from(service_secret: ServiceSecret) -> ProtoRumor {
+        service_secret.0
+    }
+}
+
+impl Deref for ServiceSecret {
+    type Target = ProtoServiceSecret;
+
+    fn deref(&self) -> &ProtoServiceSecret {
+        self.0.get_service_secret()
+    }
+}
+
+impl DerefMut for ServiceSecret {
+    fn deref_mut(&mut self) -> &mut ProtoServiceSecret {
+        self.0.mut_service_secret()
+    }
+}
+
+impl ServiceSecret {
+    /// Creates a new ServiceSecret. The secrets must be encrypted with `encrypt` before the rumor
+    /// is shared.
+    pub fn new<S1>(member_id: S1, service_group: ServiceGroup, secrets: Vec<u8>) -> Self
+        where S1: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id);
+        rumor.set_field_type(ProtoRumor_Type::ServiceSecret);
+
+        let mut proto = ProtoServiceSecret::new();
+        proto.set_service_group(format!("{}", service_group));
+        proto.set_incarnation(0);
+        proto.set_secrets(secrets);
+
+        rumor.set_service_secret(proto);
+        ServiceSecret(rumor)
+    }
+
+    pub fn encrypt(&mut self, user_pair: &BoxKeyPair, service_pair: &BoxKeyPair) -> Result<()> {
+        let secrets = self.take_secrets();
+        let encrypted_secrets = try!(user_pair.encrypt(&secrets, service_pair));
+        self.set_secrets(encrypted_secrets);
+        self.set_encrypted(true);
+        Ok(())
+    }
+
+    /// Decrypts the secrets with the service group's key from the given cache and parses them as
+    /// toml. The decrypted bytes never leave memory.
+    pub fn secrets<P: AsRef<Path>>(&self, cache_key_path: P) -> Result<toml::Value> {
+        if !self.get_encrypted() {
+            return Err(Error::ServiceSecretNotEncrypted(self.get_service_group().to_string()));
+        }
+        let bytes = try!(BoxKeyPair::decrypt(self.get_secrets(), cache_key_path.as_ref()));
+        let encoded =
+            try!(str::from_utf8(&bytes)
+            .map_err(|e| Error::ServiceSecretNotUtf8(self.get_service_group().to_string(), e)));
+        toml::Value::from_str(encoded)
+            .map_err(|e| Error::ServiceSecretDecode(self.get_service_group().to_string(), e))
+    }
+}
+
+impl Rumor for ServiceSecret {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(ServiceSecret::from(rumor))
+    }
+
+    /// Follows the same pattern as ServiceConfig; the newest incarnation wins.
+    fn merge(&mut self, mut other: ServiceSecret) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::ServiceSecret
+    }
+
+    fn id(&self) -> &str {
+        "service_secret"
+    }
+
+    fn key(&self) -> &str {
+        self.get_service_group()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use habitat_core::service::ServiceGroup;
+
+    use super::ServiceSecret;
+    use error::Error;
+    use rumor::Rumor;
+
+    fn create_service_secret(member_id: &str, secrets: &str) -> ServiceSecret {
+        let secrets_bytes: Vec<u8> = Vec::from(secrets);
+        ServiceSecret::new(member_id,
+                           ServiceGroup::new("neurosis", "production", None).unwrap(),
+                           secrets_bytes)
+    }
+
+    #[test]
+    fn identical_service_secrets_are_equal() {
+        let s1 = create_service_secret("adam", "yep");
+        let s2 = create_service_secret("adam", "yep");
+        assert_eq!(s1, s2);
+    }
+
+    #[test]
+    fn service_secrets_with_different_incarnations_are_not_equal_via_cmp() {
+        let s1 = create_service_secret("adam", "yep");
+        let mut s2 = create_service_secret("adam", "yep");
+        s2.set_incarnation(1);
+        assert_eq!(s1.partial_cmp(&s2), Some(Ordering::Less));
+        assert_eq!(s2.partial_cmp(&s1), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn merge_chooses_the_higher_incarnation() {
+        let mut s1 = create_service_secret("adam", "yep");
+        let mut s2 = create_service_secret("adam", "yep");
+        s2.set_incarnation(1);
+        let s2_check = s2.clone();
+        assert_eq!(s1.merge(s2), true);
+        assert_eq!(s1, s2_check);
+    }
+
+    #[test]
+    fn unencrypted_secrets_are_refused() {
+        let s1 = create_service_secret("adam", "password=\"hunter2\"");
+        match s1.secrets("/nonexistent") {
+            Err(Error::ServiceSecretNotEncrypted(sg)) => assert_eq!(sg, "neurosis.production"),
+            other => panic!("expected ServiceSecretNotEncrypted, got {:?}", other),
+        }
+    }
+}
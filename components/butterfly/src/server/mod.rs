@@ -32,7 +32,7 @@ use std::fmt::{self, Debug};
 use std::fs;
 use std::io;
 use std::net::{ToSocketAddrs, UdpSocket, SocketAddr};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
 use std::sync::{Arc, RwLock};
@@ -45,6 +45,7 @@ use habitat_core::service::ServiceGroup;
 use habitat_core::crypto::SymKey;
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
+use time::{self, SteadyTime};
 use toml;
 
 use error::{Result, Error};
@@ -55,7 +56,8 @@ use rumor::dat_file::DatFile;
 use rumor::service::Service;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
-use rumor::election::{Election, ElectionUpdate};
+use rumor::service_secret::ServiceSecret;
+use rumor::election::{Election, ElectionConfig, ElectionUpdate};
 use trace::{Trace, TraceKind};
 
 pub trait Suitability: Debug + Send + Sync {
@@ -74,8 +76,10 @@ pub struct Server {
     pub service_store: RumorStore<Service>,
     pub service_config_store: RumorStore<ServiceConfig>,
     pub service_file_store: RumorStore<ServiceFile>,
+    pub service_secret_store: RumorStore<ServiceSecret>,
     pub election_store: RumorStore<Election>,
     pub update_store: RumorStore<ElectionUpdate>,
+    election_configs: Arc<RwLock<HashMap<String, ElectionConfig>>>,
     swim_addr: Arc<RwLock<SocketAddr>>,
     gossip_addr: Arc<RwLock<SocketAddr>>,
     suitability_lookup: Arc<Box<Suitability>>,
@@ -120,8 +124,10 @@ impl Server {
                        service_store: RumorStore::default(),
                        service_config_store: RumorStore::default(),
                        service_file_store: RumorStore::default(),
+                       service_secret_store: RumorStore::default(),
                        election_store: RumorStore::default(),
                        update_store: RumorStore::default(),
+                       election_configs: Arc::new(RwLock::new(HashMap::new())),
                        swim_addr: Arc::new(RwLock::new(swim_socket_addr)),
                        gossip_addr: Arc::new(RwLock::new(gossip_socket_addr)),
                        suitability_lookup: Arc::new(suitability_lookup),
@@ -449,6 +455,14 @@ impl Server {
         }
     }
 
+    /// Insert a service secret rumor into the service secret store.
+    pub fn insert_service_secret(&self, service_secret: ServiceSecret) {
+        let rk = RumorKey::from(&service_secret);
+        if self.service_secret_store.insert(service_secret) {
+            self.rumor_list.insert(rk);
+        }
+    }
+
     /// Get all the Member ID's who are present in a given service group.
     fn get_electorate(&self, key: &str) -> Vec<String> {
         let mut electorate = vec![];
@@ -460,25 +474,65 @@ impl Server {
         electorate
     }
 
+    /// Set the quorum size, number of replicas and leader lease used by the elections of a given
+    /// service group.
+    pub fn configure_election(&self, sg: &ServiceGroup, config: ElectionConfig) {
+        self.election_configs
+            .write()
+            .expect("Election config lock is poisoned")
+            .insert(format!("{}", sg), config);
+    }
+
+    /// Returns the election settings for a given service group, or the defaults if the group was
+    /// never configured.
+    fn election_config(&self, key: &str) -> ElectionConfig {
+        self.election_configs
+            .read()
+            .expect("Election config lock is poisoned")
+            .get(key)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     /// Check if a given service group has quorum to run an election.
     ///
     /// A given group has quorum if, from this servers perspective, it has an alive population that
-    /// is over 50%, and at least 3 members.
+    /// is over 50%, and at least as many members as its configured quorum size (3 by default).
     fn check_quorum(&self, key: &str) -> bool {
         let electorate = self.get_electorate(key);
+        let quorum_size = self.election_config(key).quorum_size as usize;
 
         let total_population = self.service_store.len_for_key(key);
         let alive_population = electorate.len();
 
-        if total_population < 3 {
-            info!("Quorum size: {}/3 - election cannot complete",
-                  total_population);
+        if total_population < quorum_size {
+            info!("Quorum size: {}/{} - election cannot complete",
+                  total_population,
+                  quorum_size);
             return false;
         }
 
         alive_population >= ((total_population / 2) + 1)
     }
 
+    /// Check if the leader of a finished election should be considered gone. A leader is gone once
+    /// it is confirmed dead or, when the service group has a leader lease, once it has been
+    /// suspect for longer than the lease.
+    fn leader_is_lost(&self, key: &str, leader_id: &str) -> bool {
+        if self.member_list
+               .check_health_of_by_id(leader_id, Health::Confirmed) {
+            return true;
+        }
+        let lease_secs = self.election_config(key).lease_secs;
+        if lease_secs == 0 {
+            return false;
+        }
+        match self.member_list.suspect_since(leader_id) {
+            Some(since) => SteadyTime::now() - since >= time::Duration::seconds(lease_secs as i64),
+            None => false,
+        }
+    }
+
     /// Start an election for the given service group, declaring this members suitability and the
     /// term for the election.
     pub fn start_election(&self, sg: ServiceGroup, term: u64) {
@@ -504,13 +558,43 @@ impl Server {
         self.rumor_list.insert(ek);
     }
 
+    /// Advance the term of a finished update election that this member has won, keeping the
+    /// outcome intact. Other members accept a finished election with a higher term as-is, which
+    /// allows the update leader to signal the rest of the service group.
+    pub fn advance_update_election_term(&self, sg: &ServiceGroup) {
+        let key = format!("{}", sg);
+        let mut advanced = None;
+        self.update_store
+            .with_rumor(&key, "election", |rumor| if let Some(election) = rumor {
+                if election.is_finished() && election.get_member_id() == self.member_id() {
+                    let mut election = election.clone();
+                    let term = election.get_term() + 1;
+                    election.set_term(term);
+                    advanced = Some(election);
+                }
+            });
+        match advanced {
+            Some(election) => {
+                let ek = RumorKey::from(&election);
+                self.update_store.insert(election);
+                self.rumor_list.insert(ek);
+            }
+            None => warn!("Cannot advance update election for {}; we are not its leader", sg),
+        }
+    }
+
     /// Check to see if this server needs to restart a given election. This happens when:
     ///
     /// a) We are the leader, and we have lost quorum with the rest of the group.
-    /// b) We are not the leader, and we have detected that the leader is confirmed dead.
+    /// b) We are not the leader, and we have detected that the leader is confirmed dead, or has
+    ///    been suspect for longer than the group's leader lease.
+    ///
+    /// A leader which keeps quorum instead checks that its replicas are still alive, and designates
+    /// new ones in their place.
     pub fn restart_elections(&self) {
         let mut elections_to_restart = vec![];
         let mut update_elections_to_restart = vec![];
+        let mut replicas_to_reassign = vec![];
 
         self.election_store.with_keys(|(service_group, rumors)| {
             if self.service_store.contains_rumor(&service_group, self.member_id()) {
@@ -519,7 +603,8 @@ impl Server {
                     .expect("Lost an election struct between looking it up and reading it.");
                 // If we are finished, and the leader is dead, we should restart the election
                 if election.is_finished() && election.get_member_id() == self.member_id() {
-                    // If we are the leader, and we have lost quorum, we should restart the election
+                    // If we are the leader, and we have lost quorum, we should step down and
+                    // restart the election
                     if self.check_quorum(election.key()) == false {
                         warn!("Restarting election with a new term as the leader has lost \
                               quorum: {:?}",
@@ -527,10 +612,17 @@ impl Server {
                         elections_to_restart.push((String::from(&service_group[..]),
                                                    election.get_term()));
 
+                    } else {
+                        // Otherwise, make sure our replicas are still alive
+                        let replicas = self.election_config(election.key()).replicas;
+                        let electorate = self.get_electorate(election.key());
+                        let mut reassigned = election.clone();
+                        if reassigned.assign_replicas(&electorate, replicas as usize) {
+                            replicas_to_reassign.push(reassigned);
+                        }
                     }
                 } else if election.is_finished() {
-                    if self.member_list.check_health_of_by_id(election.get_member_id(),
-                                                              Health::Confirmed) {
+                    if self.leader_is_lost(election.key(), election.get_member_id()) {
                         warn!("Restarting election with a new term as the leader is dead {}: {:?}",
                               self.member_id(),
                               election);
@@ -558,8 +650,7 @@ impl Server {
 
                     }
                 } else if election.is_finished() {
-                    if self.member_list.check_health_of_by_id(election.get_member_id(),
-                                                              Health::Confirmed) {
+                    if self.leader_is_lost(election.key(), election.get_member_id()) {
                         warn!("Restarting election with a new term as the leader is dead {}: {:?}",
                               self.member_id(),
                               election);
@@ -570,6 +661,20 @@ impl Server {
             }
         });
 
+        // Replicas are reassigned under a new term, as other members only accept a finished
+        // election with a higher term than the one they already have.
+        for mut election in replicas_to_reassign {
+            let term = election.get_term() + 1;
+            election.set_term(term);
+            warn!("Reassigning replicas for {} {}: {:?}",
+                  election.get_service_group(),
+                  term,
+                  election.get_replicas());
+            let ek = RumorKey::from(&election);
+            self.election_store.insert(election);
+            self.rumor_list.insert(ek);
+        }
+
         for (service_group, old_term) in elections_to_restart {
             let sg = match ServiceGroup::from_str(&service_group) {
                 Ok(sg) => sg,
@@ -644,6 +749,8 @@ impl Server {
                         }
                         if num_votes == electorate.len() {
                             debug!("Election is finished: {:#?}", election);
+                            let replicas = self.election_config(election.key()).replicas;
+                            election.assign_replicas(&electorate, replicas as usize);
                             election.finish();
                         } else {
                             debug!("I have quorum, but election is not finished {}/{}",
@@ -807,6 +914,30 @@ impl Server {
         result
     }
 
+    /// Returns (incarnation, secrets) if the service group has secrets newer than the given
+    /// incarnation. The secrets are decrypted with the service group's key from
+    /// `cache_key_path` and are only ever held in memory.
+    pub fn service_secret_for<P: AsRef<Path>>(&self,
+                                              service_group: &str,
+                                              incarnation: Option<u64>,
+                                              cache_key_path: P)
+                                              -> Option<(u64, toml::Value)> {
+        let mut result = None;
+        self.service_secret_store
+            .with_rumor(service_group,
+                        "service_secret",
+                        |maybe_ss| if let Some(ss) = maybe_ss {
+                            if incarnation.is_none() ||
+                               ss.get_incarnation() > incarnation.unwrap() {
+                                match ss.secrets(cache_key_path.as_ref()) {
+                                    Ok(secrets) => result = Some((ss.get_incarnation(), secrets)),
+                                    Err(err) => warn!("{}", err),
+                                }
+                            }
+                        });
+        result
+    }
+
     fn generate_wire(&self, payload: Vec<u8>) -> Result<Vec<u8>> {
         message::generate_wire(payload, &self.ring_key)
     }
@@ -828,11 +959,12 @@ impl Serialize for Server {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
     {
-        let mut strukt = try!(serializer.serialize_struct("butterfly", 6));
+        let mut strukt = try!(serializer.serialize_struct("butterfly", 7));
         try!(strukt.serialize_field("member", &self.member_list));
         try!(strukt.serialize_field("service", &self.service_store));
         try!(strukt.serialize_field("service_config", &self.service_config_store));
         try!(strukt.serialize_field("service_file", &self.service_file_store));
+        try!(strukt.serialize_field("service_secret", &self.service_secret_store));
         try!(strukt.serialize_field("election", &self.election_store));
         try!(strukt.serialize_field("election_update", &self.update_store));
         strukt.end()
@@ -104,6 +104,9 @@ impl Pull {
                 Rumor_Type::ServiceFile => {
                     self.server.insert_service_file(proto.into());
                 }
+                Rumor_Type::ServiceSecret => {
+                    self.server.insert_service_secret(proto.into());
+                }
                 Rumor_Type::Election => {
                     self.server.insert_election(proto.into());
                 }
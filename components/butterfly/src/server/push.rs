@@ -279,6 +279,19 @@ impl PushWorker {
                         }
                     }
                 }
+                ProtoRumor_Type::ServiceSecret => {
+                    match self.server
+                              .service_secret_store
+                              .write_to_bytes(&rumor_key.key, &rumor_key.id) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!("Could not write our own rumor to bytes; abandoning sending \
+                                      rumor: {:?}",
+                                     e);
+                            continue 'rumorlist;
+                        }
+                    }
+                }
                 ProtoRumor_Type::Fake |
                 ProtoRumor_Type::Fake2 => {
                     debug!("You have fake rumors; how odd!");
@@ -377,6 +377,12 @@ macro_rules! trace_it {
                                 $payload.get_service_file().get_encrypted(),
                                 $payload.get_service_file().get_filename())
                     }
+                    Rumor_Type::ServiceSecret => {
+                        format!("{}-{}-{}",
+                                $payload.get_service_secret().get_service_group(),
+                                $payload.get_service_secret().get_incarnation(),
+                                $payload.get_service_secret().get_encrypted())
+                    }
                     Rumor_Type::Election | Rumor_Type::ElectionUpdate => {
                         format!("{}-{}-{}-{}-{:?}-{:?}",
                                 $payload.get_election().get_member_id(),
@@ -0,0 +1,194 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bundles a package with all of its dependencies for installation where there is no depot.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg bundle core/redis
+//! ```
+//!
+//! Will write the artifacts of the latest `core/redis` and of all of its dependencies, along
+//! with the public origin keys which signed them, to a bundle such as
+//! `core-redis-3.0.1-20160614153342-bundle.tar`. It can be installed from with:
+//!
+//! ```bash
+//! $ hab pkg install core/redis --offline core-redis-3.0.1-20160614153342-bundle.tar
+//! ```
+//!
+//! # Internals
+//!
+//! A bundle is a tar archive holding the artifacts in its `artifacts` directory and the public
+//! origin keys in its `keys` directory.
+
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use hcore::crypto::{artifact, SigKeyPair};
+use hcore::fs::cache_key_path;
+use hcore::package::PackageIdent;
+use tar;
+
+use super::install::InstallTask;
+use error::Result;
+use ui::{Status, UI};
+
+pub const ARTIFACTS_DIR: &'static str = "artifacts";
+pub const KEYS_DIR: &'static str = "keys";
+
+pub fn start<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
+                                     url: &str,
+                                     ident: &PackageIdent,
+                                     product: &str,
+                                     version: &str,
+                                     fs_root_path: &P1,
+                                     cache_artifact_path: &P2,
+                                     dst: Option<&Path>)
+                                     -> Result<PathBuf>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
+{
+    let cache_key_path = cache_key_path(Some(fs_root_path.as_ref()));
+    let task = try!(InstallTask::new(url,
+                                     product,
+                                     version,
+                                     fs_root_path.as_ref(),
+                                     cache_artifact_path.as_ref(),
+                                     &cache_key_path,
+                                     None,
+                                     None,
+                                     false));
+
+    try!(ui.begin(format!("Bundling {}", ident)));
+    let mut artifacts = try!(task.cache_closure(ui, ident.clone()));
+    let bundle_ident = try!(artifacts.last_mut().unwrap().ident());
+    let dst = match dst {
+        Some(dst) => dst.to_path_buf(),
+        None => PathBuf::from(default_file_name(&bundle_ident)),
+    };
+
+    try!(ui.status(Status::Creating, dst.display()));
+    let mut builder = tar::Builder::new(try!(File::create(&dst)));
+    let mut signers = BTreeSet::new();
+    for artifact in artifacts.iter() {
+        try!(builder.append_path_with_name(&artifact.path,
+                                           Path::new(ARTIFACTS_DIR).join(artifact.file_name())));
+        signers.insert(try!(artifact::artifact_signer(&artifact.path)));
+    }
+    for signer in signers.iter() {
+        let key_path = try!(SigKeyPair::get_public_key_path(signer, &cache_key_path));
+        let key_name = key_path.file_name().unwrap().to_os_string();
+        try!(builder.append_path_with_name(&key_path, Path::new(KEYS_DIR).join(key_name)));
+    }
+    try!(builder.finish());
+    try!(ui.end(format!("Bundled {} with {} packages and {} public origin keys into {}",
+                        &bundle_ident,
+                        artifacts.len(),
+                        signers.len(),
+                        dst.display())));
+    Ok(dst)
+}
+
+/// Unpacks a bundle's artifacts into the artifact cache and its public origin keys into the key
+/// cache. Anything else in the bundle is ignored.
+pub fn unpack(ui: &mut UI,
+              bundle: &Path,
+              cache_artifact_path: &Path,
+              cache_key_path: &Path)
+              -> Result<()> {
+    try!(ui.status(Status::Using, format!("bundle {}", bundle.display())));
+    try!(fs::create_dir_all(cache_artifact_path));
+    try!(fs::create_dir_all(cache_key_path));
+    let mut archive = tar::Archive::new(try!(File::open(bundle)));
+    for entry in try!(archive.entries()) {
+        let mut entry = try!(entry);
+        let path = try!(entry.path()).into_owned();
+        // Only the file name is used, so that no entry can be written outside of the caches.
+        let dst_dir = match path.parent() {
+            Some(parent) if parent == Path::new(ARTIFACTS_DIR) => cache_artifact_path,
+            Some(parent) if parent == Path::new(KEYS_DIR) => cache_key_path,
+            _ => {
+                debug!("Skipping {} in bundle", path.display());
+                continue;
+            }
+        };
+        let file_name = match path.file_name() {
+            Some(file_name) => file_name,
+            None => continue,
+        };
+        try!(entry.unpack(dst_dir.join(file_name)));
+        debug!("Unpacked {} from bundle into {}",
+               path.display(),
+               dst_dir.display());
+    }
+    Ok(())
+}
+
+/// Returns the file name of the bundle of a fully qualified package.
+fn default_file_name(ident: &PackageIdent) -> String {
+    format!("{}-{}-{}-{}-bundle.tar",
+            ident.origin,
+            ident.name,
+            ident.version.as_ref().unwrap(),
+            ident.release.as_ref().unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::{Read, Write};
+
+    use tar;
+    use tempdir::TempDir;
+
+    use ui::UI;
+    use super::unpack;
+
+    #[test]
+    fn unpack_only_writes_artifacts_and_keys() {
+        let tmp = TempDir::new("bundle").unwrap();
+        let bundle = tmp.path().join("test-bundle.tar");
+        {
+            let mut builder = tar::Builder::new(File::create(&bundle).unwrap());
+            for &(name, content) in [("artifacts/core-foo.hart", "artifact"),
+                                     ("keys/core-20160810182414.pub", "key"),
+                                     ("README", "ignored")]
+                        .iter() {
+                let src = tmp.path().join("src");
+                File::create(&src)
+                    .unwrap()
+                    .write_all(content.as_bytes())
+                    .unwrap();
+                builder.append_path_with_name(&src, name).unwrap();
+            }
+            builder.finish().unwrap();
+        }
+        let artifacts = tmp.path().join("artifacts");
+        let keys = tmp.path().join("keys");
+
+        unpack(&mut UI::default(), &bundle, &artifacts, &keys).unwrap();
+
+        let mut content = String::new();
+        File::open(artifacts.join("core-foo.hart"))
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "artifact");
+        assert!(keys.join("core-20160810182414.pub").is_file());
+        assert!(!artifacts.join("README").exists());
+        assert!(!keys.join("README").exists());
+    }
+}
@@ -28,32 +28,62 @@
 //!
 //! This would install the `3.0.1` version of redis.
 //!
+//! ```bash
+//! $ hab pkg install core/redis --offline ./core-redis-3.0.1-20160614153342-bundle.tar
+//! ```
+//!
+//! Will install `core/redis` and its dependencies from a bundle made by `hab pkg bundle`, or from
+//! a directory of artifacts and public origin keys, without contacting a depot.
+//!
+//! ```bash
+//! $ hab pkg install core/redis --lock habitat.lock
+//! ```
+//!
+//! Will install the release of `core/redis` and of its dependencies pinned by `habitat.lock`,
+//! pinning them first if they aren't yet. See the [lockfile](../lockfile) module.
+//!
+//! ```bash
+//! $ hab pkg install core/redis --target aarch64-linux --root /mnt/image
+//! ```
+//!
+//! Will install the `aarch64-linux` artifacts of `core/redis` and of its dependencies into the
+//! filesystem rooted at `/mnt/image`, such as one being built for an ARM device.
+//!
 //! # Internals
 //!
-//! * Download the artifact
+//! * Download the artifact, and those of its dependencies several at a time
 //! * Verify it is un-altered
 //! * Unpack it
 //!
 
+use std::cmp;
+use std::collections::VecDeque;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
 
-use depot_client::{self, Client};
+use depot_client::{self, Client, DisplayProgress};
 use hcore;
-use hcore::fs::{am_i_root, cache_key_path};
-use hcore::crypto::{artifact, SigKeyPair};
+use hcore::fs::{am_i_root, cache_key_path, pkg_install_path};
+use hcore::crypto::{artifact, SigKeyPair, PUBLIC_KEY_SUFFIX};
 use hcore::crypto::keys::parse_name_with_rev;
-use hcore::package::{Identifiable, PackageArchive, PackageIdent, Target, PackageInstall};
+use hcore::package::{Identifiable, PackageArchive, PackageIdent, Target, PackageInstall,
+                     PackageTarget};
 use hyper::status::StatusCode;
 
+use super::bundle;
+use super::lockfile::Lockfile;
 use error::{Error, Result};
-use ui::{Status, UI};
+use ui::{CombinedProgress, Status, UI};
 
 use retry::retry;
 
 pub const RETRIES: u64 = 5;
 pub const RETRY_WAIT: u64 = 3000;
+/// Maximum number of artifacts downloaded at the same time.
+pub const DOWNLOAD_WORKERS: usize = 4;
 
 pub fn start<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
                                      url: &str,
@@ -62,6 +92,9 @@ pub fn start<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
                                      version: &str,
                                      fs_root_path: &P1,
                                      cache_artifact_path: &P2,
+                                     offline_path: Option<&Path>,
+                                     lockfile: Option<&mut Lockfile>,
+                                     target: Option<PackageTarget>,
                                      ignore_target: bool)
                                      -> Result<PackageIdent>
     where P1: AsRef<Path>,
@@ -78,26 +111,44 @@ pub fn start<P1: ?Sized, P2: ?Sized>(ui: &mut UI,
     let cache_key_path = cache_key_path(Some(fs_root_path.as_ref()));
     debug!("install cache_key_path: {}", cache_key_path.display());
 
+    // A bundle's contents are unpacked into the caches, which are then installed from.
+    let offline_path = match offline_path {
+        Some(path) if path.is_file() => {
+            try!(bundle::unpack(ui, path, cache_artifact_path.as_ref(), &cache_key_path));
+            Some(cache_artifact_path.as_ref().to_path_buf())
+        }
+        Some(path) => Some(path.to_path_buf()),
+        None => None,
+    };
+
     let task = try!(InstallTask::new(url,
                                      product,
                                      version,
                                      fs_root_path.as_ref(),
                                      cache_artifact_path.as_ref(),
                                      &cache_key_path,
+                                     offline_path.as_ref().map(|p| p.as_path()),
+                                     target,
                                      ignore_target));
 
     if Path::new(ident_or_archive).is_file() {
-        task.from_artifact(ui, &Path::new(ident_or_archive))
+        task.from_artifact(ui, &Path::new(ident_or_archive), lockfile)
     } else {
-        task.from_ident(ui, try!(PackageIdent::from_str(ident_or_archive)))
+        task.from_ident(ui, try!(PackageIdent::from_str(ident_or_archive)), lockfile)
     }
 }
 
-struct InstallTask<'a> {
-    depot_client: Client,
+pub struct InstallTask<'a> {
+    depot_client: Arc<Client>,
     fs_root_path: &'a Path,
     cache_artifact_path: &'a Path,
     cache_key_path: &'a Path,
+    /// Directory of artifacts and public origin keys which, when set, packages are installed from
+    /// instead of the depot.
+    offline_path: Option<&'a Path>,
+    /// The target whose artifacts are installed, which is that of the running system unless
+    /// packages are being installed into a filesystem for another system.
+    target: PackageTarget,
     ignore_target: bool,
 }
 
@@ -108,24 +159,46 @@ impl<'a> InstallTask<'a> {
                fs_root_path: &'a Path,
                cache_artifact_path: &'a Path,
                cache_key_path: &'a Path,
+               offline_path: Option<&'a Path>,
+               target: Option<PackageTarget>,
                ignore_target: bool)
                -> Result<Self> {
+        let mut depot_client = try!(Client::new(url, product, version, Some(fs_root_path)));
+        if let Some(ref target) = target {
+            depot_client.set_target(target.clone());
+        }
+        let target = target.unwrap_or_default();
         Ok(InstallTask {
-               depot_client: try!(Client::new(url, product, version, Some(fs_root_path))),
+               depot_client: Arc::new(depot_client),
                fs_root_path: fs_root_path,
                cache_artifact_path: cache_artifact_path,
                cache_key_path: cache_key_path,
+               offline_path: offline_path,
+               target: target,
                ignore_target: ignore_target,
            })
     }
 
-    pub fn from_ident(&self, ui: &mut UI, ident: PackageIdent) -> Result<PackageIdent> {
+    /// Installs a package and its dependencies. When a lockfile is given, the package's ident is
+    /// resolved to the release it pins, and the checksums of the artifacts of the package and of
+    /// every one of its dependencies are checked against, or else added to, the lockfile.
+    pub fn from_ident(&self,
+                      ui: &mut UI,
+                      ident: PackageIdent,
+                      lockfile: Option<&mut Lockfile>)
+                      -> Result<PackageIdent> {
         try!(ui.begin(format!("Installing {}", &ident)));
-        let mut ident = ident;
-        if !ident.fully_qualified() {
-            ident = try!(self.fetch_latest_pkg_ident_for(&ident));
-        }
+        let locked = lockfile.as_ref().and_then(|l| l.locked_ident_for(&ident, &self.target));
+        let ident = match locked {
+            Some(locked) => locked,
+            None if !ident.fully_qualified() => try!(self.fetch_latest_pkg_ident_for(&ident)),
+            None => ident,
+        };
         if try!(self.is_package_installed(&ident)) {
+            if let Some(lockfile) = lockfile {
+                let mut artifact = try!(self.get_cached_artifact(ui, ident.clone(), None));
+                try!(self.lock_closure(ui, &mut artifact, None, lockfile));
+            }
             try!(ui.status(Status::Using, &ident));
             try!(ui.end(format!("Install of {} complete with {} new packages installed.",
                                 &ident,
@@ -133,12 +206,21 @@ impl<'a> InstallTask<'a> {
             return Ok(ident);
         }
 
-        self.install_package(ui, ident, None)
+        self.install_package(ui, ident, None, lockfile)
     }
 
-    pub fn from_artifact(&self, ui: &mut UI, artifact_path: &Path) -> Result<PackageIdent> {
+    pub fn from_artifact(&self,
+                         ui: &mut UI,
+                         artifact_path: &Path,
+                         lockfile: Option<&mut Lockfile>)
+                         -> Result<PackageIdent> {
         let ident = try!(PackageArchive::new(artifact_path).ident());
+        let src_path = artifact_path.parent().unwrap();
         if try!(self.is_package_installed(&ident)) {
+            if let Some(lockfile) = lockfile {
+                let mut artifact = PackageArchive::new(artifact_path);
+                try!(self.lock_closure(ui, &mut artifact, Some(src_path), lockfile));
+            }
             try!(ui.status(Status::Using, &ident));
             try!(ui.end(format!("Install of {} complete with {} new packages installed.",
                                 &ident,
@@ -146,26 +228,44 @@ impl<'a> InstallTask<'a> {
             return Ok(ident);
         }
         try!(self.cache_artifact(&ident, artifact_path));
-        let src_path = artifact_path.parent().unwrap();
 
-        self.install_package(ui, ident, Some(src_path))
+        self.install_package(ui, ident, Some(src_path), lockfile)
+    }
+
+    /// Fetches the verified artifacts of a package and of every one of its dependencies, whether
+    /// or not they're installed, into the artifact cache. The package's artifact is last.
+    pub fn cache_closure(&self, ui: &mut UI, ident: PackageIdent) -> Result<Vec<PackageArchive>> {
+        let mut ident = ident;
+        if !ident.fully_qualified() {
+            ident = try!(self.fetch_latest_pkg_ident_for(&ident));
+        }
+        let mut artifact = try!(self.get_cached_artifact(ui, ident, None));
+        let tdeps = try!(artifact.tdeps());
+        let mut artifacts = try!(self.get_cached_artifacts(ui, tdeps, None));
+        artifacts.push(artifact);
+        Ok(artifacts)
     }
 
     fn install_package(&self,
                        ui: &mut UI,
                        ident: PackageIdent,
-                       src_path: Option<&Path>)
+                       src_path: Option<&Path>,
+                       lockfile: Option<&mut Lockfile>)
                        -> Result<PackageIdent> {
         let mut artifact = try!(self.get_cached_artifact(ui, ident.clone(), src_path));
-        let mut artifacts: Vec<PackageArchive> = Vec::new();
+        if let Some(lockfile) = lockfile {
+            try!(self.lock_closure(ui, &mut artifact, src_path, lockfile));
+        }
+        let mut missing: Vec<PackageIdent> = Vec::new();
 
         for ident in try!(artifact.tdeps()) {
             if try!(self.is_package_installed(&ident)) {
                 try!(ui.status(Status::Using, &ident));
             } else {
-                artifacts.push(try!(self.get_cached_artifact(ui, ident, src_path)));
+                missing.push(ident);
             }
         }
+        let mut artifacts = try!(self.get_cached_artifacts(ui, missing, src_path));
         artifacts.push(artifact);
 
         let num_installed = artifacts.len();
@@ -178,33 +278,65 @@ impl<'a> InstallTask<'a> {
         Ok(ident)
     }
 
+    /// Checks the checksums of the artifacts of a package and of every one of its dependencies,
+    /// whether or not they're installed, against the lockfile. Packages which the lockfile
+    /// doesn't pin yet are added to it.
+    fn lock_closure(&self,
+                    ui: &mut UI,
+                    artifact: &mut PackageArchive,
+                    src_path: Option<&Path>,
+                    lockfile: &mut Lockfile)
+                    -> Result<()> {
+        let tdeps = try!(artifact.tdeps());
+        for mut dep in try!(self.get_cached_artifacts(ui, tdeps, src_path)) {
+            try!(lockfile.lock(try!(dep.ident()), &self.target, try!(dep.checksum())));
+        }
+        lockfile.lock(try!(artifact.ident()),
+                      &self.target,
+                      try!(artifact.checksum()))
+    }
+
     fn get_cached_artifact(&self,
                            ui: &mut UI,
                            ident: PackageIdent,
                            src_path: Option<&Path>)
                            -> Result<PackageArchive> {
-        if try!(self.is_artifact_cached(&ident)) {
-            debug!("Found {} in artifact cache, skipping remote download",
-                   &ident);
-        } else {
-            if retry(RETRIES,
-                     RETRY_WAIT,
-                     || self.fetch_artifact(ui, &ident, src_path),
-                     |res| res.is_ok())
-                       .is_err() {
-                return Err(Error::from(depot_client::Error::DownloadFailed(format!("We tried {} \
-                                                                                    times but \
-                                                                                    could not \
-                                                                                    download {}. \
-                                                                                    Giving up.",
-                                                                                   RETRIES,
-                                                                                   &ident))));
+        let mut artifacts = try!(self.get_cached_artifacts(ui, vec![ident], src_path));
+        Ok(artifacts.remove(0))
+    }
+
+    /// Returns the verified artifacts of the given packages from the artifact cache, first
+    /// downloading those which aren't cached.
+    fn get_cached_artifacts(&self,
+                            ui: &mut UI,
+                            idents: Vec<PackageIdent>,
+                            src_path: Option<&Path>)
+                            -> Result<Vec<PackageArchive>> {
+        let mut downloads = Vec::new();
+        for ident in idents.iter() {
+            if try!(self.is_artifact_cached(ident)) {
+                debug!("Found {} in artifact cache, skipping remote download",
+                       ident);
+            } else if try!(self.cache_local_artifact(ident, src_path)) ||
+                      try!(self.cache_local_artifact(ident, self.offline_path)) {
+                debug!("Copied {} into artifact cache", ident);
+            } else if let Some(offline_path) = self.offline_path {
+                return Err(Error::OfflineArtifactNotFound(ident.to_string(),
+                                                          offline_path.display().to_string()));
+            } else {
+                try!(ui.status(Status::Downloading, ident));
+                downloads.push(ident.clone());
             }
         }
+        try!(self.fetch_artifacts(ui, downloads));
 
-        let mut artifact = PackageArchive::new(try!(self.cached_artifact_path(&ident)));
-        try!(self.verify_artifact(ui, &ident, &mut artifact));
-        Ok(artifact)
+        let mut artifacts = Vec::new();
+        for ident in idents {
+            let mut artifact = PackageArchive::new(try!(self.cached_artifact_path(&ident)));
+            try!(self.verify_artifact(ui, &ident, &mut artifact));
+            artifacts.push(artifact);
+        }
+        Ok(artifacts)
     }
 
     fn extract_artifact(&self, ui: &mut UI, artifact: &mut PackageArchive) -> Result<()> {
@@ -214,6 +346,10 @@ impl<'a> InstallTask<'a> {
     }
 
     fn is_package_installed(&self, ident: &PackageIdent) -> Result<bool> {
+        // Packages built for another system can't be loaded here, whose target they don't match.
+        if !self.target.is_current() {
+            return Ok(pkg_install_path(ident, Some(self.fs_root_path)).is_dir());
+        }
         match PackageInstall::load(ident, Some(self.fs_root_path)) {
             Ok(_) => Ok(true),
             Err(hcore::Error::PackageNotFound(_)) => Ok(false),
@@ -226,7 +362,7 @@ impl<'a> InstallTask<'a> {
     }
 
     fn cached_artifact_path(&self, ident: &PackageIdent) -> Result<PathBuf> {
-        let name = match ident.archive_name() {
+        let name = match ident.archive_name_for(&self.target) {
             Some(n) => n,
             None => {
                 return Err(Error::HabitatCore(hcore::Error::InvalidPackageIdent(ident.to_string())))
@@ -236,16 +372,17 @@ impl<'a> InstallTask<'a> {
     }
 
     fn fetch_latest_pkg_ident_for(&self, fuzzy_ident: &PackageIdent) -> Result<PackageIdent> {
-        Ok(try!(self.depot_client.show_package(fuzzy_ident)).into())
+        if let Some(offline_path) = self.offline_path {
+            return latest_local_ident_for(fuzzy_ident, &self.target, offline_path);
+        }
+        Ok(try!(self.depot_client.show_package(fuzzy_ident, None)).into())
     }
 
-    fn fetch_artifact(&self,
-                      ui: &mut UI,
-                      ident: &PackageIdent,
-                      src_path: Option<&Path>)
-                      -> Result<()> {
+    /// Copies a package's artifact into the artifact cache from the directory of the artifact
+    /// being installed, returning false if it isn't there.
+    fn cache_local_artifact(&self, ident: &PackageIdent, src_path: Option<&Path>) -> Result<bool> {
         if let Some(src_path) = src_path {
-            let name = match ident.archive_name() {
+            let name = match ident.archive_name_for(&self.target) {
                 Some(n) => n,
                 None => return Err(
                     Error::HabitatCore(hcore::Error::InvalidPackageIdent(ident.to_string()))),
@@ -253,20 +390,62 @@ impl<'a> InstallTask<'a> {
             let local_artifact = src_path.join(name);
             if local_artifact.is_file() {
                 try!(self.cache_artifact(ident, &local_artifact));
-                return Ok(());
+                return Ok(true);
             }
         }
+        Ok(false)
+    }
 
-        try!(ui.status(Status::Downloading, ident));
-        match self.depot_client
-                  .fetch_package(ident, self.cache_artifact_path, ui.progress()) {
-            Ok(_) => Ok(()),
-            Err(depot_client::Error::APIError(StatusCode::NotImplemented, _)) => {
-                println!("Host platform or architecture not supported by the targted depot; \
-                          skipping.");
-                Ok(())
+    /// Downloads the artifacts of the given packages into the artifact cache, up to
+    /// `DOWNLOAD_WORKERS` at a time. Once a download fails for good, no more are started and the
+    /// first failure is returned after those under way finish.
+    fn fetch_artifacts(&self, ui: &mut UI, idents: Vec<PackageIdent>) -> Result<()> {
+        if idents.is_empty() {
+            return Ok(());
+        }
+        let progress = ui.combined_progress(idents.len() as u64);
+        let num_workers = cmp::min(DOWNLOAD_WORKERS, idents.len());
+        let queue = Arc::new(Mutex::new(idents.into_iter().collect::<VecDeque<PackageIdent>>()));
+        let (tx, rx) = mpsc::channel();
+        let mut workers = Vec::new();
+        for _ in 0..num_workers {
+            let depot_client = self.depot_client.clone();
+            let cache_artifact_path = self.cache_artifact_path.to_path_buf();
+            let queue = queue.clone();
+            let progress = progress.clone();
+            let tx = tx.clone();
+            let worker = try!(thread::Builder::new()
+                                  .name(String::from("artifact-download"))
+                                  .spawn(move || {
+                                             download_worker(&depot_client,
+                                                             &cache_artifact_path,
+                                                             &queue,
+                                                             progress.as_ref(),
+                                                             tx)
+                                         }));
+            workers.push(worker);
+        }
+        // Only the workers hold senders now, so the results end once they've all finished.
+        drop(tx);
+
+        let mut failure = None;
+        for result in rx {
+            if let Err(e) = result {
+                queue.lock().expect("Download queue lock poisoned").clear();
+                if failure.is_none() {
+                    failure = Some(e);
+                }
             }
-            Err(e) => Err(Error::from(e)),
+        }
+        for worker in workers {
+            let _ = worker.join();
+        }
+        if let Some(progress) = progress {
+            progress.finish();
+        }
+        match failure {
+            Some(e) => Err(e),
+            None => Ok(()),
         }
     }
 
@@ -281,8 +460,28 @@ impl<'a> InstallTask<'a> {
         Ok(())
     }
 
+    /// Copies a public origin key into the key cache from the offline directory.
+    fn cache_local_origin_key(&self,
+                              ui: &mut UI,
+                              name_with_rev: &str,
+                              offline_path: &Path)
+                              -> Result<()> {
+        let file_name = format!("{}.{}", name_with_rev, PUBLIC_KEY_SUFFIX);
+        let local_key = offline_path.join(&file_name);
+        if !local_key.is_file() {
+            return Err(Error::CryptoKeyError(format!("{} public origin key not found in {}",
+                                                     name_with_rev,
+                                                     offline_path.display())));
+        }
+        try!(fs::create_dir_all(self.cache_key_path));
+        try!(fs::copy(&local_key, self.cache_key_path.join(&file_name)));
+        try!(ui.status(Status::Cached,
+                       format!("{} public origin key", &name_with_rev)));
+        Ok(())
+    }
+
     fn cache_artifact(&self, ident: &PackageIdent, artifact_path: &Path) -> Result<()> {
-        let name = match ident.archive_name() {
+        let name = match ident.archive_name_for(&self.target) {
             Some(n) => n,
             None => {
                 return Err(Error::HabitatCore(hcore::Error::InvalidPackageIdent(ident.to_string())))
@@ -309,13 +508,16 @@ impl<'a> InstallTask<'a> {
             info!("Skipping target validation for this package.");
         } else {
             let artifact_target = try!(artifact.target());
-            try!(artifact_target.validate());
+            try!(artifact_target.validate_for(&self.target));
         }
 
 
         let nwr = try!(artifact::artifact_signer(&artifact.path));
         if let Err(_) = SigKeyPair::get_public_key_path(&nwr, self.cache_key_path) {
-            try!(self.fetch_origin_key(ui, &nwr));
+            match self.offline_path {
+                Some(offline_path) => try!(self.cache_local_origin_key(ui, &nwr, offline_path)),
+                None => try!(self.fetch_origin_key(ui, &nwr)),
+            }
         }
 
         try!(artifact.verify(&self.cache_key_path));
@@ -323,3 +525,376 @@ impl<'a> InstallTask<'a> {
         Ok(())
     }
 }
+
+/// Returns the latest package satisfying the given ident among the artifacts in a directory which
+/// are built for the given target.
+fn latest_local_ident_for(fuzzy_ident: &PackageIdent,
+                          target: &PackageTarget,
+                          path: &Path)
+                          -> Result<PackageIdent> {
+    let mut latest: Option<PackageIdent> = None;
+    for entry in try!(fs::read_dir(path)) {
+        let artifact_path = try!(entry).path();
+        if artifact_path.extension().map_or(true, |ext| ext != "hart") {
+            continue;
+        }
+        let mut artifact = PackageArchive::new(&artifact_path);
+        let ident = match (artifact.target(), artifact.ident()) {
+            (Ok(ref artifact_target), _) if artifact_target != target => continue,
+            (Ok(_), Ok(ident)) => ident,
+            (Err(e), _) | (_, Err(e)) => {
+                debug!("Skipping {}, {}", artifact_path.display(), e);
+                continue;
+            }
+        };
+        if ident.satisfies(fuzzy_ident) && latest.as_ref().map_or(true, |l| ident > *l) {
+            latest = Some(ident);
+        }
+    }
+    latest.ok_or(Error::OfflineArtifactNotFound(fuzzy_ident.to_string(),
+                                                path.display().to_string()))
+}
+
+/// Downloads artifacts from the queue until it is empty, sending the result of each download.
+fn download_worker(depot_client: &Client,
+                   cache_artifact_path: &Path,
+                   queue: &Mutex<VecDeque<PackageIdent>>,
+                   progress: Option<&CombinedProgress>,
+                   tx: mpsc::Sender<Result<()>>) {
+    loop {
+        let ident = match queue.lock().expect("Download queue lock poisoned").pop_front() {
+            Some(ident) => ident,
+            None => break,
+        };
+        let result =
+            fetch_artifact_with_retries(depot_client, &ident, cache_artifact_path, progress);
+        if tx.send(result).is_err() {
+            break;
+        }
+    }
+}
+
+/// Downloads a package's artifact into the artifact cache, retrying a failed download up to
+/// `RETRIES` times. Each retry resumes the download from where the last attempt stopped.
+fn fetch_artifact_with_retries(depot_client: &Client,
+                               ident: &PackageIdent,
+                               cache_artifact_path: &Path,
+                               progress: Option<&CombinedProgress>)
+                               -> Result<()> {
+    let mut last_error = String::new();
+    let result = retry(RETRIES,
+                       RETRY_WAIT,
+                       || {
+        let result = fetch_artifact(depot_client,
+                                    ident,
+                                    cache_artifact_path,
+                                    progress.map(|p| p.bar()));
+        if let Err(ref e) = result {
+            debug!("Failed to download {}, {}", ident, e);
+            last_error = e.to_string();
+        }
+        result
+    },
+                       |res| match *res {
+                           // Retrying won't make the depot host another target.
+                           Ok(_) | Err(Error::UnsupportedTarget(_)) => true,
+                           Err(_) => false,
+                       });
+    match result {
+        Ok(result) => result,
+        Err(_) => {
+            Err(Error::from(depot_client::Error::DownloadFailed(format!("We tried {} times but \
+                                                                         could not download \
+                                                                         {}, {}. Giving up.",
+                                                                        RETRIES,
+                                                                        ident,
+                                                                        last_error))))
+        }
+    }
+}
+
+/// Downloads a package's artifact into the artifact cache, and checks that its checksum matches
+/// the one the depot has for the package. An artifact which doesn't match is removed.
+fn fetch_artifact<D>(depot_client: &Client,
+                     ident: &PackageIdent,
+                     cache_artifact_path: &Path,
+                     progress: Option<D>)
+                     -> Result<()>
+    where D: DisplayProgress + Sized
+{
+    let checksum = try!(depot_client.show_package(ident, None)).get_checksum().to_string();
+    let artifact = match depot_client.fetch_package(ident, cache_artifact_path, progress) {
+        Ok(artifact) => artifact,
+        Err(depot_client::Error::APIError(StatusCode::NotImplemented, _)) => {
+            // A target which was asked for explicitly can't be skipped over.
+            if let Some(target) = depot_client.target() {
+                return Err(Error::UnsupportedTarget(target.to_string()));
+            }
+            println!("Host platform or architecture not supported by the targted depot; \
+                      skipping.");
+            return Ok(());
+        }
+        Err(e) => return Err(Error::from(e)),
+    };
+    let artifact_checksum = try!(artifact.checksum());
+    if artifact_checksum != checksum {
+        try!(fs::remove_file(&artifact.path));
+        return Err(Error::ArtifactChecksumMismatch((artifact.file_name(),
+                                                    artifact_checksum,
+                                                    checksum)));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::fs::{self, File};
+    use std::io::{Read, Write};
+    use std::mem;
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use depot_client::{Client, XFileName};
+    use hcore::crypto::hash;
+    use hcore::package::{PackageIdent, PackageTarget};
+    use hyper::header::{ByteRangeSpec, Range};
+    use hyper::server::{Request, Response, Server};
+    use hyper::status::StatusCode;
+    use hyper::uri::RequestUri;
+    use tempdir::TempDir;
+
+    use error::Error;
+    use ui::{Coloring, ProgressBar, UI};
+    use super::{fetch_artifact, latest_local_ident_for, InstallTask};
+
+    fn fixtures() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures")
+    }
+
+    /// Starts a depot which serves the given artifacts, keyed by the fully qualified ident of
+    /// their package, along with the checksum it claims each artifact has. Byte ranges of
+    /// artifacts are served like the real depot serves them, and only artifacts built for the
+    /// target of the running system are hosted.
+    fn fake_depot(artifacts: HashMap<String, (Vec<u8>, String)>) -> String {
+        let listening = Server::http("127.0.0.1:0")
+            .unwrap()
+            .handle(move |req: Request, mut res: Response| {
+                let uri = match req.uri {
+                    RequestUri::AbsolutePath(ref uri) => uri.clone(),
+                    _ => String::new(),
+                };
+                let mut parts = uri.splitn(2, '?');
+                let path = parts.next().unwrap().to_string();
+                let query = parts.next().unwrap_or("").to_string();
+                let path = path.trim_left_matches("/v1/depot/pkgs/");
+                let download = path.ends_with("/download");
+                let ident = path.trim_right_matches("/download");
+                let (artifact, checksum) = match artifacts.get(ident) {
+                    Some(&(ref artifact, ref checksum)) => (artifact, checksum),
+                    None => {
+                        *res.status_mut() = StatusCode::NotFound;
+                        res.send(b"").unwrap();
+                        return;
+                    }
+                };
+                let ident = PackageIdent::from_str(ident).unwrap();
+                if !download {
+                    let body = format!("{{\"ident\":{{\"origin\":\"{}\",\"name\":\"{}\",\
+                                        \"version\":\"{}\",\"release\":\"{}\"}},\
+                                        \"checksum\":\"{}\",\"manifest\":\"\",\"deps\":[],\
+                                        \"tdeps\":[],\"exposes\":[],\"config\":\"\"}}",
+                                       ident.origin,
+                                       ident.name,
+                                       ident.version.as_ref().unwrap(),
+                                       ident.release.as_ref().unwrap(),
+                                       checksum);
+                    res.send(body.as_bytes()).unwrap();
+                    return;
+                }
+                let supported = format!("target={}", PackageTarget::default());
+                if query.contains("target=") && !query.contains(&supported) {
+                    *res.status_mut() = StatusCode::NotImplemented;
+                    res.send(b"").unwrap();
+                    return;
+                }
+                let start = match req.headers.get::<Range>() {
+                    Some(&Range::Bytes(ref specs)) => {
+                        match specs[0] {
+                            ByteRangeSpec::AllFrom(start) => start as usize,
+                            _ => 0,
+                        }
+                    }
+                    _ => 0,
+                };
+                res.headers_mut().set(XFileName(ident.archive_name().unwrap()));
+                if start > 0 {
+                    *res.status_mut() = StatusCode::PartialContent;
+                }
+                res.send(&artifact[start..]).unwrap();
+            })
+            .unwrap();
+        let url = format!("http://{}/v1/depot", listening.socket);
+        // Dropping the listener joins the server thread, which never returns.
+        mem::forget(listening);
+        url
+    }
+
+    fn artifact_for(ident: &str) -> (Vec<u8>, String) {
+        let artifact = format!("HART-1\nartifact of {}", ident).into_bytes();
+        let checksum = hash::hash_bytes(&artifact).unwrap();
+        (artifact, checksum)
+    }
+
+    fn cached_artifact(cache: &Path, ident: &str) -> Vec<u8> {
+        let name = PackageIdent::from_str(ident).unwrap().archive_name().unwrap();
+        let mut content = Vec::new();
+        File::open(cache.join(name)).unwrap().read_to_end(&mut content).unwrap();
+        content
+    }
+
+    #[test]
+    fn fetch_artifacts_downloads_every_artifact() {
+        let idents = vec!["core/foo/1.0.0/20170101000000",
+                          "core/bar/1.0.0/20170101000000",
+                          "core/baz/2.0.0/20170101000000",
+                          "core/qux/3.0.0/20170101000000",
+                          "core/quux/4.0.0/20170101000000"];
+        let artifacts = idents.iter().map(|i| (i.to_string(), artifact_for(i))).collect();
+        let url = fake_depot(artifacts);
+        let tmp = TempDir::new("install").unwrap();
+        let cache = tmp.path().join("cache");
+        let keys = tmp.path().join("keys");
+        let task = InstallTask::new(&url,
+                                    "hab",
+                                    "0.0.0",
+                                    tmp.path(),
+                                    &cache,
+                                    &keys,
+                                    None,
+                                    None,
+                                    false)
+                .unwrap();
+        let mut ui = UI::default_with(Coloring::Never, Some(false));
+
+        task.fetch_artifacts(&mut ui,
+                             idents.iter().map(|i| PackageIdent::from_str(i).unwrap()).collect())
+            .unwrap();
+
+        for ident in idents {
+            assert_eq!(cached_artifact(&cache, ident), artifact_for(ident).0);
+        }
+    }
+
+    #[test]
+    fn fetch_artifact_resumes_partial_download() {
+        let ident = "core/foo/1.0.0/20170101000000";
+        let (artifact, checksum) = artifact_for(ident);
+        let mut artifacts = HashMap::new();
+        artifacts.insert(ident.to_string(), (artifact.clone(), checksum));
+        let url = fake_depot(artifacts);
+        let tmp = TempDir::new("install").unwrap();
+        let partial = tmp.path().join(format!("core-foo-1.0.0-20170101000000-{}.part",
+                                              PackageTarget::default()));
+        File::create(&partial).unwrap().write_all(&artifact[..5]).unwrap();
+        let client = Client::new(&url, "hab", "0.0.0", None).unwrap();
+
+        fetch_artifact(&client,
+                       &PackageIdent::from_str(ident).unwrap(),
+                       tmp.path(),
+                       None::<ProgressBar>)
+            .unwrap();
+
+        assert_eq!(cached_artifact(tmp.path(), ident), artifact);
+        assert!(!partial.exists());
+    }
+
+    #[test]
+    fn fetch_artifact_removes_artifact_with_mismatched_checksum() {
+        let ident = "core/foo/1.0.0/20170101000000";
+        let (artifact, _) = artifact_for(ident);
+        let (_, other_checksum) = artifact_for("core/bar/1.0.0/20170101000000");
+        let mut artifacts = HashMap::new();
+        artifacts.insert(ident.to_string(), (artifact, other_checksum));
+        let url = fake_depot(artifacts);
+        let tmp = TempDir::new("install").unwrap();
+        let client = Client::new(&url, "hab", "0.0.0", None).unwrap();
+
+        match fetch_artifact(&client,
+                             &PackageIdent::from_str(ident).unwrap(),
+                             tmp.path(),
+                             None::<ProgressBar>) {
+            Err(Error::ArtifactChecksumMismatch(_)) => (),
+            other => panic!("expected a checksum mismatch, got {:?}", other),
+        }
+        assert_eq!(fs::read_dir(tmp.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn fetch_artifact_fails_for_requested_target_which_depot_does_not_host() {
+        let ident = "core/foo/1.0.0/20170101000000";
+        let mut artifacts = HashMap::new();
+        artifacts.insert(ident.to_string(), artifact_for(ident));
+        let url = fake_depot(artifacts);
+        let tmp = TempDir::new("install").unwrap();
+        let mut client = Client::new(&url, "hab", "0.0.0", None).unwrap();
+        let target = PackageTarget::from_str("armv7-linux").unwrap();
+        client.set_target(target.clone());
+
+        match fetch_artifact(&client,
+                             &PackageIdent::from_str(ident).unwrap(),
+                             tmp.path(),
+                             None::<ProgressBar>) {
+            Err(Error::UnsupportedTarget(t)) => assert_eq!(t, target.to_string()),
+            other => panic!("expected an unsupported target, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn latest_local_ident_for_returns_latest_artifact_for_target() {
+        let linux = PackageTarget::from_str("x86_64-linux").unwrap();
+        let windows = PackageTarget::from_str("x86_64-windows").unwrap();
+
+        assert_eq!(latest_local_ident_for(&PackageIdent::from_str("core/foo").unwrap(),
+                                          &linux,
+                                          &fixtures())
+                           .unwrap(),
+                   PackageIdent::from_str("core/foo/1.1.0/20170201000000").unwrap());
+        assert_eq!(latest_local_ident_for(&PackageIdent::from_str("core/foo/1.0.0").unwrap(),
+                                          &linux,
+                                          &fixtures())
+                           .unwrap(),
+                   PackageIdent::from_str("core/foo/1.0.0/20170101000000").unwrap());
+        assert_eq!(latest_local_ident_for(&PackageIdent::from_str("core/foo").unwrap(),
+                                          &windows,
+                                          &fixtures())
+                           .unwrap(),
+                   PackageIdent::from_str("core/foo/2.0.0/20170301000000").unwrap());
+    }
+
+    #[test]
+    fn latest_local_ident_for_skips_files_which_are_not_artifacts() {
+        let tmp = TempDir::new("install").unwrap();
+        File::create(tmp.path().join("core-foo-3.0.0-20170401000000-x86_64-linux.hart"))
+            .unwrap()
+            .write_all(b"not an artifact")
+            .unwrap();
+        File::create(tmp.path().join("README")).unwrap();
+        fs::copy(fixtures().join("core-foo-1.0.0-20170101000000-x86_64-linux.hart"),
+                 tmp.path().join("core-foo-1.0.0-20170101000000-x86_64-linux.hart"))
+            .unwrap();
+        let linux = PackageTarget::from_str("x86_64-linux").unwrap();
+
+        assert_eq!(latest_local_ident_for(&PackageIdent::from_str("core/foo").unwrap(),
+                                          &linux,
+                                          tmp.path())
+                           .unwrap(),
+                   PackageIdent::from_str("core/foo/1.0.0/20170101000000").unwrap());
+        match latest_local_ident_for(&PackageIdent::from_str("core/bar").unwrap(),
+                                     &linux,
+                                     tmp.path()) {
+            Err(Error::OfflineArtifactNotFound(..)) => (),
+            other => panic!("expected no artifact to be found, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pins the packages installed by `hab pkg install --lock` so that the same install can be
+//! reproduced elsewhere.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg install core/redis --lock habitat.lock
+//! ```
+//!
+//! Will install the latest `core/redis` and, since `habitat.lock` doesn't exist yet, write the
+//! fully qualified ident and artifact checksum of it and of every one of its dependencies to
+//! `habitat.lock`. Running the same command on another machine will install exactly those
+//! releases, failing if the artifact of any of them has a different checksum.
+//!
+//! Artifacts built for different targets are different files, so packages are locked per target.
+//! Installing with `--target aarch64-linux` from a lockfile which only has `x86_64-linux` packages
+//! pins the `aarch64-linux` releases alongside them rather than checking the artifacts against
+//! the checksums of another target's.
+//!
+//! # Format
+//!
+//! A lockfile is a TOML file with a `[[package]]` table for each locked package:
+//!
+//! ```toml
+//! [[package]]
+//! ident = "core/redis/3.2.4/20170514150022"
+//! target = "x86_64-linux"
+//! checksum = "f5b9a3bc6a0ad6e8e1b0d0a4c6fb47c0e5fbb9f65ed7c6f2d1e1b9d8d0a6b0c1"
+//! ```
+
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use hcore::package::{PackageIdent, PackageTarget};
+use hcore::util::{deserialize_using_from_str, serialize_using_to_string};
+use toml;
+
+use error::{Error, Result};
+
+const LOCKFILE_HEADER: &'static str = "# This file is generated by `hab pkg install --lock`. \
+                                       Check it in alongside your project\n\
+                                       # to install the same packages everywhere.\n\n";
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Lockfile {
+    #[serde(default, rename = "package")]
+    packages: Vec<LockedPackage>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LockedPackage {
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_using_to_string")]
+    pub ident: PackageIdent,
+    #[serde(deserialize_with = "deserialize_using_from_str",
+            serialize_with = "serialize_using_to_string")]
+    pub target: PackageTarget,
+    pub checksum: String,
+}
+
+impl Lockfile {
+    /// Reads a lockfile, or returns an empty one if there is no file at the given path.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Lockfile::default());
+        }
+        let mut content = String::new();
+        try!(try!(File::open(path)).read_to_string(&mut content));
+        toml::from_str(&content)
+            .map_err(|e| Error::LockfileMalformed(path.display().to_string(), e.to_string()))
+    }
+
+    /// Writes the lockfile with its packages ordered by ident, then by target.
+    pub fn write(&mut self, path: &Path) -> Result<()> {
+        self.packages
+            .sort_by(|a, b| {
+                         (a.ident.to_string(), a.target.to_string())
+                             .cmp(&(b.ident.to_string(), b.target.to_string()))
+                     });
+        let content = try!(toml::to_string(&*self));
+        let mut file = try!(File::create(path));
+        try!(file.write_all(LOCKFILE_HEADER.as_bytes()));
+        try!(file.write_all(content.as_bytes()));
+        Ok(())
+    }
+
+    /// Returns the latest package locked for the given target which satisfies the given ident,
+    /// if any.
+    pub fn locked_ident_for(&self,
+                            ident: &PackageIdent,
+                            target: &PackageTarget)
+                            -> Option<PackageIdent> {
+        self.packages
+            .iter()
+            .filter(|p| p.target == *target)
+            .map(|p| &p.ident)
+            .filter(|locked| locked.satisfies(ident))
+            .max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .cloned()
+    }
+
+    /// Checks the checksum of a package's artifact for the given target against the one locked
+    /// for the package and target, locking the checksum if they aren't locked yet.
+    pub fn lock(&mut self,
+                ident: PackageIdent,
+                target: &PackageTarget,
+                checksum: String)
+                -> Result<()> {
+        let locked = self.packages
+            .iter()
+            .find(|p| p.ident == ident && p.target == *target)
+            .map(|p| p.checksum.clone());
+        match locked {
+            Some(ref locked) if *locked != checksum => {
+                Err(Error::LockfileChecksumMismatch((format!("{} ({})", ident, target),
+                                                     checksum,
+                                                     locked.clone())))
+            }
+            Some(_) => Ok(()),
+            None => {
+                self.packages
+                    .push(LockedPackage {
+                              ident: ident,
+                              target: target.clone(),
+                              checksum: checksum,
+                          });
+                Ok(())
+            }
+        }
+    }
+
+    pub fn packages(&self) -> &[LockedPackage] {
+        &self.packages
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::{PackageIdent, PackageTarget};
+    use tempdir::TempDir;
+
+    use super::Lockfile;
+
+    fn ident(ident: &str) -> PackageIdent {
+        PackageIdent::from_str(ident).unwrap()
+    }
+
+    fn target(target: &str) -> PackageTarget {
+        PackageTarget::from_str(target).unwrap()
+    }
+
+    #[test]
+    fn lockfile_round_trips_through_file() {
+        let tmp = TempDir::new("lockfile").unwrap();
+        let path = tmp.path().join("habitat.lock");
+        let mut lockfile = Lockfile::default();
+        lockfile
+            .lock(ident("core/redis/3.2.4/20170514150022"),
+                  &target("x86_64-linux"),
+                  "abc".to_string())
+            .unwrap();
+        lockfile
+            .lock(ident("core/glibc/2.22/20160612063629"),
+                  &target("x86_64-linux"),
+                  "def".to_string())
+            .unwrap();
+
+        lockfile.write(&path).unwrap();
+        let loaded = Lockfile::load(&path).unwrap();
+
+        assert_eq!(loaded, lockfile);
+        assert_eq!(loaded.packages()[0].ident, ident("core/glibc/2.22/20160612063629"));
+    }
+
+    #[test]
+    fn lockfile_load_missing_file_is_empty() {
+        let tmp = TempDir::new("lockfile").unwrap();
+
+        let lockfile = Lockfile::load(&tmp.path().join("habitat.lock")).unwrap();
+
+        assert!(lockfile.packages().is_empty());
+    }
+
+    #[test]
+    fn lockfile_lock_fails_on_checksum_mismatch() {
+        let mut lockfile = Lockfile::default();
+        lockfile
+            .lock(ident("core/redis/3.2.4/20170514150022"),
+                  &target("x86_64-linux"),
+                  "abc".to_string())
+            .unwrap();
+
+        assert!(lockfile
+                    .lock(ident("core/redis/3.2.4/20170514150022"),
+                          &target("x86_64-linux"),
+                          "abc".to_string())
+                    .is_ok());
+        assert!(lockfile
+                    .lock(ident("core/redis/3.2.4/20170514150022"),
+                          &target("x86_64-linux"),
+                          "xyz".to_string())
+                    .is_err());
+    }
+
+    #[test]
+    fn lockfile_locked_ident_for_returns_latest_match() {
+        let mut lockfile = Lockfile::default();
+        lockfile
+            .lock(ident("core/redis/3.0.7/20160614153342"),
+                  &target("x86_64-linux"),
+                  "abc".to_string())
+            .unwrap();
+        lockfile
+            .lock(ident("core/redis/3.2.4/20170514150022"),
+                  &target("x86_64-linux"),
+                  "def".to_string())
+            .unwrap();
+
+        assert_eq!(lockfile.locked_ident_for(&ident("core/redis"), &target("x86_64-linux")),
+                   Some(ident("core/redis/3.2.4/20170514150022")));
+        assert_eq!(lockfile.locked_ident_for(&ident("core/redis/3.0.7"), &target("x86_64-linux")),
+                   Some(ident("core/redis/3.0.7/20160614153342")));
+        assert_eq!(lockfile.locked_ident_for(&ident("core/nginx"), &target("x86_64-linux")),
+                   None);
+    }
+
+    #[test]
+    fn lockfile_locks_packages_per_target() {
+        let mut lockfile = Lockfile::default();
+        lockfile
+            .lock(ident("core/redis/3.2.4/20170514150022"),
+                  &target("x86_64-linux"),
+                  "abc".to_string())
+            .unwrap();
+
+        assert_eq!(lockfile.locked_ident_for(&ident("core/redis"), &target("aarch64-linux")),
+                   None);
+        assert!(lockfile
+                    .lock(ident("core/redis/3.2.4/20170514150022"),
+                          &target("aarch64-linux"),
+                          "xyz".to_string())
+                    .is_ok());
+        assert_eq!(lockfile.packages().len(), 2);
+    }
+}
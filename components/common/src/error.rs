@@ -28,6 +28,7 @@ pub type Result<T> = result::Result<T, Error>;
 
 #[derive(Debug)]
 pub enum Error {
+    ArtifactChecksumMismatch((String, String, String)),
     ArtifactIdentMismatch((String, String, String)),
     CantUploadGossipToml,
     CryptoKeyError(String),
@@ -37,16 +38,26 @@ pub enum Error {
     HabitatCore(hcore::Error),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
+    LockfileChecksumMismatch((String, String, String)),
+    LockfileMalformed(String, String),
+    OfflineArtifactNotFound(String, String),
     RootRequired,
     StrFromUtf8Error(str::Utf8Error),
     StringFromUtf8Error(string::FromUtf8Error),
     TomlSerializeError(toml::ser::Error),
+    UnsupportedTarget(String),
     WireDecode(String),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::ArtifactChecksumMismatch((ref a, ref ac, ref c)) => {
+                format!("Artifact checksum {} for `{}' does not match expected checksum {}",
+                        ac,
+                        a,
+                        c)
+            }
             Error::ArtifactIdentMismatch((ref a, ref ai, ref i)) => {
                 format!("Artifact ident {} for `{}' does not match expected ident {}",
                         ai,
@@ -63,12 +74,28 @@ impl fmt::Display for Error {
             Error::FileNameError => format!("Failed to extract a filename"),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::IO(ref err) => format!("{}", err),
+            Error::LockfileChecksumMismatch((ref i, ref ac, ref c)) => {
+                format!("Artifact checksum {} for {} does not match checksum {} in the \
+                         lockfile",
+                        ac,
+                        i,
+                        c)
+            }
+            Error::LockfileMalformed(ref path, ref e) => {
+                format!("Can't read lockfile {}: {}", path, e)
+            }
+            Error::OfflineArtifactNotFound(ref ident, ref path) => {
+                format!("No artifact for {} was found in {}", ident, path)
+            }
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation".to_string()
             }
             Error::StrFromUtf8Error(ref e) => format!("{}", e),
             Error::StringFromUtf8Error(ref e) => format!("{}", e),
             Error::TomlSerializeError(ref e) => format!("Can't serialize TOML: {}", e),
+            Error::UnsupportedTarget(ref t) => {
+                format!("The depot does not host packages for the {} target", t)
+            }
             Error::WireDecode(ref m) => format!("Failed to decode wire message: {}", m),
         };
         write!(f, "{}", msg)
@@ -78,6 +105,7 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::ArtifactChecksumMismatch((_, _, _)) => "Artifact checksum does not match expected checksum",
             Error::ArtifactIdentMismatch((_, _, _)) => "Artifact ident does not match expected ident",
             Error::CantUploadGossipToml => "Can't upload gossip.toml, it's a reserved filename",
             Error::CryptoKeyError(_) => "Missing or invalid key",
@@ -86,10 +114,14 @@ impl error::Error for Error {
             Error::FileNameError => "Failed to extract a filename from a path",
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::LockfileChecksumMismatch((_, _, _)) => "Artifact checksum does not match the checksum in the lockfile",
+            Error::LockfileMalformed(_, _) => "Can't read lockfile",
+            Error::OfflineArtifactNotFound(_, _) => "No artifact for a package was found offline",
             Error::RootRequired => "Root or administrator permissions required to complete operation",
             Error::StrFromUtf8Error(_) => "Failed to convert a string as UTF-8",
             Error::StringFromUtf8Error(_) => "Failed to convert a string as UTF-8",
             Error::TomlSerializeError(_) => "Can't serialize TOML",
+            Error::UnsupportedTarget(_) => "The depot does not host packages for the target",
             Error::WireDecode(_) => "Failed to decode wire message",
         }
     }
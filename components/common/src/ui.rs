@@ -15,6 +15,7 @@
 use std::fmt;
 use std::io::{self, BufRead, BufReader, Read, Write};
 use std::process;
+use std::sync::{Arc, Mutex};
 
 use ansi_term::Colour;
 use depot_client::DisplayProgress;
@@ -166,6 +167,16 @@ impl UI {
         }
     }
 
+    /// Returns a progress display for downloading the given number of files at the same time, or
+    /// `None` if the output is not a terminal.
+    pub fn combined_progress(&mut self, files: u64) -> Option<CombinedProgress> {
+        if self.shell.out.is_a_terminal() {
+            Some(CombinedProgress::new(files))
+        } else {
+            None
+        }
+    }
+
     pub fn title(&mut self, text: &str) -> Result<()> {
         let ref mut stream = self.shell.out;
         match stream.is_colored() {
@@ -688,3 +699,171 @@ impl Write for ProgressBar {
         self.bar.flush()
     }
 }
+
+/// The combined progress of several files downloaded at the same time, drawn as a single line
+/// counting the files downloaded and the bytes written across all of them.
+///
+/// Each download writes to its own `CombinedProgressBar`, which may be sent to another thread.
+#[derive(Clone)]
+pub struct CombinedProgress {
+    state: Arc<Mutex<CombinedState>>,
+}
+
+struct CombinedState {
+    files: u64,
+    files_done: u64,
+    total: u64,
+    current: u64,
+    line: String,
+}
+
+impl CombinedState {
+    fn line(&self) -> String {
+        format!("    {} of {} downloaded, {} of {}",
+                self.files_done,
+                self.files,
+                format_bytes(self.current),
+                format_bytes(self.total))
+    }
+
+    /// Redraws the line, but only when its text has changed.
+    fn draw(&mut self) {
+        let line = self.line();
+        if line != self.line {
+            print!("\r{}", line);
+            io::stdout().flush().ok().expect("flush() fail");
+            self.line = line;
+        }
+    }
+}
+
+impl CombinedProgress {
+    pub fn new(files: u64) -> Self {
+        CombinedProgress {
+            state: Arc::new(Mutex::new(CombinedState {
+                                           files: files,
+                                           files_done: 0,
+                                           total: 0,
+                                           current: 0,
+                                           line: String::new(),
+                                       })),
+        }
+    }
+
+    /// Returns the progress bar of one attempt to download a file.
+    pub fn bar(&self) -> CombinedProgressBar {
+        CombinedProgressBar {
+            state: self.state.clone(),
+            size: 0,
+            current: 0,
+        }
+    }
+
+    pub fn finish(&self) {
+        println!("");
+        io::stdout().flush().ok().expect("flush() fail");
+    }
+}
+
+/// Progress of a single download towards a `CombinedProgress`.
+pub struct CombinedProgressBar {
+    state: Arc<Mutex<CombinedState>>,
+    size: u64,
+    current: u64,
+}
+
+impl DisplayProgress for CombinedProgressBar {
+    fn size(&mut self, size: u64) {
+        let mut state = self.state.lock().expect("Progress lock poisoned");
+        state.total += size;
+        self.size = size;
+        state.draw();
+    }
+
+    fn finish(&mut self) {
+        let mut state = self.state.lock().expect("Progress lock poisoned");
+        state.files_done += 1;
+        state.draw();
+    }
+}
+
+impl Write for CombinedProgressBar {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        {
+            let mut state = self.state.lock().expect("Progress lock poisoned");
+            state.current += buf.len() as u64;
+            state.draw();
+        }
+        self.current += buf.len() as u64;
+        if self.current == self.size {
+            self.finish();
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for CombinedProgressBar {
+    /// An interrupted download will never write the rest of its bytes, so they are taken back
+    /// out of the total; the download which resumes it adds the bytes it has left to write.
+    fn drop(&mut self) {
+        if self.current < self.size {
+            if let Ok(mut state) = self.state.lock() {
+                state.total -= self.size - self.current;
+            }
+        }
+    }
+}
+
+/// Formats a number of bytes in the largest unit in which it is at least 1.
+pub fn format_bytes(bytes: u64) -> String {
+    let units = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < units.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, units[unit])
+    } else {
+        format!("{:.1} {}", value, units[unit])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use depot_client::DisplayProgress;
+
+    use super::{format_bytes, CombinedProgress};
+
+    #[test]
+    fn format_bytes_uses_largest_unit() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(10 * 1024 * 1024), "10.0 MB");
+    }
+
+    #[test]
+    fn combined_progress_takes_back_interrupted_downloads() {
+        let progress = CombinedProgress::new(2);
+        {
+            let mut bar = progress.bar();
+            bar.size(100);
+            bar.write(&[0; 40]).unwrap();
+        }
+        let mut bar = progress.bar();
+        bar.size(60);
+        bar.write(&[0; 60]).unwrap();
+
+        let state = progress.state.lock().unwrap();
+        assert_eq!(state.total, 100);
+        assert_eq!(state.current, 100);
+        assert_eq!(state.files_done, 1);
+    }
+}
@@ -100,6 +100,8 @@ pub enum Error {
     MetaFileIO(io::Error),
     /// Occurs when we can't find an outbound IP address
     NoOutboundAddr,
+    /// Occurs when a cgroup cannot be created or configured.
+    CgroupFailed(String),
     /// Occurs when a suitable installed package cannot be found.
     PackageNotFound(package::PackageIdent),
     /// When an error occurs parsing an integer.
@@ -234,6 +236,7 @@ impl fmt::Display for Error {
             Error::MetaFileNotFound(ref e) => format!("Couldn't read MetaFile: {}, not found", e),
             Error::MetaFileIO(ref e) => format!("IO error while accessing MetaFile: {:?}", e),
             Error::NoOutboundAddr => format!("Failed to discover this hosts outbound IP address"),
+            Error::CgroupFailed(ref e) => format!("Failed to configure cgroup: {}", e),
             Error::PackageNotFound(ref pkg) => {
                 if pkg.fully_qualified() {
                     format!("Cannot find package: {}", pkg)
@@ -316,6 +319,7 @@ impl error::Error for Error {
             Error::MetaFileNotFound(_) => "Failed to read an archive's metafile",
             Error::MetaFileIO(_) => "MetaFile could not be read or written to",
             Error::NoOutboundAddr => "Failed to discover the outbound IP address",
+            Error::CgroupFailed(_) => "Failed to configure cgroup",
             Error::PackageNotFound(_) => "Cannot find a package",
             Error::ParseIntError(_) => "Failed to parse an integer from a string!",
             Error::PermissionFailed(_) => "Failed to set permissions",
@@ -13,15 +13,57 @@
 // limitations under the License.
 
 use libc;
-use std::ffi::OsString;
-use std::path::PathBuf;
+use std::cmp;
+use std::ffi::{CString, OsString};
+use std::fs::{self, File};
+use std::io::{self, Write};
+#[cfg(target_os = "linux")]
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::os::unix::ffi::OsStrExt;
 use std::os::unix::process::CommandExt;
 use std::process::{self, Command};
+use std::ptr;
 use time::{Duration, SteadyTime};
 
 use error::{Error, Result};
 
-use super::{HabExitStatus, ExitStatusExt, ShutdownMethod};
+#[cfg(target_os = "linux")]
+use super::Isolation;
+use super::{HabExitStatus, ExitStatusExt, ResourceLimits, ShutdownMethod};
+
+const CGROUP_ROOT: &'static str = "/sys/fs/cgroup";
+/// Cgroup, beneath the root of each hierarchy, which holds the cgroups of limited processes.
+const CGROUP_PARENT: &'static str = "habitat";
+/// Controllers used by the cgroup v1 hierarchies.
+const CGROUP_V1_CONTROLLERS: [&'static str; 3] = ["cpu", "memory", "pids"];
+/// Signals which the processes left behind by `fork_into_pid_namespace` pass on to their children.
+#[cfg(target_os = "linux")]
+const FORWARDED_SIGNALS: [libc::c_int; 6] = [libc::SIGHUP,
+                                             libc::SIGINT,
+                                             libc::SIGQUIT,
+                                             libc::SIGTERM,
+                                             libc::SIGUSR1,
+                                             libc::SIGUSR2];
+// Values from `<linux/sockios.h>` and `<net/if.h>`, used to bring up the loopback interface of a
+// new network namespace.
+#[cfg(target_os = "linux")]
+const SIOCGIFFLAGS: libc::c_ulong = 0x8913;
+#[cfg(target_os = "linux")]
+const SIOCSIFFLAGS: libc::c_ulong = 0x8914;
+#[cfg(target_os = "linux")]
+const IFF_UP: libc::c_short = 0x1;
+#[cfg(target_os = "linux")]
+const IFNAMSIZ: usize = 16;
+
+/// The parts of `struct ifreq` needed to get and set an interface's flags.
+#[cfg(target_os = "linux")]
+#[repr(C)]
+struct IfFlagsReq {
+    name: [libc::c_char; IFNAMSIZ],
+    flags: libc::c_short,
+    _pad: [u8; 22],
+}
 
 pub fn become_command(command: PathBuf, args: Vec<OsString>) -> Result<()> {
     become_exec_command(command, args)
@@ -38,6 +80,422 @@ pub fn is_alive(pid: u32) -> bool {
     process_group_id >= 0
 }
 
+/// Prepares a command so the process it spawns, and all of that process's children, are held to
+/// the given resource limits and run as the given user and group. `name` identifies the process's
+/// cgroup and must be unique among the limited processes on the host; it may be a relative path,
+/// such as `<supervisor>/<service group>`, to nest the cgroup beneath others.
+///
+/// The process joins its cgroup and sets its rlimits between `fork(2)` and `exec(3)`. This must
+/// happen before it drops root privileges, so the user and group are switched to here instead of
+/// with `Command::uid` and `Command::gid`, which take effect earlier.
+pub fn apply_resource_limits(cmd: &mut Command,
+                             name: &str,
+                             limits: &ResourceLimits,
+                             uid: u32,
+                             gid: u32)
+                             -> Result<()> {
+    let procs_files = if limits.needs_cgroup() {
+        try!(create_cgroup(name, limits))
+    } else {
+        vec![]
+    };
+    // Paths are converted up front as the child should not allocate after forking.
+    let procs_files = procs_files
+        .iter()
+        .map(|path| CString::new(path.as_os_str().as_bytes()).unwrap())
+        .collect::<Vec<CString>>();
+    let open_files_max = limits.open_files_max;
+    cmd.before_exec(move || {
+        for procs_file in procs_files.iter() {
+            try!(join_cgroup(procs_file));
+        }
+        unsafe {
+            if let Some(max) = open_files_max {
+                let rlimit = libc::rlimit {
+                    rlim_cur: max as libc::rlim_t,
+                    rlim_max: max as libc::rlim_t,
+                };
+                try!(check_os(libc::setrlimit(libc::RLIMIT_NOFILE, &rlimit)));
+            }
+            if libc::getuid() == 0 {
+                try!(check_os(libc::setgroups(0, ptr::null())));
+            }
+            try!(check_os(libc::setgid(gid)));
+            try!(check_os(libc::setuid(uid)));
+        }
+        Ok(())
+    });
+    Ok(())
+}
+
+/// Prepares a command so the process it spawns runs in new PID and mount namespaces, and a new
+/// network namespace if asked for. Within them the read-only paths are remounted read-only, except
+/// for the writable paths beneath them, and a `/proc` is mounted which shows only the namespace's
+/// processes.
+///
+/// A new PID namespace only holds the children of the process which creates it, so the spawned
+/// process forks; its child is the namespace's init, which forks again to run the command, while
+/// it stays behind in its place, passing on the signals it's sent and exiting as the command
+/// does. This must happen before the command's resource limits are applied and it drops root
+/// privileges, so this must be called before `apply_resource_limits`.
+#[cfg(target_os = "linux")]
+pub fn apply_isolation(cmd: &mut Command, isolation: &Isolation) -> Result<()> {
+    // Paths are converted up front as the child should not allocate after forking. Paths which
+    // don't exist can't be mounted over, and needn't be.
+    let read_only_paths = mount_points(&isolation.read_only_paths);
+    let writable_paths = mount_points(&isolation.writable_paths);
+    let mut namespaces = libc::CLONE_NEWNS | libc::CLONE_NEWPID;
+    if isolation.network {
+        namespaces |= libc::CLONE_NEWNET;
+    }
+    let network = isolation.network;
+    cmd.before_exec(move || {
+        unsafe {
+            try!(check_os(libc::unshare(namespaces)));
+            // Keep the mounts made below from propagating back to the host's mount namespace.
+            try!(mount(None, b"/\0", None, libc::MS_REC | libc::MS_PRIVATE));
+            // A bind mount takes its flags from the mount it's made from, so the writable paths
+            // are bound before the paths they're beneath are made read-only.
+            for path in writable_paths.iter() {
+                try!(bind_mount(path, false));
+            }
+            for path in read_only_paths.iter() {
+                try!(bind_mount(path, true));
+            }
+            try!(fork_into_pid_namespace(|| {
+                try!(mount(Some(&b"proc\0"[..]),
+                           b"/proc\0",
+                           Some(&b"proc\0"[..]),
+                           libc::MS_NOSUID | libc::MS_NODEV | libc::MS_NOEXEC));
+                if network {
+                    try!(bring_up_loopback());
+                }
+                Ok(())
+            }));
+        }
+        Ok(())
+    });
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn mount_points(paths: &[PathBuf]) -> Vec<CString> {
+    paths
+        .iter()
+        .filter(|path| path.exists())
+        .map(|path| CString::new(path.as_os_str().as_bytes()).unwrap())
+        .collect()
+}
+
+/// Mounts a filesystem; the byte strings must be NUL terminated.
+#[cfg(target_os = "linux")]
+unsafe fn mount(source: Option<&[u8]>,
+                target: &[u8],
+                fstype: Option<&[u8]>,
+                flags: libc::c_ulong)
+                -> io::Result<()> {
+    let as_ptr = |s: Option<&[u8]>| s.map_or(ptr::null(), |s| s.as_ptr() as *const libc::c_char);
+    check_os(libc::mount(as_ptr(source),
+                         target.as_ptr() as *const libc::c_char,
+                         as_ptr(fstype),
+                         flags,
+                         ptr::null()))
+}
+
+/// Bind mounts a path, along with the mounts beneath it, over itself, so that it can be
+/// remounted read-only without affecting the host.
+#[cfg(target_os = "linux")]
+unsafe fn bind_mount(path: &CString, read_only: bool) -> io::Result<()> {
+    let path = path.as_bytes_with_nul();
+    try!(mount(Some(path), path, None, libc::MS_BIND | libc::MS_REC));
+    if read_only {
+        try!(mount(None,
+                   path,
+                   None,
+                   libc::MS_BIND | libc::MS_REMOUNT | libc::MS_RDONLY));
+    }
+    Ok(())
+}
+
+/// Forks the calling process twice, returning in the grandchild. The child is the first process
+/// of the PID namespace created for the caller's children; it acts as the namespace's init,
+/// passing on the signals it's sent to the grandchild, which goes on to run the command, and
+/// reaping every process orphaned in the namespace. The caller never returns either: it passes the
+/// signals it's sent on to the child. Each exits with the same status as the process it waits on,
+/// though as the kernel only lets a namespace's init be killed from outside it, the init exits
+/// with 128 plus the signal the grandchild died of, rather than dying of it.
+///
+/// The init is needed because the kernel drops the signals sent to the first process of a PID
+/// namespace which it has no handler for, so a command which didn't handle `SIGTERM` couldn't be
+/// stopped gracefully, and nothing would reap the processes orphaned by its children.
+///
+/// `mount_proc` is called in the init, and so in the namespace, before the command is forked.
+///
+/// Only async-signal-safe calls are made, so it is safe to call between `fork(2)` and `exec(3)`.
+#[cfg(target_os = "linux")]
+unsafe fn fork_into_pid_namespace<F>(mount_proc: F) -> io::Result<()>
+    where F: FnOnce() -> io::Result<()>
+{
+    // The signals are blocked before forking so that none sent in the meantime are lost. The
+    // processes which wait on their children leave them blocked to take them with `sigwait(3)`,
+    // which also keeps the kernel from dropping those sent to the init.
+    let mut signals: libc::sigset_t = mem::zeroed();
+    let mut old_signals: libc::sigset_t = mem::zeroed();
+    libc::sigemptyset(&mut signals);
+    for signal in FORWARDED_SIGNALS.iter() {
+        libc::sigaddset(&mut signals, *signal);
+    }
+    libc::sigaddset(&mut signals, libc::SIGCHLD);
+    try!(check_os(libc::sigprocmask(libc::SIG_BLOCK, &signals, &mut old_signals)));
+    // Killing the first process of a PID namespace kills every process in it, so nothing is left
+    // running should the caller be killed.
+    try!(fork_and_wait(&signals));
+    try!(mount_proc());
+    try!(fork_and_wait(&signals));
+    check_os(libc::sigprocmask(libc::SIG_SETMASK, &old_signals, ptr::null_mut()))
+}
+
+/// Forks the calling process, returning in the child, which is killed should its parent die. The
+/// parent never returns: it waits on the child as described by `fork_into_pid_namespace`.
+#[cfg(target_os = "linux")]
+unsafe fn fork_and_wait(signals: &libc::sigset_t) -> io::Result<()> {
+    let parent = libc::getpid();
+    let child = libc::fork();
+    if child < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    if child == 0 {
+        try!(check_os(libc::prctl(libc::PR_SET_PDEATHSIG, libc::SIGKILL)));
+        // Should the parent have died before the death signal was asked for, it will never be
+        // sent, so exit as it would have had us do.
+        if libc::getppid() != parent {
+            libc::_exit(128 + libc::SIGKILL);
+        }
+        return Ok(());
+    }
+    // The parent holds none of the files its child needs, and must not hold the pipe through
+    // which the child reports a failure to exec, or the process which spawned it would wait on
+    // the pipe for as long as the parent lives.
+    let max_fd = libc::sysconf(libc::_SC_OPEN_MAX);
+    for fd in 3..max_fd {
+        libc::close(fd as libc::c_int);
+    }
+    loop {
+        let mut signal: libc::c_int = 0;
+        if libc::sigwait(signals, &mut signal) != 0 {
+            continue;
+        }
+        if signal != libc::SIGCHLD {
+            libc::kill(child, signal);
+            continue;
+        }
+        // Other children are only ever the orphans reparented to a namespace's init.
+        loop {
+            let mut status: libc::c_int = 0;
+            let pid = libc::waitpid(-1, &mut status, libc::WNOHANG);
+            if pid <= 0 {
+                break;
+            }
+            if pid == child {
+                exit_as(status);
+            }
+        }
+    }
+}
+
+/// Exits with the status of a child which has exited.
+#[cfg(target_os = "linux")]
+unsafe fn exit_as(status: libc::c_int) -> ! {
+    if !libc::WIFEXITED(status) {
+        // Die of the same signal, so that it is reported as the service's exit status. An init is
+        // not killed by its own signals, and so exits instead.
+        let signal = libc::WTERMSIG(status);
+        let mut unblock: libc::sigset_t = mem::zeroed();
+        libc::sigemptyset(&mut unblock);
+        libc::sigaddset(&mut unblock, signal);
+        libc::signal(signal, libc::SIG_DFL);
+        libc::sigprocmask(libc::SIG_UNBLOCK, &unblock, ptr::null_mut());
+        libc::kill(libc::getpid(), signal);
+        libc::_exit(128 + signal);
+    }
+    libc::_exit(libc::WEXITSTATUS(status));
+}
+
+/// Brings up the loopback interface, which starts out down in a new network namespace.
+#[cfg(target_os = "linux")]
+unsafe fn bring_up_loopback() -> io::Result<()> {
+    let sock = libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0);
+    if sock < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let mut req: IfFlagsReq = mem::zeroed();
+    req.name[0] = b'l' as libc::c_char;
+    req.name[1] = b'o' as libc::c_char;
+    let mut result = libc::ioctl(sock, SIOCGIFFLAGS as _, &mut req as *mut IfFlagsReq);
+    if result == 0 {
+        req.flags |= IFF_UP;
+        result = libc::ioctl(sock, SIOCSIFFLAGS as _, &mut req as *mut IfFlagsReq);
+    }
+    let err = io::Error::last_os_error();
+    libc::close(sock);
+    if result != 0 {
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Removes the cgroup created by `apply_resource_limits` once its processes have exited.
+pub fn release_resource_limits(name: &str) -> Result<()> {
+    let root = Path::new(CGROUP_ROOT);
+    let mut parents = vec![root.join(CGROUP_PARENT)];
+    for controller in CGROUP_V1_CONTROLLERS.iter() {
+        parents.push(root.join(controller).join(CGROUP_PARENT));
+    }
+    for parent in parents.iter() {
+        let cgroup = parent.join(name);
+        if !cgroup.is_dir() {
+            continue;
+        }
+        try!(fs::remove_dir(&cgroup).map_err(|e| cgroup_error(&cgroup, e)));
+        // The cgroups it was nested in are removed once they hold no others, and otherwise left.
+        let mut nested_in = Path::new(name).parent();
+        while let Some(path) = nested_in {
+            if path.as_os_str().is_empty() || fs::remove_dir(parent.join(path)).is_err() {
+                break;
+            }
+            nested_in = path.parent();
+        }
+    }
+    Ok(())
+}
+
+/// Converts cgroup v1 CPU shares, from 2 to 262144, to a cgroup v2 CPU weight, from 1 to 10000.
+fn cpu_shares_to_weight(shares: u64) -> u64 {
+    let shares = cmp::min(cmp::max(shares, 2), 262144);
+    1 + ((shares - 2) * 9999) / 262142
+}
+
+/// Creates and configures the cgroups for a process, returning the `cgroup.procs` files it must
+/// be written to.
+fn create_cgroup(name: &str, limits: &ResourceLimits) -> Result<Vec<PathBuf>> {
+    let root = Path::new(CGROUP_ROOT);
+    if root.join("cgroup.controllers").is_file() {
+        create_unified_cgroup(root, name, limits)
+    } else if root.is_dir() {
+        create_legacy_cgroups(root, name, limits)
+    } else {
+        Err(Error::CgroupFailed(format!("no cgroup filesystem is mounted at {}", CGROUP_ROOT)))
+    }
+}
+
+/// Creates a cgroup in a cgroup v2 unified hierarchy.
+fn create_unified_cgroup(root: &Path,
+                         name: &str,
+                         limits: &ResourceLimits)
+                         -> Result<Vec<PathBuf>> {
+    let parent = root.join(CGROUP_PARENT);
+    try!(fs::create_dir_all(&parent).map_err(|e| cgroup_error(&parent, e)));
+    let mut controllers = vec![];
+    if limits.cpu_shares.is_some() {
+        controllers.push("+cpu");
+    }
+    if limits.memory_bytes.is_some() {
+        controllers.push("+memory");
+    }
+    if limits.pids_max.is_some() {
+        controllers.push("+pids");
+    }
+    // A controller's limits can only be set on a cgroup when each of its ancestors has enabled
+    // the controller for its children.
+    let controllers = controllers.join(" ");
+    try!(write_cgroup_file(&root.join("cgroup.subtree_control"), &controllers));
+    try!(write_cgroup_file(&parent.join("cgroup.subtree_control"), &controllers));
+    let mut ancestor = parent.clone();
+    if let Some(nested_in) = Path::new(name).parent() {
+        for component in nested_in.components() {
+            ancestor.push(component);
+            try!(fs::create_dir_all(&ancestor).map_err(|e| cgroup_error(&ancestor, e)));
+            try!(write_cgroup_file(&ancestor.join("cgroup.subtree_control"), &controllers));
+        }
+    }
+    let cgroup = parent.join(name);
+    try!(fs::create_dir_all(&cgroup).map_err(|e| cgroup_error(&cgroup, e)));
+    if let Some(shares) = limits.cpu_shares {
+        try!(write_cgroup_file(&cgroup.join("cpu.weight"),
+                               &cpu_shares_to_weight(shares).to_string()));
+    }
+    if let Some(bytes) = limits.memory_bytes {
+        try!(write_cgroup_file(&cgroup.join("memory.max"), &bytes.to_string()));
+    }
+    if let Some(max) = limits.pids_max {
+        try!(write_cgroup_file(&cgroup.join("pids.max"), &max.to_string()));
+    }
+    Ok(vec![cgroup.join("cgroup.procs")])
+}
+
+/// Creates a cgroup in each of the cgroup v1 hierarchies needed to enforce the limits.
+fn create_legacy_cgroups(root: &Path,
+                         name: &str,
+                         limits: &ResourceLimits)
+                         -> Result<Vec<PathBuf>> {
+    let settings = [("cpu", "cpu.shares", limits.cpu_shares),
+                    ("memory", "memory.limit_in_bytes", limits.memory_bytes),
+                    ("pids", "pids.max", limits.pids_max)];
+    let mut procs_files = vec![];
+    for &(controller, file, value) in settings.iter() {
+        if let Some(value) = value {
+            let hierarchy = root.join(controller);
+            if !hierarchy.is_dir() {
+                return Err(Error::CgroupFailed(format!("no {} cgroup hierarchy is mounted at {}",
+                                                       controller,
+                                                       hierarchy.display())));
+            }
+            let cgroup = hierarchy.join(CGROUP_PARENT).join(name);
+            try!(fs::create_dir_all(&cgroup).map_err(|e| cgroup_error(&cgroup, e)));
+            try!(write_cgroup_file(&cgroup.join(file), &value.to_string()));
+            procs_files.push(cgroup.join("cgroup.procs"));
+        }
+    }
+    Ok(procs_files)
+}
+
+fn write_cgroup_file(path: &Path, value: &str) -> Result<()> {
+    debug!("Writing '{}' to {}", value, path.display());
+    let mut file = try!(File::create(path).map_err(|e| cgroup_error(path, e)));
+    file.write_all(value.as_bytes()).map_err(|e| cgroup_error(path, e))
+}
+
+fn cgroup_error(path: &Path, err: io::Error) -> Error {
+    Error::CgroupFailed(format!("{}, {}", path.display(), err))
+}
+
+/// Moves the calling process into the cgroup which owns the given `cgroup.procs` file. Only
+/// async-signal-safe calls are made, so it is safe to call between `fork(2)` and `exec(3)`.
+fn join_cgroup(procs_file: &CString) -> io::Result<()> {
+    unsafe {
+        let fd = libc::open(procs_file.as_ptr(), libc::O_WRONLY);
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // Writing 0 moves the writing process.
+        let written = libc::write(fd, b"0".as_ptr() as *const libc::c_void, 1);
+        let err = io::Error::last_os_error();
+        libc::close(fd);
+        if written != 1 {
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+fn check_os(result: libc::c_int) -> io::Result<()> {
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
 /// send a Unix signal to a pid
 fn send_signal(pid: u32, sig: libc::c_int) -> Result<()> {
     unsafe {
@@ -101,10 +559,10 @@ impl Child {
         }
     }
 
-    pub fn kill(&mut self) -> Result<ShutdownMethod> {
+    pub fn kill(&mut self, timeout_secs: u64) -> Result<ShutdownMethod> {
         try!(send_signal(self.pid, libc::SIGTERM));
 
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        let stop_time = SteadyTime::now() + Duration::seconds(timeout_secs as i64);
         loop {
             match self.status() {
                 Ok(status) => {
@@ -156,6 +614,14 @@ mod tests {
     use std::process::Command;
     use super::super::*;
 
+    #[test]
+    fn cpu_shares_convert_to_weight() {
+        assert_eq!(super::cpu_shares_to_weight(2), 1);
+        assert_eq!(super::cpu_shares_to_weight(1024), 39);
+        assert_eq!(super::cpu_shares_to_weight(262144), 10000);
+        assert_eq!(super::cpu_shares_to_weight(0), 1);
+    }
+
     #[test]
     fn running_process_returns_no_exit_status() {
         let mut cmd = Command::new("/bin/bash");
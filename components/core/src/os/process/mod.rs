@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::fmt;
+use std::path::PathBuf;
 use std::process::Child;
 
 use error::Result;
@@ -27,6 +28,14 @@ mod imp;
 mod imp;
 
 pub use self::imp::{become_command, current_pid, is_alive};
+#[cfg(not(windows))]
+pub use self::imp::{apply_resource_limits, release_resource_limits};
+#[cfg(target_os = "linux")]
+pub use self::imp::apply_isolation;
+
+/// Number of seconds a process is given to exit after being asked to terminate, before it is
+/// killed.
+pub const DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 8;
 
 pub enum ShutdownMethod {
     AlreadyExited,
@@ -45,6 +54,44 @@ impl fmt::Display for ShutdownMethod {
     }
 }
 
+/// Limits on the resources available to a process and all of its children. On Linux, the CPU,
+/// memory and PID limits are enforced with a cgroup and the open file limit with an rlimit. Other
+/// platforms ignore them.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ResourceLimits {
+    /// Relative share of CPU time, as in cgroup v1's `cpu.shares`. A process with 2048 shares
+    /// gets twice the CPU time of one with 1024 under contention.
+    pub cpu_shares: Option<u64>,
+    pub memory_bytes: Option<u64>,
+    pub pids_max: Option<u64>,
+    pub open_files_max: Option<u64>,
+}
+
+impl ResourceLimits {
+    pub fn is_empty(&self) -> bool {
+        self.cpu_shares.is_none() && self.memory_bytes.is_none() && self.pids_max.is_none() &&
+        self.open_files_max.is_none()
+    }
+
+    /// Returns true if any of the limits must be enforced with a cgroup.
+    pub fn needs_cgroup(&self) -> bool {
+        self.cpu_shares.is_some() || self.memory_bytes.is_some() || self.pids_max.is_some()
+    }
+}
+
+/// Namespaces in which a process and all of its children are isolated from the rest of the host.
+/// The process gets its own PID and mount namespaces, and optionally its own network namespace.
+/// Only Linux supports them.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Isolation {
+    /// Whether the process gets its own network namespace, which has only a loopback interface.
+    pub network: bool,
+    /// Paths the process may read but not write to.
+    pub read_only_paths: Vec<PathBuf>,
+    /// Paths, beneath `read_only_paths`, which the process may still write to.
+    pub writable_paths: Vec<PathBuf>,
+}
+
 pub struct HabChild {
     inner: imp::Child,
 }
@@ -66,7 +113,13 @@ impl HabChild {
     }
 
     pub fn kill(&mut self) -> Result<ShutdownMethod> {
-        self.inner.kill()
+        self.kill_with_timeout(DEFAULT_SHUTDOWN_TIMEOUT_SECS)
+    }
+
+    /// Ask the process to terminate, waiting up to `timeout_secs` seconds for it to exit before
+    /// killing it.
+    pub fn kill_with_timeout(&mut self, timeout_secs: u64) -> Result<ShutdownMethod> {
+        self.inner.kill(timeout_secs)
     }
 }
 
@@ -158,7 +158,7 @@ impl Child {
         Ok(HabExitStatus { status: Some(exit_status) })
     }
 
-    pub fn kill(&mut self) -> Result<ShutdownMethod> {
+    pub fn kill(&mut self, timeout_secs: u64) -> Result<ShutdownMethod> {
         if self.last_status.is_some() {
             return Ok(ShutdownMethod::AlreadyExited);
         }
@@ -184,7 +184,7 @@ impl Child {
             }
         }
 
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        let stop_time = SteadyTime::now() + Duration::seconds(timeout_secs as i64);
 
         let result;
         loop {
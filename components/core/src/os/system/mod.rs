@@ -39,6 +39,8 @@ pub struct Uname {
 #[derive(Debug, Hash, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub enum Architecture {
     X86_64,
+    Aarch64,
+    Armv7,
 }
 
 impl fmt::Display for Architecture {
@@ -69,6 +71,10 @@ impl FromStr for Architecture {
         let architecture = value.trim().to_lowercase();
         match architecture.as_ref() {
             "x86_64" => Ok(Architecture::X86_64),
+            // `uname` reports the machine of 64-bit ARM kernels as `aarch64` on Linux and as
+            // `arm64` elsewhere, and that of 32-bit ARMv7 kernels as `armv7l`.
+            "aarch64" | "arm64" => Ok(Architecture::Aarch64),
+            "armv7" | "armv7l" => Ok(Architecture::Armv7),
             _ => return Err(Error::InvalidArchitecture(value.to_string())),
         }
     }
@@ -85,15 +85,19 @@ impl PackageIdent {
     }
 
     pub fn archive_name(&self) -> Option<String> {
+        self.archive_name_for(&PackageTarget::default())
+    }
+
+    /// Returns the file name of the package's artifact built for the given target.
+    pub fn archive_name_for(&self, target: &PackageTarget) -> Option<String> {
         if self.fully_qualified() {
-            let default_target = PackageTarget::default();
             Some(format!("{}-{}-{}-{}-{}-{}.hart",
                          self.origin,
                          self.name,
                          self.version.as_ref().unwrap(),
                          self.release.as_ref().unwrap(),
-                         default_target.architecture,
-                         default_target.platform))
+                         target.architecture,
+                         target.platform))
         } else {
             None
         }
@@ -76,6 +76,18 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the fully qualified idents of every installed package release.
+    ///
+    /// An optional `fs_root` path may be provided to list the packages of a filesystem not
+    /// currently rooted at `/`.
+    pub fn installed_idents(fs_root_path: Option<&Path>) -> Result<Vec<PackageIdent>> {
+        let package_root_path = fs::pkg_root_path(fs_root_path);
+        if !package_root_path.exists() {
+            return Ok(vec![]);
+        }
+        Self::package_list(&package_root_path)
+    }
+
     fn resolve_package_install<T>(ident: &PackageIdent,
                                   fs_root_path: Option<T>)
                                   -> Result<PackageInstall>
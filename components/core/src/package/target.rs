@@ -68,10 +68,32 @@ impl PackageTarget {
     pub fn current_architecture() -> Architecture {
         if cfg!(target_arch = "x86_64") {
             Architecture::X86_64
+        } else if cfg!(target_arch = "aarch64") {
+            Architecture::Aarch64
+        } else if cfg!(target_arch = "arm") {
+            Architecture::Armv7
         } else {
             unreachable!("binary built for an unknown architecture")
         }
     }
+
+    /// Returns whether this is the target of the running system.
+    pub fn is_current(&self) -> bool {
+        *self == PackageTarget::default()
+    }
+
+    /// Validates that a package built for this target can be installed for the given target,
+    /// which may be that of another system.
+    pub fn validate_for(&self, target: &PackageTarget) -> Result<()> {
+        if self == target {
+            Ok(())
+        } else {
+            Err(Error::TargetMatchError(format!("Package target ({}) does not match requested \
+                                                 target ({}).",
+                                                self,
+                                                target)))
+        }
+    }
 }
 
 impl Target for PackageTarget {
@@ -150,6 +172,27 @@ mod tests {
         assert_eq!(target.architecture, Architecture::X86_64);
     }
 
+    #[test]
+    fn package_target_from_string_with_arm_architectures() {
+        let target = PackageTarget::from_str("aarch64-linux").unwrap();
+        assert_eq!(target.platform, Platform::Linux);
+        assert_eq!(target.architecture, Architecture::Aarch64);
+        assert_eq!(target.to_string(), "aarch64-linux");
+
+        let target = PackageTarget::from_str("armv7l-linux").unwrap();
+        assert_eq!(target.architecture, Architecture::Armv7);
+        assert_eq!(target.to_string(), "armv7-linux");
+    }
+
+    #[test]
+    fn package_target_validate_for_requested_target() {
+        let target = PackageTarget::from_str("aarch64-linux").unwrap();
+        target.validate_for(&PackageTarget::from_str("aarch64-linux").unwrap()).unwrap();
+        assert!(target
+                    .validate_for(&PackageTarget::from_str("armv7-linux").unwrap())
+                    .is_err());
+    }
+
     #[test]
     #[should_panic]
     fn package_target_with_reversed_target_string() {
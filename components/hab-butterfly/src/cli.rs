@@ -31,6 +31,12 @@ pub fn get() -> App<'static, 'static> {
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_config_apply().aliases(&["a", "ap", "app", "appl"]))
         )
+        (@subcommand secret =>
+            (about: "Commands relating to Habitat service secrets")
+            (aliases: &["se", "sec", "secr", "secre"])
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_secret_apply().aliases(&["a", "ap", "app", "appl"]))
+        )
         (@subcommand file =>
             (about: "Commands relating to Habitat files")
             (aliases: &["f", "fi", "fil"])
@@ -74,6 +80,25 @@ fn sub_config_apply() -> App<'static, 'static> {
     )
 }
 
+fn sub_secret_apply() -> App<'static, 'static> {
+    clap_app!(@subcommand apply =>
+        (about: "Applies encrypted secrets to a group of Habitat Supervisors")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for these secrets (ex: 42)")
+        (@arg FILE: {file_exists_or_stdin}
+            "Path to local file on disk (ex: /tmp/secrets.toml, default: <stdin>)")
+        (@arg ORG: --org +takes_value "Name of service organization to use for encryption")
+        (@arg USER: -u --user +takes_value "Name of a user key to use for encryption")
+    )
+}
+
 fn file_exists(val: String) -> result::Result<(), String> {
     if Path::new(&val).is_file() {
         Ok(())
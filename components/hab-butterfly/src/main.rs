@@ -94,6 +94,12 @@ fn start(ui: &mut UI) -> Result<()> {
                 _ => unreachable!(),
             }
         }
+        ("secret", Some(matches)) => {
+            match matches.subcommand() {
+                ("apply", Some(m)) => try!(sub_secret_apply(ui, m)),
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     };
     Ok(())
@@ -198,6 +204,56 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                  service_pair.as_ref())
 }
 
+fn sub_secret_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+    let file_path = match m.value_of("FILE") {
+        Some("-") | None => None,
+        Some(p) => Some(Path::new(p)),
+    };
+
+    init();
+    let cache = default_cache_key_path(Some(&*FS_ROOT));
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+
+    // Secrets are always encrypted, so both an organization and a user key are required.
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+    match org_param_or_env(&m) {
+        Some(org) => sg.set_org(org),
+        None if sg.org().is_some() => (),
+        None => {
+            return Err(Error::ArgumentError("An organization is required to encrypt secrets, \
+                                             use --org or HAB_ORG"))
+        }
+    }
+    let service_pair = try!(BoxKeyPair::get_latest_pair_for(&sg, &cache));
+    let user_pair = match user_param_or_env(&m) {
+        Some(username) => try!(BoxKeyPair::get_latest_pair_for(username, &cache)),
+        None => {
+            return Err(Error::ArgumentError("A user key is required to encrypt secrets, use \
+                                             --user or HAB_USER"))
+        }
+    };
+    command::secret::apply::start(ui,
+                                  &sg,
+                                  number,
+                                  file_path,
+                                  &peers,
+                                  ring_key.as_ref(),
+                                  &user_pair,
+                                  &service_pair)
+}
+
 fn ui() -> UI {
     let isatty = if henv::var(NONINTERACTIVE_ENVVAR)
            .map(|val| val == "true")
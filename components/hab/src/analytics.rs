@@ -265,6 +265,7 @@ pub fn instrument_subcommand() {
         // reported.
         ("config", "apply", _) |
         ("file", "upload", _) |
+        ("secret", "apply", _) |
         ("pkg", "build", _) |
         ("pkg", "upload", _) |
         ("studio", "build", _) |
@@ -316,7 +317,7 @@ pub fn instrument_clap_error(err: &clap::Error) {
     // Use a pattern match against the first program argument.
     match arg1.as_str() {
         // Match against subcommands which are 2 levels deep.
-        "config" | "file" | "pkg" => {
+        "config" | "file" | "pkg" | "secret" => {
             record_event(Event::CliError,
                          &format!("{:?}--{}--{}--{}", err.kind, PRODUCT, arg1, arg2))
         }
@@ -482,7 +483,8 @@ fn should_send() -> bool {
         ("install", _, _) |
         ("origin", "key", "upload") |
         ("pkg", "install", _) |
-        ("pkg", "upload", _) => true,
+        ("pkg", "upload", _) |
+        ("secret", "apply", _) => true,
         _ => false,
     }
 }
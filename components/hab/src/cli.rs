@@ -18,6 +18,7 @@ use std::str::FromStr;
 
 use clap::{App, AppSettings, Arg};
 use hcore::crypto::keys::PairType;
+use hcore::package::PackageTarget;
 use regex::Regex;
 use url::Url;
 
@@ -76,6 +77,28 @@ pub fn get() -> App<'static, 'static> {
                     "Ring key name, which will encrypt communication messages")
             )
         )
+        (@subcommand secret =>
+            (about: "Commands relating to Habitat service secrets")
+            (aliases: &["se", "sec", "secr", "secre"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand apply =>
+                (about: "Applies encrypted secrets to a group of Habitat Supervisors")
+                (aliases: &["a", "ap", "app", "appl"])
+                (@arg PEER: -p --peer +takes_value
+                    "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+                    (default: 127.0.0.1:9638)")
+                (@arg RING: -r --ring +takes_value
+                    "Ring key name, which will encrypt communication messages")
+                (@arg SERVICE_GROUP: +required {valid_service_group}
+                    "Target service group (ex: redis.default)")
+                (@arg VERSION_NUMBER: +required
+                    "A version number (positive integer) for these secrets (ex: 42)")
+                (@arg FILE: {file_exists_or_stdin}
+                    "Path to local file on disk (ex: /tmp/secrets.toml, default: <stdin>)")
+                (@arg ORG: --org +takes_value "Name of service organization")
+                (@arg USER: -u --user +takes_value "Name of the user key")
+            )
+        )
         (@subcommand origin =>
             (about: "Commands relating to Habitat origin keys")
             (aliases: &["o", "or", "ori", "orig", "origi"])
@@ -149,6 +172,18 @@ pub fn get() -> App<'static, 'static> {
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
             )
             (subcommand: sub_pkg_build())
+            (@subcommand bundle =>
+                (about: "Bundles a package with all of its dependencies and the public origin keys \
+                    which signed them, for installation with 'hab pkg install --offline'")
+                (aliases: &["bun", "bund", "bundl"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                    "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                (@arg OUTPUT: -o --output +takes_value
+                    "Path of the bundle to write \
+                    (default: <origin>-<name>-<version>-<release>-bundle.tar)")
+            )
             (@subcommand env =>
                 (about: "Prints the runtime environment of a specific installed package")
                 (@arg PKG_IDENT: +required +takes_value
@@ -172,6 +207,12 @@ pub fn get() -> App<'static, 'static> {
                 (@arg PKG_IDENT: +required +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
             )
+            (@subcommand gc =>
+                (about: "Uninstalls old releases of installed packages which are no longer in \
+                    use, and deletes old artifacts from the artifact cache")
+                (@arg KEEP: -k --keep +takes_value {valid_keep}
+                    "Number of the newest releases of each package to keep (default: 1)")
+            )
             (@subcommand hash =>
                 (about: "Generates a blake2b hashsum from a target at any given filepath")
                 (aliases: &["ha", "has"])
@@ -211,6 +252,14 @@ pub fn get() -> App<'static, 'static> {
                     "The destination path to the signed Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
+            (@subcommand uninstall =>
+                (about: "Uninstalls a package which no other installed package depends on and \
+                    no running Supervisor has loaded")
+                (aliases: &["un", "uni", "unin", "unins", "uninst", "uninsta", "uninstal"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg FORCE: -f --force "Uninstall the package even if it is still in use")
+            )
             (@subcommand upload =>
                 (about: "Uploads a local Habitat Artifact to a Depot")
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
@@ -448,6 +497,18 @@ fn sub_pkg_install() -> App<'static, 'static> {
             "One or more Habitat package identifiers (ex: acme/redis) and/or filepaths \
             to a Habitat Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
         (@arg BINLINK: -b --binlink "Binlink all binaries from installed package(s)")
+        (@arg OFFLINE: --offline +takes_value {path_exists}
+            "Install from a bundle made by 'hab pkg bundle', or a directory of artifacts and \
+            public origin keys, without contacting a Depot")
+        (@arg TARGET: --target +takes_value {valid_target} conflicts_with[BINLINK]
+            "Install the artifacts built for a target other than this system's, such as \
+            aarch64-linux or armv7-linux. Requires --root")
+        (@arg FS_ROOT: --root +takes_value
+            "Install into an alternate filesystem root (default: $FS_ROOT or /)")
+        (@arg LOCKFILE: --lock +takes_value
+            "Install the releases pinned by a lockfile, failing if any artifact's checksum \
+            differs from the pinned one. Packages which aren't pinned yet are added to it, \
+            creating the lockfile if it doesn't exist")
     );
     sub.arg(Arg::with_name("IGNORE_TARGET")
                 .help("Skips target validation for package installation.")
@@ -464,6 +525,14 @@ fn file_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn path_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).exists() {
+        Ok(())
+    } else {
+        Err(format!("Path: '{}' cannot be found", &val))
+    }
+}
+
 fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
@@ -487,6 +556,20 @@ fn valid_service_group(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_keep(val: String) -> result::Result<(), String> {
+    match val.parse::<usize>() {
+        Ok(keep) if keep > 0 => Ok(()),
+        _ => Err(format!("KEEP: '{}' is not a positive number", &val)),
+    }
+}
+
+fn valid_target(val: String) -> result::Result<(), String> {
+    match PackageTarget::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("TARGET: '{}' is not a valid package target", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
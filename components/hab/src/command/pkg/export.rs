@@ -106,6 +106,9 @@ mod inner {
                                     VERSION,
                                     Path::new(&*FS_ROOT_PATH),
                                     &cache_artifact_path(None),
+                                    None,
+                                    None,
+                                    None,
                                     false));
             }
         }
@@ -0,0 +1,199 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Removes old releases of installed packages and old cached artifacts.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg gc --keep 2
+//! ```
+//!
+//! Will keep the 2 newest releases of every installed package and uninstall the older ones,
+//! except for those which a remaining package depends on or a running Supervisor has loaded. It
+//! will then keep the artifacts of the 2 newest releases of every package in the artifact cache
+//! and delete the older ones.
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use common::ui::{format_bytes, Status, UI};
+use hcore::fs::{am_i_root, pkg_install_path};
+use hcore::package::{PackageArchive, PackageIdent};
+use walkdir::WalkDir;
+
+use super::uninstall;
+use error::{Error, Result};
+
+pub fn start(ui: &mut UI,
+             fs_root_path: &Path,
+             cache_artifact_path: &Path,
+             keep: usize)
+             -> Result<()> {
+    if !am_i_root() {
+        return Err(Error::RootRequired);
+    }
+    try!(ui.begin(format!("Removing all but the {} newest releases of every package", keep)));
+    let (releases, release_bytes) = try!(remove_releases(ui, fs_root_path, keep));
+    let (artifacts, artifact_bytes) = try!(remove_artifacts(ui, cache_artifact_path, keep));
+    try!(ui.end(format!("Removed {} releases and {} cached artifacts, freeing {}",
+                        releases,
+                        artifacts,
+                        format_bytes(release_bytes + artifact_bytes))));
+    Ok(())
+}
+
+/// Uninstalls the old releases of every installed package which are no longer in use, returning
+/// how many were uninstalled and how many bytes they took up.
+fn remove_releases(ui: &mut UI, fs_root_path: &Path, keep: usize) -> Result<(usize, u64)> {
+    let tdeps = try!(uninstall::installed_tdeps(fs_root_path));
+    let installed = tdeps
+        .keys()
+        .map(|ident| (ident.clone(), pkg_install_path(ident, Some(fs_root_path))))
+        .collect();
+    let stale = stale_releases(installed, keep);
+    let stale_idents: HashSet<PackageIdent> = stale
+        .iter()
+        .map(|&(ref ident, _)| ident.clone())
+        .collect();
+
+    // Since dependencies are transitive, a release is in use when it is loaded, when it is a
+    // dependency of a loaded release, or when it is a dependency of a release which is kept.
+    let mut in_use: HashSet<PackageIdent> = HashSet::new();
+    for (_, loaded) in try!(uninstall::loaded_packages(fs_root_path)) {
+        if let Some(deps) = tdeps.get(&loaded) {
+            in_use.extend(deps.iter().cloned());
+        }
+        in_use.insert(loaded);
+    }
+    for (ident, deps) in tdeps.iter() {
+        if !stale_idents.contains(ident) {
+            in_use.extend(deps.iter().cloned());
+        }
+    }
+
+    let (mut count, mut bytes) = (0, 0);
+    for (ident, path) in stale.into_iter() {
+        if in_use.contains(&ident) {
+            debug!("Keeping {}, it is still in use", &ident);
+            continue;
+        }
+        bytes += disk_usage(&path);
+        try!(uninstall::remove_package(ui, &ident, fs_root_path));
+        count += 1;
+    }
+    Ok((count, bytes))
+}
+
+/// Deletes the artifacts of the old releases of every package in the artifact cache, returning
+/// how many were deleted and how many bytes they took up.
+fn remove_artifacts(ui: &mut UI, cache_artifact_path: &Path, keep: usize) -> Result<(usize, u64)> {
+    if !cache_artifact_path.is_dir() {
+        return Ok((0, 0));
+    }
+    let mut artifacts = vec![];
+    for entry in try!(fs::read_dir(cache_artifact_path)) {
+        let path = try!(entry).path();
+        if !path.is_file() {
+            continue;
+        }
+        // Anything which isn't a readable artifact, such as a partial download, is left alone.
+        match PackageArchive::new(&path).ident() {
+            Ok(ident) => artifacts.push((ident, path)),
+            Err(e) => debug!("Skipping {}, {}", path.display(), e),
+        }
+    }
+
+    let (mut count, mut bytes) = (0, 0);
+    for (_, path) in stale_releases(artifacts, keep).into_iter() {
+        bytes += disk_usage(&path);
+        try!(ui.status(Status::Deleting, path.display()));
+        try!(fs::remove_file(&path));
+        count += 1;
+    }
+    Ok((count, bytes))
+}
+
+/// Returns the releases of every package older than its `keep` newest releases, ordered by ident.
+fn stale_releases(releases: Vec<(PackageIdent, PathBuf)>,
+                  keep: usize)
+                  -> Vec<(PackageIdent, PathBuf)> {
+    let mut packages: HashMap<(String, String), Vec<(PackageIdent, PathBuf)>> = HashMap::new();
+    for (ident, path) in releases.into_iter() {
+        packages
+            .entry((ident.origin.clone(), ident.name.clone()))
+            .or_insert(vec![])
+            .push((ident, path));
+    }
+    let mut stale = vec![];
+    for (_, mut releases) in packages.into_iter() {
+        // Newest first
+        releases.sort_by(|&(ref a, _), &(ref b, _)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+        stale.extend(releases.into_iter().skip(keep));
+    }
+    stale.sort_by(|&(ref a, _), &(ref b, _)| a.to_string().cmp(&b.to_string()));
+    stale
+}
+
+/// Returns the number of bytes taken up by the files under the given path.
+fn disk_usage(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.metadata().ok())
+        .filter(|m| m.is_file())
+        .map(|m| m.len())
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use super::stale_releases;
+
+    fn release(ident: &str) -> (PackageIdent, PathBuf) {
+        (PackageIdent::from_str(ident).unwrap(), PathBuf::from(ident))
+    }
+
+    #[test]
+    fn stale_releases_keeps_newest_of_each_package() {
+        let releases = vec![release("core/redis/3.0.7/20160614153342"),
+                            release("core/redis/3.2.4/20170514150022"),
+                            release("core/redis/3.2.4/20170101000000"),
+                            release("core/nginx/1.11.10/20170513215519"),
+                            release("acme/redis/1.0.0/20170101000000")];
+
+        let stale: Vec<String> = stale_releases(releases, 1)
+            .into_iter()
+            .map(|(ident, _)| ident.to_string())
+            .collect();
+
+        assert_eq!(stale,
+                   vec!["core/redis/3.0.7/20160614153342", "core/redis/3.2.4/20170101000000"]);
+    }
+
+    #[test]
+    fn stale_releases_keeps_everything_when_there_are_few_releases() {
+        let releases = vec![release("core/redis/3.0.7/20160614153342"),
+                            release("core/redis/3.2.4/20170514150022")];
+
+        assert!(stale_releases(releases, 2).is_empty());
+    }
+}
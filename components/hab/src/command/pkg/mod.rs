@@ -17,11 +17,13 @@ pub mod build;
 pub mod env;
 pub mod exec;
 pub mod export;
+pub mod gc;
 pub mod hash;
 pub mod header;
 pub mod path;
 pub mod provides;
 pub mod search;
 pub mod sign;
+pub mod uninstall;
 pub mod upload;
 pub mod verify;
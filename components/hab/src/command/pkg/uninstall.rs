@@ -0,0 +1,335 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Uninstalls a package.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg uninstall core/redis
+//! ```
+//!
+//! Will uninstall the latest installed release of `core/redis`, provided that no other installed
+//! package depends on it and that no running Supervisor has it loaded.
+//!
+//! ```bash
+//! $ hab pkg uninstall core/redis/3.0.7/21120102031201 --force
+//! ```
+//!
+//! Will uninstall that exact release, even if it is still in use.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use common::ui::{Status, UI};
+use hcore::fs::{am_i_root, pkg_install_path, pkg_root_path};
+use hcore::os::process;
+use hcore::package::{PackageIdent, PackageInstall};
+use hcore::service::ServiceGroup;
+use serde_json;
+use toml;
+
+use error::{Error, Result};
+
+/// The directory, relative to the filesystem root, holding the state of every Supervisor.
+const SUP_STATE_PATH: &'static str = "hab/sup";
+const SUP_LOCK_FILE: &'static str = "LOCK";
+const SUP_SPECS_DIR: &'static str = "specs";
+const SUP_SERVICES_DATA_FILE: &'static str = "data/services.dat";
+const SPEC_FILE_EXT: &'static str = "spec";
+
+/// The parts of a service recorded in a Supervisor's services state file which are needed to
+/// tell which package it runs.
+#[derive(Deserialize)]
+struct RunningService {
+    service_group: String,
+    package: String,
+}
+
+pub fn start(ui: &mut UI, ident: &PackageIdent, fs_root_path: &Path, force: bool) -> Result<()> {
+    if !am_i_root() {
+        return Err(Error::RootRequired);
+    }
+    let ident = try!(PackageInstall::load(ident, Some(fs_root_path))).ident().clone();
+    try!(ui.begin(format!("Uninstalling {}", &ident)));
+    if force {
+        try!(ui.warn(format!("Not checking whether {} is still in use", &ident)));
+    } else {
+        let dependents = dependents_of(&ident, &try!(installed_tdeps(fs_root_path)));
+        if !dependents.is_empty() {
+            return Err(Error::PackageIsDependency(ident.to_string(),
+                                                  dependents
+                                                      .iter()
+                                                      .map(|d| d.to_string())
+                                                      .collect()));
+        }
+        let services: Vec<String> = try!(loaded_packages(fs_root_path))
+            .into_iter()
+            .filter(|&(_, ref loaded)| *loaded == ident)
+            .map(|(service, _)| service)
+            .collect();
+        if !services.is_empty() {
+            return Err(Error::PackageIsLoaded(ident.to_string(), services));
+        }
+    }
+    try!(remove_package(ui, &ident, fs_root_path));
+    try!(ui.end(format!("Uninstalled {}", &ident)));
+    Ok(())
+}
+
+/// Returns the transitive dependencies of every installed package, keyed by package.
+pub fn installed_tdeps(fs_root_path: &Path) -> Result<HashMap<PackageIdent, Vec<PackageIdent>>> {
+    let mut tdeps = HashMap::new();
+    for ident in try!(PackageInstall::installed_idents(Some(fs_root_path))) {
+        let pkg_install = try!(PackageInstall::load(&ident, Some(fs_root_path)));
+        tdeps.insert(ident, try!(pkg_install.tdeps()));
+    }
+    Ok(tdeps)
+}
+
+/// Returns the packages which have the given package as a transitive dependency.
+pub fn dependents_of(ident: &PackageIdent,
+                     tdeps: &HashMap<PackageIdent, Vec<PackageIdent>>)
+                     -> Vec<PackageIdent> {
+    let mut dependents: Vec<PackageIdent> = tdeps
+        .iter()
+        .filter(|&(_, deps)| deps.contains(ident))
+        .map(|(dependent, _)| dependent.clone())
+        .collect();
+    dependents.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+    dependents
+}
+
+/// Returns the services loaded by every running Supervisor, each along with the installed
+/// package it runs.
+///
+/// The package a service runs is read from its Supervisor's services state file. A service which
+/// isn't recorded there yet will be started with the latest installed release satisfying the
+/// ident in its spec, so that release is returned for it instead.
+///
+/// Only Supervisors keeping their state under the default state path are found.
+pub fn loaded_packages(fs_root_path: &Path) -> Result<Vec<(String, PackageIdent)>> {
+    let mut loaded = vec![];
+    let state_path = fs_root_path.join(SUP_STATE_PATH);
+    if !state_path.is_dir() {
+        return Ok(loaded);
+    }
+    for entry in try!(fs::read_dir(&state_path)) {
+        let sup_path = try!(entry).path();
+        if !is_sup_running(&sup_path) {
+            continue;
+        }
+        let running = try!(read_running_services(&sup_path));
+        let specs_path = sup_path.join(SUP_SPECS_DIR);
+        if specs_path.is_dir() {
+            for spec in try!(fs::read_dir(&specs_path)) {
+                let spec_path = try!(spec).path();
+                if spec_path.extension().and_then(|e| e.to_str()) != Some(SPEC_FILE_EXT) {
+                    continue;
+                }
+                let service = spec_path
+                    .file_stem()
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned();
+                if running.iter().any(|&(ref name, _)| *name == service) {
+                    continue;
+                }
+                let spec_ident = match try!(read_spec_ident(&spec_path)) {
+                    Some(spec_ident) => spec_ident,
+                    None => continue,
+                };
+                // A spec whose package isn't installed doesn't keep any package in use.
+                if let Ok(pkg_install) = PackageInstall::load(&spec_ident, Some(fs_root_path)) {
+                    loaded.push((service, pkg_install.ident().clone()));
+                }
+            }
+        }
+        loaded.extend(running);
+    }
+    Ok(loaded)
+}
+
+/// Removes an installed package release, along with its version, name and origin directories
+/// when they are left empty.
+pub fn remove_package(ui: &mut UI, ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+    let install_path = pkg_install_path(ident, Some(fs_root_path));
+    let pkg_root = pkg_root_path(Some(fs_root_path));
+    try!(ui.status(Status::Deleting, install_path.display()));
+    try!(fs::remove_dir_all(&install_path));
+    let mut dir = install_path.parent().map(PathBuf::from);
+    while let Some(path) = dir {
+        if path == pkg_root || fs::remove_dir(&path).is_err() {
+            break;
+        }
+        dir = path.parent().map(PathBuf::from);
+    }
+    Ok(())
+}
+
+/// Returns whether the Supervisor with the given state path holds its process lock.
+fn is_sup_running(sup_path: &Path) -> bool {
+    let file = match File::open(sup_path.join(SUP_LOCK_FILE)) {
+        Ok(file) => file,
+        Err(_) => return false,
+    };
+    match BufReader::new(file).lines().next() {
+        Some(Ok(line)) => line.parse::<u32>().map(process::is_alive).unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Returns the name of every service recorded in a Supervisor's services state file, each along
+/// with the package it runs.
+fn read_running_services(sup_path: &Path) -> Result<Vec<(String, PackageIdent)>> {
+    let services_path = sup_path.join(SUP_SERVICES_DATA_FILE);
+    let file = match File::open(&services_path) {
+        Ok(file) => file,
+        Err(_) => return Ok(vec![]),
+    };
+    let services: Vec<RunningService> = match serde_json::from_reader(file) {
+        Ok(services) => services,
+        Err(e) => {
+            debug!("Skipping unreadable services state file {}, {}",
+                   services_path.display(),
+                   e);
+            return Ok(vec![]);
+        }
+    };
+    let mut running = vec![];
+    for service in services.into_iter() {
+        let service_group = try!(ServiceGroup::from_str(&service.service_group));
+        running.push((service_group.service().to_string(),
+                      try!(PackageIdent::from_str(&service.package))));
+    }
+    Ok(running)
+}
+
+fn read_spec_ident(spec_path: &Path) -> Result<Option<PackageIdent>> {
+    let mut content = String::new();
+    try!(try!(File::open(spec_path)).read_to_string(&mut content));
+    let spec: toml::Value = match toml::from_str(&content) {
+        Ok(spec) => spec,
+        Err(e) => {
+            debug!("Skipping unreadable spec {}, {}", spec_path.display(), e);
+            return Ok(None);
+        }
+    };
+    match spec.as_table()
+              .and_then(|spec| spec.get("ident"))
+              .and_then(|ident| ident.as_str()) {
+        Some(ident) => Ok(Some(try!(PackageIdent::from_str(ident)))),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use hcore::fs::pkg_install_path;
+    use hcore::os::process;
+    use hcore::package::{PackageIdent, PackageTarget};
+    use tempdir::TempDir;
+
+    use super::{dependents_of, loaded_packages};
+
+    fn ident(ident: &str) -> PackageIdent {
+        PackageIdent::from_str(ident).unwrap()
+    }
+
+    fn write_file(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        File::create(path)
+            .unwrap()
+            .write_all(content.as_bytes())
+            .unwrap();
+    }
+
+    fn install(fs_root: &Path, release: &str) {
+        write_file(&pkg_install_path(&ident(release), Some(fs_root)).join("TARGET"),
+                   &PackageTarget::default().to_string());
+    }
+
+    fn running_sup(fs_root: &Path) -> PathBuf {
+        let sup_path = fs_root.join("hab/sup/default");
+        write_file(&sup_path.join("LOCK"),
+                   &format!("{}\n", process::current_pid()));
+        sup_path
+    }
+
+    #[test]
+    fn dependents_of_returns_packages_depending_on_ident() {
+        let mut tdeps = HashMap::new();
+        tdeps.insert(ident("core/redis/3.2.4/20170514150022"),
+                     vec![ident("core/glibc/2.22/20160612063629")]);
+        tdeps.insert(ident("core/nginx/1.11.10/20170513215519"),
+                     vec![ident("core/openssl/1.0.2j/20161214010423"),
+                          ident("core/glibc/2.22/20160612063629")]);
+        tdeps.insert(ident("core/glibc/2.22/20160612063629"), vec![]);
+
+        assert_eq!(dependents_of(&ident("core/glibc/2.22/20160612063629"), &tdeps),
+                   vec![ident("core/nginx/1.11.10/20170513215519"),
+                        ident("core/redis/3.2.4/20170514150022")]);
+        assert!(dependents_of(&ident("core/redis/3.2.4/20170514150022"), &tdeps).is_empty());
+    }
+
+    #[test]
+    fn loaded_packages_returns_release_recorded_as_running() {
+        let tmp = TempDir::new("uninstall").unwrap();
+        install(tmp.path(), "core/redis/3.0.7/20160614153342");
+        install(tmp.path(), "core/redis/3.2.4/20170514150022");
+        let sup_path = running_sup(tmp.path());
+        write_file(&sup_path.join("specs/redis.spec"), "ident = \"core/redis\"\n");
+        write_file(&sup_path.join("data/services.dat"),
+                   r#"[{"service_group": "redis.default",
+                        "package": "core/redis/3.0.7/20160614153342"}]"#);
+
+        assert_eq!(loaded_packages(tmp.path()).unwrap(),
+                   vec![("redis".to_string(), ident("core/redis/3.0.7/20160614153342"))]);
+    }
+
+    #[test]
+    fn loaded_packages_returns_latest_release_for_service_not_yet_running() {
+        let tmp = TempDir::new("uninstall").unwrap();
+        install(tmp.path(), "core/redis/3.0.7/20160614153342");
+        install(tmp.path(), "core/redis/3.2.4/20170514150022");
+        let sup_path = running_sup(tmp.path());
+        write_file(&sup_path.join("specs/redis.spec"), "ident = \"core/redis\"\n");
+        write_file(&sup_path.join("specs/nginx.spec"), "ident = \"core/nginx\"\n");
+
+        assert_eq!(loaded_packages(tmp.path()).unwrap(),
+                   vec![("redis".to_string(), ident("core/redis/3.2.4/20170514150022"))]);
+    }
+
+    #[test]
+    fn loaded_packages_skips_supervisors_which_are_not_running() {
+        let tmp = TempDir::new("uninstall").unwrap();
+        install(tmp.path(), "core/redis/3.2.4/20170514150022");
+        let sup_path = tmp.path().join("hab/sup/default");
+        write_file(&sup_path.join("specs/redis.spec"), "ident = \"core/redis\"\n");
+        write_file(&sup_path.join("data/services.dat"),
+                   r#"[{"service_group": "redis.default",
+                        "package": "core/redis/3.2.4/20170514150022"}]"#);
+
+        assert!(loaded_packages(tmp.path()).unwrap().is_empty());
+    }
+}
@@ -47,6 +47,8 @@ pub enum Error {
     HandlebarsRenderError(handlebars::TemplateRenderError),
     IO(io::Error),
     PackageArchiveMalformed(String),
+    PackageIsDependency(String, Vec<String>),
+    PackageIsLoaded(String, Vec<String>),
     PathPrefixError(path::StripPrefixError),
     ProvidesError(String),
     RootRequired,
@@ -107,6 +109,18 @@ impl fmt::Display for Error {
                 format!("Package archive was unreadable or contained unexpected contents: {:?}",
                         e)
             }
+            Error::PackageIsDependency(ref ident, ref dependents) => {
+                format!("Can't uninstall {}, it is a dependency of {}. Pass --force to \
+                         uninstall it anyway.",
+                        ident,
+                        dependents.join(", "))
+            }
+            Error::PackageIsLoaded(ref ident, ref services) => {
+                format!("Can't uninstall {}, it is loaded by a running Supervisor as {}. \
+                         Unload it first, or pass --force to uninstall it anyway.",
+                        ident,
+                        services.join(", "))
+            }
             Error::PathPrefixError(ref err) => format!("{}", err),
             Error::ProvidesError(ref err) => format!("Can't find {}", err),
             Error::RootRequired => {
@@ -144,6 +158,8 @@ impl error::Error for Error {
             Error::HandlebarsRenderError(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
             Error::PackageArchiveMalformed(_) => "Package archive was unreadable or had unexpected contents",
+            Error::PackageIsDependency(_, _) => "Package is a dependency of another installed package",
+            Error::PackageIsLoaded(_, _) => "Package is loaded by a running Supervisor",
             Error::PathPrefixError(ref err) => err.description(),
             Error::ProvidesError(_) => "Can't find a package that provides the given search parameter",
             Error::RootRequired => "Root or administrator permissions required to complete operation",
@@ -65,6 +65,9 @@ pub fn command_from_min_pkg(ui: &mut UI,
                                                           VERSION,
                                                           fs_root_path,
                                                           &cache_artifact_path(None),
+                                                          None,
+                                                          None,
+                                                          None,
                                                           false));
             command_from_min_pkg(ui, &command, &ident, &cache_key_path, retry + 1)
         }
@@ -30,11 +30,15 @@ extern crate retry;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+extern crate serde_json;
 extern crate toml;
 extern crate url;
 extern crate uuid;
 extern crate walkdir;
 
+#[cfg(test)]
+extern crate tempdir;
+
 pub mod analytics;
 pub mod cli;
 pub mod command;
@@ -33,13 +33,14 @@ use std::thread;
 
 use clap::{ArgMatches, Shell};
 
+use common::command::package::lockfile::Lockfile;
 use common::ui::{Coloring, UI, NOCOLORING_ENVVAR, NONINTERACTIVE_ENVVAR};
 use hcore::env as henv;
 use hcore::crypto::{init, default_cache_key_path, SigKeyPair};
 use hcore::crypto::keys::PairType;
 use hcore::fs::{cache_artifact_path, cache_analytics_path, cache_key_path};
 use hcore::service::ServiceGroup;
-use hcore::package::PackageIdent;
+use hcore::package::{PackageIdent, PackageTarget};
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
 use hab::{analytics, cli, command, config, AUTH_TOKEN_ENVVAR, ORIGIN_ENVVAR, PRODUCT, VERSION};
@@ -113,16 +114,19 @@ fn start(ui: &mut UI) -> Result<()> {
             match matches.subcommand() {
                 ("binlink", Some(m)) => try!(sub_pkg_binlink(ui, m)),
                 ("build", Some(m)) => try!(sub_pkg_build(ui, m)),
+                ("bundle", Some(m)) => try!(sub_pkg_bundle(ui, m)),
                 ("config", Some(m)) => try!(sub_pkg_config(m)),
                 ("env", Some(m)) => try!(sub_pkg_env(m)),
                 ("exec", Some(m)) => try!(sub_pkg_exec(m, remaining_args)),
                 ("export", Some(m)) => try!(sub_pkg_export(ui, m)),
+                ("gc", Some(m)) => try!(sub_pkg_gc(ui, m)),
                 ("hash", Some(m)) => try!(sub_pkg_hash(m)),
                 ("install", Some(m)) => try!(sub_pkg_install(ui, m)),
                 ("path", Some(m)) => try!(sub_pkg_path(m)),
                 ("provides", Some(m)) => try!(sub_pkg_provides(m)),
                 ("search", Some(m)) => try!(sub_pkg_search(m)),
                 ("sign", Some(m)) => try!(sub_pkg_sign(ui, m)),
+                ("uninstall", Some(m)) => try!(sub_pkg_uninstall(ui, m)),
                 ("upload", Some(m)) => try!(sub_pkg_upload(ui, m)),
                 ("verify", Some(m)) => try!(sub_pkg_verify(ui, m)),
                 ("header", Some(m)) => try!(sub_pkg_header(ui, m)),
@@ -286,6 +290,24 @@ fn sub_pkg_build(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::build::start(ui, plan_context, root, src, keys, reuse)
 }
 
+fn sub_pkg_bundle(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+    let output = m.value_of("OUTPUT").map(Path::new);
+    init();
+
+    try!(common::command::package::bundle::start(ui,
+                                                 url,
+                                                 &ident,
+                                                 PRODUCT,
+                                                 VERSION,
+                                                 &*FS_ROOT,
+                                                 &cache_artifact_path(Some(&*FS_ROOT)),
+                                                 output));
+    Ok(())
+}
+
 fn sub_pkg_config(m: &ArgMatches) -> Result<()> {
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
 
@@ -313,6 +335,13 @@ fn sub_pkg_export(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::export::start(ui, &ident, &export_fmt)
 }
 
+fn sub_pkg_gc(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let keep = m.value_of("KEEP")
+        .map_or(1, |keep| keep.parse().unwrap()); // Validated via clap
+
+    command::pkg::gc::start(ui, &*FS_ROOT, &cache_artifact_path(Some(&*FS_ROOT)), keep)
+}
+
 fn sub_pkg_hash(m: &ArgMatches) -> Result<()> {
     init();
     match m.value_of("SOURCE") {
@@ -348,6 +377,24 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     } else {
         false
     };
+    let offline_path = m.value_of("OFFLINE").map(Path::new);
+    let lock_path = m.value_of("LOCKFILE").map(Path::new);
+    let mut lockfile = match lock_path {
+        Some(lock_path) => Some(try!(Lockfile::load(lock_path))),
+        None => None,
+    };
+    let fs_root = m.value_of("FS_ROOT")
+        .map(PathBuf::from)
+        .unwrap_or(FS_ROOT.to_path_buf());
+    let target = match m.value_of("TARGET") {
+        Some(target) => Some(try!(PackageTarget::from_str(target))),
+        None => None,
+    };
+    // Packages for another system would break this one if they were installed into its root.
+    if target.as_ref().map_or(false, |t| !t.is_current()) && fs_root == Path::new("/") {
+        return Err(Error::ArgumentError("Installing packages for another target requires an \
+                                         alternate filesystem root, set with --root"));
+    }
     init();
 
     for ident_or_artifact in ident_or_artifacts {
@@ -356,14 +403,20 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                                       ident_or_artifact,
                                                       PRODUCT,
                                                       VERSION,
-                                                      &*FS_ROOT,
-                                                      &cache_artifact_path(Some(&*FS_ROOT)),
+                                                      &fs_root,
+                                                      &cache_artifact_path(Some(&fs_root)),
+                                                      offline_path,
+                                                      lockfile.as_mut(),
+                                                      target.clone(),
                                                       ignore_target));
         if m.is_present("BINLINK") {
             let dest_dir = Path::new(m.value_of("DEST_DIR").unwrap_or(DEFAULT_BINLINK_DIR));
-            command::pkg::binlink::binlink_all_in_pkg(ui, &pkg_ident, dest_dir, &*FS_ROOT)?;
+            command::pkg::binlink::binlink_all_in_pkg(ui, &pkg_ident, dest_dir, &fs_root)?;
         }
     }
+    if let (Some(lock_path), Some(mut lockfile)) = (lock_path, lockfile) {
+        try!(lockfile.write(lock_path));
+    }
     Ok(())
 }
 
@@ -399,6 +452,12 @@ fn sub_pkg_sign(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::sign::start(ui, &pair, &src, &dst)
 }
 
+fn sub_pkg_uninstall(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())); // Required via clap
+
+    command::pkg::uninstall::start(ui, &ident, &*FS_ROOT, m.is_present("FORCE"))
+}
+
 fn sub_pkg_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let key_path = cache_key_path(Some(&*FS_ROOT));
@@ -496,7 +555,7 @@ fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
             args.insert(0, OsString::from("config"));
             command::butterfly::start(ui, args)
         }
-        ("config", _) | ("file", _) => {
+        ("config", _) | ("file", _) | ("secret", _) => {
             command::butterfly::start(ui, env::args_os().skip(1).collect())
         }
         ("stu", _) | ("stud", _) | ("studi", _) | ("studio", _) => {
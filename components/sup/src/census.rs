@@ -193,7 +193,9 @@ pub struct CensusGroup {
 
     local_member_id: MemberId,
     population: BTreeMap<MemberId, CensusMember>,
+    replica_ids: Vec<MemberId>,
     update_leader_id: Option<MemberId>,
+    update_election_term: u64,
 }
 impl CensusGroup {
     fn new(sg: ServiceGroup, local_member_id: &MemberId) -> Self {
@@ -203,7 +205,9 @@ impl CensusGroup {
             local_member_id: local_member_id.clone(),
             population: BTreeMap::new(),
             leader_id: None,
+            replica_ids: Vec::new(),
             update_leader_id: None,
+            update_election_term: 0,
         }
     }
 
@@ -218,6 +222,20 @@ impl CensusGroup {
         }
     }
 
+    /// Return the ids of the members the leader has designated as replicas, in the order the
+    /// leader designated them.
+    pub fn replica_ids(&self) -> &[MemberId] {
+        &self.replica_ids
+    }
+
+    /// Return the members the leader has designated as replicas.
+    pub fn replicas(&self) -> Vec<&CensusMember> {
+        self.replica_ids
+            .iter()
+            .filter_map(|id| self.population.get(id))
+            .collect()
+    }
+
     pub fn update_leader(&self) -> Option<&CensusMember> {
         match self.update_leader_id {
             Some(ref id) => self.population.get(id),
@@ -225,10 +243,24 @@ impl CensusGroup {
         }
     }
 
+    /// Return the term of the most recently observed update election.
+    pub fn update_election_term(&self) -> u64 {
+        self.update_election_term
+    }
+
     pub fn members(&self) -> Vec<&CensusMember> {
         self.population.values().map(|cm| cm).collect()
     }
 
+    /// Return the alive members of the group, excluding the update leader, ordered by member id.
+    pub fn update_followers(&self) -> Vec<&CensusMember> {
+        self.population
+            .values()
+            .filter(|cm| cm.alive.unwrap_or(false))
+            .filter(|cm| Some(&cm.member_id) != self.update_leader_id.as_ref())
+            .collect()
+    }
+
     /// Return previous alive peer, the peer to your left in the ordered members list, or None if
     /// you have no alive peers.
     pub fn previous_peer(&self) -> Option<&CensusMember> {
@@ -264,6 +296,11 @@ impl CensusGroup {
 
     fn update_from_election_rumor(&mut self, election: &ElectionRumor) {
         self.leader_id = None;
+        self.replica_ids = if election.is_finished() {
+            election.get_replicas().to_vec()
+        } else {
+            Vec::new()
+        };
         for census_member in self.population.values_mut() {
             if census_member.update_from_election_rumor(election) {
                 self.leader_id = Some(census_member.member_id.clone());
@@ -284,6 +321,7 @@ impl CensusGroup {
 
     fn update_from_election_update_rumor(&mut self, election: &ElectionUpdateRumor) {
         self.update_leader_id = None;
+        self.update_election_term = election.get_term();
         for census_member in self.population.values_mut() {
             if census_member.update_from_election_update_rumor(election) {
                 self.update_leader_id = Some(census_member.member_id.clone());
@@ -308,6 +346,8 @@ pub struct CensusMember {
     sys: SysInfo,
     leader: Option<bool>,
     follower: Option<bool>,
+    replica: Option<bool>,
+    role: Option<String>,
     update_leader: Option<bool>,
     update_follower: Option<bool>,
     election_is_running: Option<bool>,
@@ -324,6 +364,11 @@ pub struct CensusMember {
 }
 
 impl CensusMember {
+    /// Returns the member's role in its service group's election, if one has finished.
+    pub fn role(&self) -> Option<&str> {
+        self.role.as_ref().map(|role| role.as_str())
+    }
+
     pub fn as_protobuf(&self) -> CensusEntryProto {
         let mut cep = CensusEntryProto::new();
         cep.set_member_id(self.member_id.clone());
@@ -391,6 +436,8 @@ impl CensusMember {
             butterfly::rumor::election::Election_Status::Running => {
                 self.leader = Some(false);
                 self.follower = Some(false);
+                self.replica = Some(false);
+                self.role = None;
                 self.election_is_running = Some(true);
                 self.election_is_no_quorum = Some(false);
                 self.election_is_finished = Some(false);
@@ -398,6 +445,8 @@ impl CensusMember {
             butterfly::rumor::election::Election_Status::NoQuorum => {
                 self.leader = Some(false);
                 self.follower = Some(false);
+                self.replica = Some(false);
+                self.role = None;
                 self.election_is_running = Some(false);
                 self.election_is_no_quorum = Some(true);
                 self.election_is_finished = Some(false);
@@ -409,10 +458,16 @@ impl CensusMember {
                 if self.member_id == election.get_member_id() {
                     self.leader = Some(true);
                     self.follower = Some(false);
+                    self.replica = Some(false);
+                    self.role = Some(String::from("leader"));
                     return true;
                 } else {
+                    // Replicas still follow the leader; they are followers with a role.
+                    let replica = election.is_replica(&self.member_id);
                     self.leader = Some(false);
                     self.follower = Some(true);
+                    self.replica = Some(replica);
+                    self.role = Some(String::from(if replica { "replica" } else { "follower" }));
                 }
             }
         }
@@ -549,10 +604,61 @@ mod tests {
                        "member-b".to_string());
             assert_eq!(census_group_two.update_leader().unwrap().member_id,
                        "member-b".to_string());
+            assert_eq!(census_group_two.update_election_term(), 0);
 
             let members = census_group_two.members();
             assert_eq!(members[0].member_id, "member-a");
             assert_eq!(members[1].member_id, "member-b");
         }
+
+        #[test]
+        fn update_from_rumors_with_replicas() {
+            let sys_info = SysInfo::default();
+            let pg_id = PackageIdent::new("core",
+                                          "postgresql",
+                                          Some("9.6.1"),
+                                          Some("20170514001355"));
+            let sg = ServiceGroup::new("postgresql", "prod", None).unwrap();
+            let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+            for member_id in vec!["member-a", "member-b", "member-c", "member-d"] {
+                service_store.insert(ServiceRumor::new(member_id.to_string(),
+                                                       &pg_id,
+                                                       &sg,
+                                                       &sys_info,
+                                                       None));
+            }
+
+            let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+            let mut election = ElectionRumor::new("member-b", sg.clone(), 10);
+            let electorate: Vec<String> = vec!["member-a", "member-b", "member-c", "member-d"]
+                .into_iter()
+                .map(String::from)
+                .collect();
+            election.assign_replicas(&electorate, 2);
+            election.finish();
+            election_store.insert(election);
+
+            let election_update_store: RumorStore<ElectionUpdateRumor> = RumorStore::default();
+            let member_list = MemberList::new();
+            let mut ring = CensusRing::new("member-c".to_string());
+            ring.update_from_rumors(0,
+                                    &service_store,
+                                    &election_store,
+                                    &election_update_store,
+                                    &member_list);
+            let census_group = ring.census_group_for(&sg).unwrap();
+
+            assert_eq!(census_group.leader().unwrap().member_id, "member-b");
+            assert_eq!(census_group.replica_ids(),
+                       &["member-a".to_string(), "member-c".to_string()]);
+            assert_eq!(census_group.replicas().len(), 2);
+            let me = census_group.me().unwrap();
+            assert_eq!(me.replica, Some(true));
+            assert_eq!(me.follower, Some(true));
+            assert_eq!(me.role, Some("replica".to_string()));
+            let members = census_group.members();
+            assert_eq!(members[1].role, Some("leader".to_string()));
+            assert_eq!(members[3].role, Some("follower".to_string()));
+        }
     }
 }
@@ -0,0 +1,220 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renders a package's configuration templates and hooks without running a Supervisor.
+//!
+//! The templates are rendered against the package's default configuration, an optional user
+//! configuration in place of `user.toml`, and an optional census file standing in for the data a
+//! Supervisor would gather from its ring. This lets template changes be tested in CI.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup config render acme/redis --user-toml user.toml --census census.json -o out
+//! ```
+//!
+//! Will render `acme/redis`'s configuration files into `out/config` and its hooks into
+//! `out/hooks`.
+//!
+//! ```bash
+//! $ hab-sup config render acme/redis --config-from ./redis -o out
+//! ```
+//!
+//! Will do the same, but with the templates and `default.toml` of the plan in `./redis` rather
+//! than those of the installed package.
+//!
+//! The census file is a JSON object which is merged over the rendered data, so it usually holds
+//! the `svc` and `bind` namespaces, and may override values in `sys`:
+//!
+//! ```json
+//! {
+//!   "svc": {
+//!     "me": { "member_id": "a", "alive": true, "leader": true },
+//!     "members": [ { "member_id": "a", "alive": true, "sys": { "ip": "10.0.0.1" } } ]
+//!   },
+//!   "bind": { "has_database": false }
+//! }
+//! ```
+
+use std;
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+
+use ansi_term::Colour::Purple;
+use hcore::fs::FS_ROOT_PATH;
+use hcore::package::{PackageIdent, PackageInstall};
+use serde_json;
+use toml;
+
+use config::GossipListenAddr;
+use error::{Error, Result};
+use http_gateway;
+use manager::service::ServiceConfig;
+use manager::service::hooks::RenderPair;
+use supervisor::RuntimeConfig;
+use util::convert;
+
+static LOGKEY: &'static str = "CR";
+
+pub fn run(ident: &PackageIdent,
+           config_from: Option<&Path>,
+           user_toml: Option<&Path>,
+           census: Option<&Path>,
+           output: &Path)
+           -> Result<()> {
+    let package = try!(PackageInstall::load(ident, Some(&*FS_ROOT_PATH)));
+    let config_root = config_from
+        .map(|path| path.to_path_buf())
+        .unwrap_or(package.installed_path.clone());
+    let mut svc_cfg = try!(ServiceConfig::new(&package,
+                                              &try!(runtime_config(&package)),
+                                              config_root,
+                                              Vec::new(),
+                                              &GossipListenAddr::default(),
+                                              &http_gateway::ListenAddr::default()));
+    let user = match user_toml {
+        Some(path) => try!(read_user_toml(path)),
+        None => toml::value::Table::new(),
+    };
+    svc_cfg.set_user_config(user);
+    try!(svc_cfg.validate());
+
+    let mut data = convert::toml_to_json(try!(svc_cfg.to_toml()));
+    if let Some(path) = census {
+        json_merge(&mut data, try!(read_census(path)));
+    }
+
+    let config_output = output.join("config");
+    try!(std::fs::create_dir_all(&config_output));
+    for (config, contents) in try!(svc_cfg.render_config_files(&data)) {
+        try!(write_rendered(&config_output.join(&config), &contents));
+    }
+
+    let hooks_output = output.join("hooks");
+    try!(std::fs::create_dir_all(&hooks_output));
+    for (hook, path) in try!(hook_templates(&svc_cfg.config_root.join("hooks"))) {
+        let pair = try!(RenderPair::new(hooks_output.join(&hook), &path));
+        let contents = try!(pair.template.render("hook", &data));
+        try!(write_rendered(&pair.path, &contents));
+    }
+    Ok(())
+}
+
+/// Builds the runtime configuration from the package alone; unlike a running service, the
+/// package's user and group need not exist on this machine.
+fn runtime_config(package: &PackageInstall) -> Result<RuntimeConfig> {
+    let mut runtime_cfg = RuntimeConfig::default();
+    if let Some(user) = try!(package.svc_user()) {
+        runtime_cfg.svc_user = user;
+    }
+    if let Some(group) = try!(package.svc_group()) {
+        runtime_cfg.svc_group = group;
+    }
+    runtime_cfg.env_vars = try!(package.runtime_environment());
+    Ok(runtime_cfg)
+}
+
+fn read_user_toml(path: &Path) -> Result<toml::value::Table> {
+    let mut file = try!(File::open(path));
+    let mut config = String::new();
+    try!(file.read_to_string(&mut config));
+    toml::de::from_str(&config).map_err(|e| sup_error!(Error::TomlParser(e)))
+}
+
+fn read_census(path: &Path) -> Result<serde_json::Value> {
+    let file = try!(File::open(path)
+        .map_err(|e| sup_error!(Error::BadCensusFile(path.to_path_buf(), e.to_string()))));
+    let census: serde_json::Value = try!(serde_json::from_reader(file)
+        .map_err(|e| sup_error!(Error::BadCensusFile(path.to_path_buf(), e.to_string()))));
+    if !census.is_object() {
+        return Err(sup_error!(Error::BadCensusFile(path.to_path_buf(),
+                                                   "expected a JSON object".to_string())));
+    }
+    Ok(census)
+}
+
+/// Returns the name and path of each hook template in a package's `hooks` directory.
+fn hook_templates(hooks_path: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let mut hooks = Vec::new();
+    let entries = match std::fs::read_dir(hooks_path) {
+        Ok(entries) => entries,
+        Err(e) => {
+            debug!("No hooks directory in package: {}", e);
+            return Ok(hooks);
+        }
+    };
+    for entry in entries {
+        let path = try!(entry).path();
+        if !path.is_file() {
+            continue;
+        }
+        if let Some(name) = path.file_name().map(|n| n.to_string_lossy().into_owned()) {
+            hooks.push((name, path.clone()));
+        }
+    }
+    hooks.sort();
+    Ok(hooks)
+}
+
+fn write_rendered(path: &Path, contents: &str) -> Result<()> {
+    let mut file = try!(File::create(path));
+    try!(file.write_all(contents.as_bytes()));
+    outputln!("Rendered {}", Purple.bold().paint(path.display().to_string()));
+    Ok(())
+}
+
+/// Merges `other` into `me`; objects are merged key by key and any other value replaces the one
+/// it is merged over.
+fn json_merge(me: &mut serde_json::Value, other: serde_json::Value) {
+    match other {
+        serde_json::Value::Object(other) => {
+            if let serde_json::Value::Object(ref mut me) = *me {
+                for (key, value) in other {
+                    if me.contains_key(&key) {
+                        json_merge(me.get_mut(&key).unwrap(), value);
+                    } else {
+                        me.insert(key, value);
+                    }
+                }
+                return;
+            }
+            *me = serde_json::Value::Object(other);
+        }
+        other => *me = other,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+
+    use super::json_merge;
+
+    #[test]
+    fn json_merge_merges_objects_and_replaces_values() {
+        let mut me: serde_json::Value =
+            serde_json::from_str(r#"{"sys": {"ip": "127.0.0.1", "hostname": "localhost"},
+                                     "svc": {}}"#)
+                .unwrap();
+        let other: serde_json::Value =
+            serde_json::from_str(r#"{"sys": {"ip": "10.0.0.1"},
+                                     "svc": {"members": [{"member_id": "a"}]}}"#)
+                .unwrap();
+        json_merge(&mut me, other);
+        assert_eq!(me["sys"]["ip"].as_str(), Some("10.0.0.1"));
+        assert_eq!(me["sys"]["hostname"].as_str(), Some("localhost"));
+        assert_eq!(me["svc"]["members"][0]["member_id"].as_str(), Some("a"));
+    }
+}
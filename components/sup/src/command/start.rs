@@ -81,6 +81,9 @@ pub fn run(cfg: ManagerConfig,
                                                      VERSION,
                                                      Path::new(&*FS_ROOT_PATH),
                                                      &fs::cache_artifact_path(None),
+                                                     None,
+                                                     None,
+                                                     None,
                                                      false)?;
         }
         Manager::save_spec_for(&cfg, spec)?;
@@ -0,0 +1,329 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reports the state of the services run by a Supervisor.
+//!
+//! The services are read from the Supervisor's HTTP gateway when it is reachable, and otherwise
+//! from the services state the Supervisor last wrote to its data directory.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab-sup status
+//! ```
+//!
+//! Will print the ident, release, state, PID, uptime, health and topology role of every service.
+//!
+//! ```bash
+//! $ hab-sup status acme/redis --json
+//! ```
+//!
+//! Will print the same for the `acme/redis` service only, as JSON.
+
+use std::cmp;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::iter;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::Path;
+use std::time::Duration;
+
+use hcore::package::{Identifiable, PackageIdent};
+use hcore::util::deserialize_using_from_str;
+use hyper::client::Client;
+use hyper::status::StatusCode;
+use serde_json;
+use time;
+
+use error::{Error, Result};
+use manager::{Manager, ManagerConfig};
+
+static LOGKEY: &'static str = "ST";
+const GATEWAY_TIMEOUT_SECS: u64 = 2;
+
+/// The parts of a service's state, as written by the Supervisor, which are reported.
+#[derive(Debug, Deserialize)]
+struct ServiceState {
+    service_group: String,
+    #[serde(deserialize_with = "deserialize_using_from_str")]
+    package: PackageIdent,
+    topology: String,
+    #[serde(default)]
+    role: Option<String>,
+    health_check: String,
+    supervisor: ProcessState,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProcessState {
+    pid: Option<u32>,
+    state: String,
+    #[serde(default)]
+    state_entered_at: Option<i64>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ServiceStatus {
+    pub ident: String,
+    pub release: Option<String>,
+    pub service_group: String,
+    pub state: String,
+    pub pid: Option<u32>,
+    /// Seconds the service's process has been up, or `None` if it is not.
+    pub uptime_secs: Option<i64>,
+    pub health: String,
+    pub topology: String,
+    pub role: Option<String>,
+}
+
+impl ServiceStatus {
+    fn from_state(state: ServiceState, now: i64) -> Self {
+        let up = state.supervisor.state.to_lowercase() == "up";
+        ServiceStatus {
+            ident: format!("{}/{}/{}",
+                           state.package.origin(),
+                           state.package.name(),
+                           state.package.version().unwrap_or("")),
+            release: state.package.release().map(|release| release.to_string()),
+            service_group: state.service_group,
+            state: state.supervisor.state.to_lowercase(),
+            pid: state.supervisor.pid,
+            uptime_secs: match state.supervisor.state_entered_at {
+                Some(entered_at) if up => Some(cmp::max(now - entered_at, 0)),
+                _ => None,
+            },
+            health: state.health_check.to_lowercase(),
+            topology: state.topology,
+            role: state.role,
+        }
+    }
+}
+
+pub fn run(cfg: &ManagerConfig, ident: Option<&PackageIdent>, json: bool) -> Result<()> {
+    if !try!(Manager::is_running(cfg)) {
+        let _ = writeln!(&mut io::stderr(),
+                         "The Supervisor is not running; showing the last state it recorded");
+    }
+    let now = time::get_time().sec;
+    let statuses: Vec<ServiceStatus> = try!(read_services(cfg))
+        .into_iter()
+        .filter(|state| ident.map_or(true, |ident| state.package.satisfies(ident)))
+        .map(|state| ServiceStatus::from_state(state, now))
+        .collect();
+    if let Some(ident) = ident {
+        if statuses.is_empty() {
+            return Err(sup_error!(Error::ServiceNotLoaded(ident.clone())));
+        }
+    }
+    if json {
+        println!("{}", serde_json::to_string_pretty(&statuses).unwrap());
+    } else {
+        print!("{}", format_table(&statuses));
+    }
+    Ok(())
+}
+
+/// Reads the services from the Supervisor's HTTP gateway, falling back to the services state
+/// file when the gateway can't be reached.
+fn read_services(cfg: &ManagerConfig) -> Result<Vec<ServiceState>> {
+    match read_services_from_gateway(&cfg.http_listen) {
+        Some(services) => Ok(services),
+        None => read_services_from_file(&Manager::services_data_path_for(cfg)),
+    }
+}
+
+fn read_services_from_gateway(listen: &SocketAddr) -> Option<Vec<ServiceState>> {
+    let mut addr = *listen;
+    if addr.ip().is_unspecified() {
+        addr.set_ip(match addr.ip() {
+                        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+                        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)),
+                    });
+    }
+    let mut client = Client::new();
+    client.set_read_timeout(Some(Duration::from_secs(GATEWAY_TIMEOUT_SECS)));
+    client.set_write_timeout(Some(Duration::from_secs(GATEWAY_TIMEOUT_SECS)));
+    let url = format!("http://{}/services", addr);
+    let mut res = match client.get(&url).send() {
+        Ok(res) => res,
+        Err(e) => {
+            debug!("HTTP gateway at {} is unreachable, {}", addr, e);
+            return None;
+        }
+    };
+    if res.status != StatusCode::Ok {
+        debug!("HTTP gateway at {} responded with {}", addr, res.status);
+        return None;
+    }
+    let mut body = String::new();
+    if let Err(e) = res.read_to_string(&mut body) {
+        debug!("Couldn't read services from the HTTP gateway, {}", e);
+        return None;
+    }
+    match serde_json::from_str(&body) {
+        Ok(services) => Some(services),
+        Err(e) => {
+            debug!("Couldn't parse services from the HTTP gateway, {}", e);
+            None
+        }
+    }
+}
+
+fn read_services_from_file(path: &Path) -> Result<Vec<ServiceState>> {
+    let file = try!(File::open(path)
+        .map_err(|e| sup_error!(Error::BadServicesFile(path.to_path_buf(), e.to_string()))));
+    serde_json::from_reader(file)
+        .map_err(|e| sup_error!(Error::BadServicesFile(path.to_path_buf(), e.to_string())))
+}
+
+fn format_table(statuses: &[ServiceStatus]) -> String {
+    let header = ["SERVICE", "IDENT", "RELEASE", "STATE", "PID", "UPTIME", "HEALTH", "ROLE"];
+    let mut rows: Vec<Vec<String>> = vec![header.iter().map(|h| h.to_string()).collect()];
+    for status in statuses {
+        rows.push(vec![status.service_group.clone(),
+                       status.ident.clone(),
+                       status.release.clone().unwrap_or("-".to_string()),
+                       status.state.clone(),
+                       status.pid.map_or("-".to_string(), |pid| pid.to_string()),
+                       status.uptime_secs.map_or("-".to_string(), format_duration),
+                       status.health.clone(),
+                       role(status)]);
+    }
+    let mut widths = vec![0; header.len()];
+    for row in rows.iter() {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = cmp::max(widths[i], cell.len());
+        }
+    }
+    let mut table = String::new();
+    for row in rows.iter() {
+        let mut line = String::new();
+        for (i, cell) in row.iter().enumerate() {
+            line.push_str(cell);
+            if i < row.len() - 1 {
+                let padding = widths[i] - cell.len() + 2;
+                line.extend(iter::repeat(' ').take(padding));
+            }
+        }
+        table.push_str(&line);
+        table.push('\n');
+    }
+    table
+}
+
+/// Returns the service's role in its service group, or its topology if it is standalone.
+fn role(status: &ServiceStatus) -> String {
+    match status.role {
+        Some(ref role) => role.clone(),
+        None if status.topology == "standalone" => status.topology.clone(),
+        None => "-".to_string(),
+    }
+}
+
+/// Formats a number of seconds as the largest two units, such as `3d4h` or `12m5s`.
+fn format_duration(secs: i64) -> String {
+    let (days, hours, minutes, secs) =
+        (secs / 86400, (secs % 86400) / 3600, (secs % 3600) / 60, secs % 60);
+    if days > 0 {
+        format!("{}d{}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h{}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m{}s", minutes, secs)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json;
+
+    use super::{format_duration, format_table, ServiceState, ServiceStatus};
+
+    const SERVICES: &'static str = r#"[
+        {
+            "service_group": "redis.default",
+            "package": "core/redis/3.2.4/20170514150022",
+            "topology": "leader",
+            "role": "follower",
+            "health_check": "Ok",
+            "depot_url": "https://willem.habitat.sh/v1/depot",
+            "supervisor": {
+                "pid": 1234,
+                "state": "Up",
+                "state_entered_at": 1000
+            }
+        },
+        {
+            "service_group": "nginx.default",
+            "package": "core/nginx/1.11.10/20170513215519",
+            "topology": "standalone",
+            "health_check": "Unknown",
+            "supervisor": {
+                "pid": null,
+                "state": "Down"
+            }
+        }
+    ]"#;
+
+    fn statuses() -> Vec<ServiceStatus> {
+        let services: Vec<ServiceState> = serde_json::from_str(SERVICES).unwrap();
+        services
+            .into_iter()
+            .map(|state| ServiceStatus::from_state(state, 4723))
+            .collect()
+    }
+
+    #[test]
+    fn service_status_from_state() {
+        let statuses = statuses();
+
+        assert_eq!(statuses[0],
+                   ServiceStatus {
+                       ident: String::from("core/redis/3.2.4"),
+                       release: Some(String::from("20170514150022")),
+                       service_group: String::from("redis.default"),
+                       state: String::from("up"),
+                       pid: Some(1234),
+                       uptime_secs: Some(3723),
+                       health: String::from("ok"),
+                       topology: String::from("leader"),
+                       role: Some(String::from("follower")),
+                   });
+        assert_eq!(statuses[1].state, "down");
+        assert_eq!(statuses[1].uptime_secs, None);
+        assert_eq!(statuses[1].role, None);
+    }
+
+    #[test]
+    fn format_table_aligns_columns() {
+        let table = format_table(&statuses());
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("SERVICE        IDENT               RELEASE"));
+        assert!(lines[1].starts_with("redis.default  core/redis/3.2.4    20170514150022"));
+        assert!(lines[1].ends_with("1234  1h2m    ok       follower"));
+        assert!(lines[2].ends_with("-     -       unknown  standalone"));
+    }
+
+    #[test]
+    fn format_duration_uses_two_units() {
+        assert_eq!(format_duration(42), "42s");
+        assert_eq!(format_duration(725), "12m5s");
+        assert_eq!(format_duration(3723), "1h2m");
+        assert_eq!(format_duration(273600), "3d4h");
+    }
+}
@@ -19,18 +19,34 @@
 //! details.
 //!
 //! See the [Config](struct.Config.html) struct for the specific options available.
+//!
+//! The Supervisor can also read its options from a TOML file, described by
+//! [SupConfig](struct.SupConfig.html). Options given on the command line take precedence over
+//! those in the file.
 
 use std::fmt;
-use std::io;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
+use std::path::Path;
 use std::result;
 use std::str::FromStr;
 
+use butterfly::server::timing::Timing;
+use toml;
+
 use error::{Error, Result, SupError};
 
 pub const GOSSIP_DEFAULT_PORT: u16 = 9638;
+/// Environment variable naming the Supervisor's configuration file.
+pub const SUP_CONFIG_ENVVAR: &'static str = "HAB_SUP_CONFIG";
+/// Name of the configuration file read from the Supervisor's state directory when neither
+/// `--config` nor `HAB_SUP_CONFIG` is given.
+pub const SUP_CONFIG_FILE: &'static str = "config.toml";
+/// Default number of seconds between checks for updated packages.
+pub const DEFAULT_UPDATE_FREQUENCY_SECS: u64 = 60;
 
 static LOGKEY: &'static str = "CFG";
 
@@ -91,3 +107,192 @@ impl fmt::Display for GossipListenAddr {
         write!(f, "{}", self.0)
     }
 }
+
+/// Resolves the listen addresses of initial peers, given as `IP[:PORT]` or `HOSTNAME[:PORT]`.
+/// The default gossip port is used for peers without one.
+pub fn resolve_peers<T: AsRef<str>>(peers: &[T]) -> Result<Vec<SocketAddr>> {
+    let mut gossip_peers = Vec::with_capacity(peers.len());
+    for peer in peers {
+        let peer = peer.as_ref();
+        let peer_addr = if peer.find(':').is_some() {
+            peer.to_string()
+        } else {
+            format!("{}:{}", peer, GOSSIP_DEFAULT_PORT)
+        };
+        let addrs: Vec<SocketAddr> = match peer_addr.to_socket_addrs() {
+            Ok(addrs) => addrs.collect(),
+            Err(e) => {
+                outputln!("Failed to resolve peer: {}", peer_addr);
+                return Err(sup_error!(Error::NameLookup(e)));
+            }
+        };
+        gossip_peers.push(addrs[0]);
+    }
+    Ok(gossip_peers)
+}
+
+/// The Supervisor's configuration file. Every option may be omitted, in which case the command
+/// line flag or the built in default is used.
+///
+/// ```toml
+/// listen_gossip = "0.0.0.0:9638"
+/// listen_http = "0.0.0.0:9631"
+/// peers = ["10.0.0.1", "10.0.0.2:9638"]
+/// permanent_peer = false
+/// ring = "production"
+/// org = "acme"
+/// depot_url = "https://depot.example.com/v1/depot"
+/// update_frequency_secs = 60
+///
+/// [gossip]
+/// ping_ms = 1000
+/// pingreq_ms = 2100
+/// gossip_period_ms = 1000
+/// suspicion_timeout_protocol_periods = 3
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SupConfig {
+    pub listen_gossip: Option<String>,
+    pub listen_http: Option<String>,
+    #[serde(default)]
+    pub peers: Vec<String>,
+    pub permanent_peer: Option<bool>,
+    pub ring: Option<String>,
+    pub org: Option<String>,
+    /// Depot used by services which are loaded without a `--url`.
+    pub depot_url: Option<String>,
+    /// Number of seconds between checks for updated packages.
+    pub update_frequency_secs: Option<u64>,
+    #[serde(default)]
+    pub gossip: GossipTimingConfig,
+}
+
+/// Timing of the gossip protocol; see `butterfly::server::timing::Timing`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct GossipTimingConfig {
+    pub ping_ms: Option<i64>,
+    pub pingreq_ms: Option<i64>,
+    pub gossip_period_ms: Option<i64>,
+    pub suspicion_timeout_protocol_periods: Option<i64>,
+}
+
+impl SupConfig {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = File::open(&path).map_err(|err| {
+                sup_error!(Error::SupConfigFileIO(path.as_ref().to_path_buf(), err))
+            })?;
+        let mut file = BufReader::new(file);
+        let mut buf = String::new();
+        file.read_to_string(&mut buf)
+            .map_err(|err| sup_error!(Error::SupConfigFileIO(path.as_ref().to_path_buf(), err)))?;
+        Self::from_str(&buf)
+    }
+
+    /// Returns the gossip timing, using the default for any value which is not set.
+    pub fn timing(&self) -> Timing {
+        let default = Timing::default();
+        Timing::new(self.gossip.ping_ms.unwrap_or(default.ping_ms),
+                    self.gossip.pingreq_ms.unwrap_or(default.pingreq_ms),
+                    self.gossip
+                        .gossip_period_ms
+                        .unwrap_or(default.gossip_period_ms),
+                    self.gossip
+                        .suspicion_timeout_protocol_periods
+                        .unwrap_or(default.suspicion_timeout_protocol_periods))
+    }
+
+    pub fn update_frequency_secs(&self) -> u64 {
+        self.update_frequency_secs
+            .unwrap_or(DEFAULT_UPDATE_FREQUENCY_SECS)
+    }
+
+    fn validate(&self) -> Result<()> {
+        let timing = self.timing();
+        if timing.ping_ms <= 0 || timing.pingreq_ms <= 0 || timing.gossip_period_ms <= 0 ||
+           timing.suspicion_timeout_protocol_periods <= 0 {
+            return Err(sup_error!(Error::InvalidSupConfig("gossip timing values must be \
+                                                           greater than 0"
+                                                                  .to_string())));
+        }
+        if timing.pingreq_ms < timing.ping_ms * 2 {
+            return Err(sup_error!(Error::InvalidSupConfig("gossip.pingreq_ms must be at least \
+                                                           twice gossip.ping_ms"
+                                                                  .to_string())));
+        }
+        if self.update_frequency_secs() == 0 {
+            return Err(sup_error!(Error::InvalidSupConfig("update_frequency_secs must be \
+                                                           greater than 0"
+                                                                  .to_string())));
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SupConfig {
+    type Err = SupError;
+
+    fn from_str(toml: &str) -> result::Result<Self, Self::Err> {
+        let config: SupConfig = toml::from_str(toml)
+            .map_err(|e| sup_error!(Error::SupConfigParse(e)))?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use butterfly::server::timing::Timing;
+
+    use super::*;
+
+    #[test]
+    fn sup_config_from_str() {
+        let toml = r#"
+            listen_gossip = "0.0.0.0:9000"
+            peers = ["10.0.0.1", "10.0.0.2:9638"]
+            permanent_peer = true
+            depot_url = "http://depot.example.com/v1/depot"
+            update_frequency_secs = 300
+
+            [gossip]
+            ping_ms = 500
+            pingreq_ms = 1100
+            "#;
+        let config = SupConfig::from_str(toml).unwrap();
+
+        assert_eq!(config.listen_gossip, Some(String::from("0.0.0.0:9000")));
+        assert_eq!(config.listen_http, None);
+        assert_eq!(config.peers,
+                   vec![String::from("10.0.0.1"), String::from("10.0.0.2:9638")]);
+        assert_eq!(config.permanent_peer, Some(true));
+        assert_eq!(config.update_frequency_secs(), 300);
+        let timing = config.timing();
+        assert_eq!(timing.ping_ms, 500);
+        assert_eq!(timing.pingreq_ms, 1100);
+        assert_eq!(timing.gossip_period_ms, Timing::default().gossip_period_ms);
+    }
+
+    #[test]
+    fn sup_config_defaults() {
+        let config = SupConfig::from_str("").unwrap();
+
+        assert_eq!(config, SupConfig::default());
+        assert_eq!(config.update_frequency_secs(), DEFAULT_UPDATE_FREQUENCY_SECS);
+    }
+
+    #[test]
+    fn sup_config_rejects_unknown_options() {
+        assert!(SupConfig::from_str("listen_gosip = \"0.0.0.0:9000\"").is_err());
+    }
+
+    #[test]
+    fn sup_config_rejects_invalid_timing() {
+        assert!(SupConfig::from_str("[gossip]\nping_ms = 0").is_err());
+        assert!(SupConfig::from_str("[gossip]\nping_ms = 1000\npingreq_ms = 1500").is_err());
+        assert!(SupConfig::from_str("update_frequency_secs = 0").is_err());
+    }
+}
@@ -100,12 +100,16 @@ impl SupError {
 /// All the kinds of errors we produce.
 #[derive(Debug)]
 pub enum Error {
+    BadCensusFile(PathBuf, String),
     BadDataFile(PathBuf, io::Error),
     BadDataPath(PathBuf, io::Error),
     BadDesiredState(String),
+    BadMaintenanceWindow(String),
+    BadServicesFile(PathBuf, String),
     BadSpecsPath(PathBuf, io::Error),
     BadStartStyle(String),
     ButterflyError(butterfly::error::Error),
+    ConfigSchemaViolation(Vec<String>),
     DepotClient(depot_client::Error),
     EnvJoinPathsError(env::JoinPathsError),
     ExecCommandNotFound(String),
@@ -115,12 +119,24 @@ pub enum Error {
     TemplateFileError(handlebars::TemplateFileError),
     TemplateRenderError(handlebars::RenderError),
     InvalidBinding(String),
+    InvalidCanaryBatchPercent(u8),
+    InvalidHealthCheckInterval,
+    InvalidHealthCheckThreshold,
     InvalidKeyParameter(String),
+    InvalidLogFormat(String),
     InvalidPidFile,
+    InvalidQuorumSize,
+    InvalidReplicas,
+    InvalidResourceLimit(&'static str, u64),
+    InvalidRestartBackoff(u64, u64),
+    InvalidRestartPolicy(String),
+    InvalidSchemaType(String),
+    InvalidSupConfig(String),
     InvalidTopology(String),
     InvalidUpdateStrategy(String),
     Io(io::Error),
     IPFailed,
+    IsolationNotSupported,
     MissingRequiredBind(Vec<String>),
     MissingRequiredIdent,
     NameLookup(io::Error),
@@ -132,6 +148,7 @@ pub enum Error {
     ProcessLocked(u32),
     ProcessLockIO(PathBuf, io::Error),
     ServiceLoaded(package::PackageIdent),
+    ServiceNotLoaded(package::PackageIdent),
     ServiceSpecFileIO(PathBuf, io::Error),
     ServiceSpecParse(toml::de::Error),
     ServiceSpecRender(toml::ser::Error),
@@ -141,6 +158,8 @@ pub enum Error {
     SpecWatcherNotify(notify::Error),
     StrFromUtf8Error(str::Utf8Error),
     StringFromUtf8Error(string::FromUtf8Error),
+    SupConfigFileIO(PathBuf, io::Error),
+    SupConfigParse(toml::de::Error),
     TomlEncode(toml::ser::Error),
     TomlMergeError(String),
     TomlParser(toml::de::Error),
@@ -153,6 +172,9 @@ impl fmt::Display for SupError {
     // verbose on, and print it.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let content = match self.err {
+            Error::BadCensusFile(ref path, ref err) => {
+                format!("Unable to read census file, {}, {}", path.display(), err)
+            }
             Error::BadDataFile(ref path, ref err) => {
                 format!("Unable to read or write to data file, {}, {}",
                         path.display(),
@@ -166,6 +188,14 @@ impl fmt::Display for SupError {
             Error::BadDesiredState(ref state) => {
                 format!("Unknown service desired state style '{}'", state)
             }
+            Error::BadMaintenanceWindow(ref window) => {
+                format!("Invalid maintenance window '{}', expected HH:MM-HH:MM", window)
+            }
+            Error::BadServicesFile(ref path, ref err) => {
+                format!("Unable to read the Supervisor's services state from '{}', {}",
+                        path.display(),
+                        err)
+            }
             Error::BadSpecsPath(ref path, ref err) => {
                 format!("Unable to create the specs directory '{}' ({})",
                         path.display(),
@@ -173,6 +203,10 @@ impl fmt::Display for SupError {
             }
             Error::BadStartStyle(ref style) => format!("Unknown service start style '{}'", style),
             Error::ButterflyError(ref err) => format!("Butterfly error: {}", err),
+            Error::ConfigSchemaViolation(ref violations) => {
+                format!("Configuration does not match the package's schema: {}",
+                        violations.join("; "))
+            }
             Error::ExecCommandNotFound(ref c) => {
                 format!("`{}' was not found on the filesystem or in PATH", c)
             }
@@ -189,14 +223,47 @@ impl fmt::Display for SupError {
                          <NAME> is a service name and <SERVICE_GROUP> is a valid service group",
                         binding)
             }
+            Error::InvalidCanaryBatchPercent(ref p) => {
+                format!("Invalid canary batch percentage: {}, must be between 1 and 100", p)
+            }
+            Error::InvalidHealthCheckInterval => {
+                format!("Invalid health check interval, must be at least 1 second")
+            }
+            Error::InvalidHealthCheckThreshold => {
+                format!("Invalid health check failure threshold, must be at least 1")
+            }
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
+            Error::InvalidLogFormat(ref f) => format!("Invalid log format: {}", f),
             Error::InvalidPidFile => format!("Invalid child process PID file"),
+            Error::InvalidQuorumSize => format!("Invalid quorum size, must be at least 1"),
+            Error::InvalidReplicas => {
+                format!("Invalid replicas, the replicated topology requires at least 1 replica")
+            }
+            Error::InvalidResourceLimit(ref name, ref min) => {
+                format!("Invalid resource limit: {} must be at least {}", name, min)
+            }
+            Error::InvalidRestartBackoff(ref backoff, ref max) => {
+                format!("Invalid restart backoff: {}s, must not be greater than the maximum \
+                         backoff of {}s",
+                        backoff,
+                        max)
+            }
+            Error::InvalidRestartPolicy(ref p) => format!("Invalid restart policy: {}", p),
+            Error::InvalidSchemaType(ref t) => {
+                format!("Invalid configuration schema type: {}, must be one of array, boolean, \
+                         datetime, float, integer, string or table",
+                        t)
+            }
+            Error::InvalidSupConfig(ref e) => format!("Invalid Supervisor config file, {}", e),
             Error::InvalidTopology(ref t) => format!("Invalid topology: {}", t),
             Error::InvalidUpdateStrategy(ref s) => format!("Invalid update strategy: {}", s),
             Error::Io(ref err) => format!("{}", err),
             Error::IPFailed => format!("Failed to discover this hosts outbound IP address"),
+            Error::IsolationNotSupported => {
+                format!("Running services in their own namespaces is only supported on Linux")
+            }
             Error::MissingRequiredBind(ref e) => {
                 format!("Missing required bind(s), {}", e.join(", "))
             }
@@ -228,6 +295,9 @@ impl fmt::Display for SupError {
             Error::ServiceLoaded(ref ident) => {
                 format!("Service already loaded, unload '{}' and try again", ident)
             }
+            Error::ServiceNotLoaded(ref ident) => {
+                format!("No service matching '{}' is loaded by the Supervisor", ident)
+            }
             Error::ServiceSpecFileIO(ref path, ref err) => {
                 format!("Unable to write or read to a service spec file at {}, {}",
                         path.display(),
@@ -248,6 +318,14 @@ impl fmt::Display for SupError {
             Error::SpecWatcherNotify(ref e) => format!("{}", e),
             Error::StrFromUtf8Error(ref e) => format!("{}", e),
             Error::StringFromUtf8Error(ref e) => format!("{}", e),
+            Error::SupConfigFileIO(ref path, ref err) => {
+                format!("Unable to read the Supervisor config file at {}, {}",
+                        path.display(),
+                        err)
+            }
+            Error::SupConfigParse(ref err) => {
+                format!("Unable to parse contents of the Supervisor config file, {}", err)
+            }
             Error::TomlEncode(ref e) => format!("Failed to encode TOML: {}", e),
             Error::TomlMergeError(ref e) => format!("Failed to merge TOML: {}", e),
             Error::TomlParser(ref err) => format!("Failed to parse TOML: {}", err),
@@ -270,12 +348,16 @@ impl fmt::Display for SupError {
 impl error::Error for SupError {
     fn description(&self) -> &str {
         match self.err {
+            Error::BadCensusFile(_, _) => "Unable to read census file",
             Error::BadDataFile(_, _) => "Unable to read or write to a data file",
             Error::BadDataPath(_, _) => "Unable to read or write to data directory",
             Error::BadDesiredState(_) => "Unknown desired state in service spec",
+            Error::BadMaintenanceWindow(_) => "Invalid maintenance window in service spec",
+            Error::BadServicesFile(_, _) => "Unable to read the Supervisor's services state",
             Error::BadSpecsPath(_, _) => "Unable to create the specs directory",
             Error::BadStartStyle(_) => "Unknown start style in service spec",
             Error::ButterflyError(ref err) => err.description(),
+            Error::ConfigSchemaViolation(_) => "Configuration does not match the package's schema",
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
             Error::TemplateFileError(ref err) => err.description(),
             Error::TemplateRenderError(ref err) => err.description(),
@@ -285,12 +367,24 @@ impl error::Error for SupError {
             Error::EnvJoinPathsError(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
             Error::InvalidBinding(_) => "Invalid binding parameter",
+            Error::InvalidCanaryBatchPercent(_) => "Invalid canary batch percentage",
+            Error::InvalidHealthCheckInterval => "Invalid health check interval",
+            Error::InvalidHealthCheckThreshold => "Invalid health check failure threshold",
             Error::InvalidKeyParameter(_) => "Key parameter error",
+            Error::InvalidLogFormat(_) => "Invalid log format",
             Error::InvalidPidFile => "Invalid child process PID file",
+            Error::InvalidQuorumSize => "Invalid quorum size",
+            Error::InvalidReplicas => "Invalid number of replicas",
+            Error::InvalidResourceLimit(_, _) => "Invalid resource limit",
+            Error::InvalidRestartBackoff(_, _) => "Restart backoff exceeds the maximum backoff",
+            Error::InvalidRestartPolicy(_) => "Invalid restart policy",
+            Error::InvalidSchemaType(_) => "Invalid configuration schema type",
+            Error::InvalidSupConfig(_) => "Invalid Supervisor config file",
             Error::InvalidTopology(_) => "Invalid topology",
             Error::InvalidUpdateStrategy(_) => "Invalid update strategy",
             Error::Io(ref err) => err.description(),
             Error::IPFailed => "Failed to discover the outbound IP address",
+            Error::IsolationNotSupported => "Service isolation is not supported on this platform",
             Error::MissingRequiredBind(_) => "A service to start without specifying a service group for all required binds",
             Error::MissingRequiredIdent => "Missing required ident field: (example: ident = \"core/redis\")",
             Error::NetParseError(_) => "Can't parse IP:port",
@@ -302,6 +396,7 @@ impl error::Error for SupError {
             Error::ProcessLocked(_) => "Another instance of the Habitat Supervisor is already running",
             Error::ProcessLockIO(_, _) => "Unable to write or read to a process lock",
             Error::ServiceLoaded(_) => "Service load or start called when service already loaded",
+            Error::ServiceNotLoaded(_) => "Service is not loaded by the Supervisor",
             Error::ServiceSpecFileIO(_, _) => "Unable to write or read to a service spec file",
             Error::ServiceSpecParse(_) => "Service spec could not be parsed successfully",
             Error::ServiceSpecRender(_) => "Service spec TOML could not be rendered successfully",
@@ -311,6 +406,8 @@ impl error::Error for SupError {
             Error::SpecWatcherNotify(_) => "Spec watcher error",
             Error::StrFromUtf8Error(_) => "Failed to convert a str from a &[u8] as UTF-8",
             Error::StringFromUtf8Error(_) => "Failed to convert a string from a Vec<u8> as UTF-8",
+            Error::SupConfigFileIO(_, _) => "Unable to read the Supervisor config file",
+            Error::SupConfigParse(_) => "Supervisor config file could not be parsed successfully",
             Error::TomlEncode(_) => "Failed to encode toml!",
             Error::TomlMergeError(_) => "Failed to merge TOML!",
             Error::TomlParser(_) => "Failed to parse TOML!",
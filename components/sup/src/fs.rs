@@ -20,6 +20,12 @@ lazy_static! {
     pub static ref SVC_ROOT: PathBuf = {
         Path::new(&*FS_ROOT_PATH).join("hab/svc")
     };
+
+    /// The root path containing each service's decrypted secrets. This lives on tmpfs so that
+    /// secrets are never written to persistent storage.
+    pub static ref SVC_SECRETS_ROOT: PathBuf = {
+        Path::new(&*FS_ROOT_PATH).join("dev/shm/hab/svc")
+    };
 }
 
 /// Returns the root path for a given service's configuration, files, and data.
@@ -32,6 +38,12 @@ pub fn svc_config_file<T: AsRef<Path>>(service_name: T) -> PathBuf {
     svc_path(service_name).join("config.toml")
 }
 
+/// Returns the path to the file recording which layer of configuration supplied each key of a
+/// given service's config.toml.
+pub fn svc_config_provenance_file<T: AsRef<Path>>(service_name: T) -> PathBuf {
+    svc_path(service_name).join("config_provenance.toml")
+}
+
 /// Returns the path to the configuration directory for a given service.
 pub fn svc_config_path<T: AsRef<Path>>(service_name: T) -> PathBuf {
     svc_path(service_name).join("config")
@@ -67,6 +79,11 @@ pub fn svc_logs_path<T: AsRef<Path>>(service_name: T) -> PathBuf {
     svc_path(service_name).join("logs")
 }
 
+/// Returns the path to the decrypted secrets file for a given service.
+pub fn svc_secrets_file<T: AsRef<Path>>(service_name: T) -> PathBuf {
+    SVC_SECRETS_ROOT.join(service_name).join("secrets.toml")
+}
+
 /// Returns the path to a given service's pid file.
 pub fn svc_pid_file<T: AsRef<Path>>(service_name: T) -> PathBuf {
     svc_path(service_name).join("PID")
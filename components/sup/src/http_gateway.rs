@@ -14,29 +14,37 @@
 
 use std::collections::HashMap;
 use std::fmt;
-use std::fs::File;
-use std::io::{self, Read};
+use std::fs::{self as stdfs, File};
+use std::io::{self, Read, Write};
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
+use std::path::Path;
 use std::result;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{RecvTimeoutError, Sender};
 use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
+use hcore::fs::FS_ROOT_PATH;
+use hcore::package::PackageInstall;
 use hcore::service::ServiceGroup;
 use iron::prelude::*;
 use iron::{headers, status, typemap};
 use iron::modifiers::Header;
+use iron::response::WriteBody;
 use persistent;
 use prometheus::{self, CounterVec, HistogramVec, TextEncoder, Encoder};
 use router::Router;
 use serde_json;
+use toml;
 
 use error::{Result, Error, SupError};
 use fs;
-use manager;
-use manager::service::HealthCheck;
+use manager::{self, ServiceCommand};
+use manager::events::{EventBus, Subscription};
+use manager::service::{CachedHealthCheck, DesiredState, HealthCheck, ServiceSpec, StartStyle};
 use manager::service::hooks::{self, HealthCheckHook};
 
 static LOGKEY: &'static str = "HG";
@@ -56,6 +64,10 @@ macro_rules! with_metrics {
 }
 
 lazy_static! {
+    /// Interval at which a comment is sent to event stream clients when there are no events, so
+    /// that closed connections are noticed.
+    static ref EVENT_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
     static ref HTTP_COUNTER: CounterVec = register_counter_vec!(
         opts!(
             "http_requests_total",
@@ -132,13 +144,37 @@ impl typemap::Key for ManagerFs {
     type Value = manager::FsCfg;
 }
 
+struct AuthToken;
+
+impl typemap::Key for AuthToken {
+    type Value = Option<String>;
+}
+
+struct ManagerCommands;
+
+impl typemap::Key for ManagerCommands {
+    type Value = Mutex<Sender<ServiceCommand>>;
+}
+
+struct ManagerEvents;
+
+impl typemap::Key for ManagerEvents {
+    type Value = EventBus;
+}
+
 pub struct Server(Iron<Chain>, ListenAddr);
 
 impl Server {
-    pub fn new(manager_state: Arc<manager::FsCfg>, listen_addr: ListenAddr) -> Self {
+    pub fn new(manager_state: Arc<manager::FsCfg>,
+               listen_addr: ListenAddr,
+               auth_token: Option<String>,
+               commands: Sender<ServiceCommand>,
+               events: EventBus)
+               -> Self {
         let router = router!(
             butterfly: get "/butterfly" => with_metrics!(butterfly, "butterfly"),
             census: get "/census" => with_metrics!(census, "census"),
+            events: get "/events" => with_metrics!(events, "events"),
             metrics: get "/metrics" => with_metrics!(metrics, "metrics"),
             services: get "/services" => with_metrics!(services, "services"),
             service_config: get "/services/:svc/:group/config" => {
@@ -150,10 +186,32 @@ impl Server {
             },
             service_health_org: get "/services/:svc/:group/:org/health" => {
                 with_metrics!(health, "config")
+            },
+            service_load: post "/services" => with_metrics!(service_load, "service_load"),
+            service_replace: put "/services/:svc/:group" => {
+                with_metrics!(service_replace, "service_replace")
+            },
+            service_unload: delete "/services/:svc/:group" => {
+                with_metrics!(service_unload, "service_unload")
+            },
+            service_start: post "/services/:svc/:group/start" => {
+                with_metrics!(service_start, "service_start")
+            },
+            service_stop: post "/services/:svc/:group/stop" => {
+                with_metrics!(service_stop, "service_stop")
+            },
+            service_restart: post "/services/:svc/:group/restart" => {
+                with_metrics!(service_restart, "service_restart")
+            },
+            service_config_apply: put "/services/:svc/:group/config" => {
+                with_metrics!(service_config_apply, "service_config_apply")
             }
         );
         let mut chain = Chain::new(router);
         chain.link(persistent::Read::<ManagerFs>::both(manager_state));
+        chain.link(persistent::Read::<AuthToken>::both(auth_token));
+        chain.link(persistent::Read::<ManagerCommands>::both(Mutex::new(commands)));
+        chain.link(persistent::Read::<ManagerEvents>::both(events));
         Server(Iron::new(chain), listen_addr)
     }
 
@@ -169,10 +227,32 @@ impl Server {
     }
 }
 
+/// Response body which streams events to the client as server-sent events until the connection is
+/// closed.
+struct EventStream(Subscription);
+
+impl WriteBody for EventStream {
+    fn write_body(&mut self, res: &mut Write) -> io::Result<()> {
+        loop {
+            match self.0.recv_timeout(*EVENT_KEEPALIVE_INTERVAL) {
+                Ok(event) => {
+                    let data = serde_json::to_string(&event).expect("Event serialization failed");
+                    try!(write!(res, "event: {}\ndata: {}\n\n", event.kind, data));
+                }
+                Err(RecvTimeoutError::Timeout) => try!(write!(res, ": keepalive\n\n")),
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+            try!(res.flush());
+        }
+    }
+}
+
 #[derive(Default, Serialize)]
 struct HealthCheckBody {
     stdout: String,
     stderr: String,
+    last_check: String,
+    last_duration_ms: u64,
 }
 
 fn butterfly(req: &mut Request) -> IronResult<Response> {
@@ -192,12 +272,17 @@ fn census(req: &mut Request) -> IronResult<Response> {
 }
 
 fn config(req: &mut Request) -> IronResult<Response> {
+    // With `?provenance`, respond with the configuration layer which supplied each key instead.
+    let provenance = req.url
+        .query()
+        .map_or(false, |query| query.split('&').any(|p| p == "provenance"));
     // JW TODO: We don't really care about the other parts of the service group. This is because
     // we're maybe doing the wrong thing by placing all services in /hab/svc without including
     // any information about the group name or organization perhaps? Either way - this isn't
     // harmful for now - we'll either include that or change the URI to this endpoint to only
     // require service name.
     let config_file = match build_service_group(req) {
+        Ok(ref sg) if provenance => fs::svc_config_provenance_file(sg.service()),
         Ok(sg) => fs::svc_config_file(sg.service()),
         Err(_) => return Ok(Response::with(status::BadRequest)),
     };
@@ -207,6 +292,22 @@ fn config(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn events(req: &mut Request) -> IronResult<Response> {
+    if let Some(response) = authenticate(req) {
+        return Ok(response);
+    }
+    let events = req.get::<persistent::Read<ManagerEvents>>().unwrap();
+    let subscription = match events.subscribe() {
+        Some(subscription) => subscription,
+        None => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    let body: Box<WriteBody> = Box::new(EventStream(subscription));
+    Ok(Response::with((status::Ok,
+                       Header(headers::ContentType("text/event-stream".parse().unwrap())),
+                       Header(headers::CacheControl(vec![headers::CacheDirective::NoCache])),
+                       body)))
+}
+
 fn health(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     let (health_file, stdout_path, stderr_path) = match build_service_group(req) {
@@ -219,11 +320,18 @@ fn health(req: &mut Request) -> IronResult<Response> {
     };
     match File::open(&health_file) {
         Ok(mut file) => {
-            let mut buf = String::new();
+            let mut content = String::new();
+            if file.read_to_string(&mut content).is_err() {
+                return Ok(Response::with(status::InternalServerError));
+            }
+            let cached = match CachedHealthCheck::from_str(&content) {
+                Ok(cached) => cached,
+                Err(_) => return Ok(Response::with(status::InternalServerError)),
+            };
             let mut body = HealthCheckBody::default();
-            file.read_to_string(&mut buf).unwrap();
-            let code = i8::from_str(buf.trim()).unwrap();
-            let status: status::Status = HealthCheck::from(code).into();
+            body.last_check = cached.checked_at;
+            body.last_duration_ms = cached.duration_ms;
+            let status: status::Status = HealthCheck::from(cached.status).into();
             if let Ok(mut file) = File::open(&stdout_path) {
                 let _ = file.read_to_string(&mut body.stdout);
             }
@@ -255,6 +363,206 @@ fn metrics(_req: &mut Request) -> IronResult<Response> {
     Ok(Response::with((status::Ok, String::from_utf8(buffer).unwrap())))
 }
 
+/// Load a service from the spec in the request body. Services which are already loaded are
+/// left untouched.
+fn service_load(req: &mut Request) -> IronResult<Response> {
+    if let Some(response) = authenticate(req) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let spec = match spec_from_body(req) {
+        Ok(spec) => spec,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    if let Err(err) = validate_spec(&spec) {
+        return Ok(Response::with((status::UnprocessableEntity, err.to_string())));
+    }
+    let spec_file = state.spec_file(&spec.ident.name);
+    if spec_file.is_file() {
+        return Ok(Response::with(status::Conflict));
+    }
+    match spec.to_file(&spec_file) {
+        Ok(_) => Ok(Response::with(status::Created)),
+        Err(err) => {
+            outputln!("Unable to save spec for {}, {}", spec.ident, err);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+/// Load a service from the spec in the request body, reloading it if it is already loaded.
+fn service_replace(req: &mut Request) -> IronResult<Response> {
+    if let Some(response) = authenticate(req) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let (sg, spec) = match (build_service_group(req), spec_from_body(req)) {
+        (Ok(sg), Ok(spec)) => (sg, spec),
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+    if spec.ident.name != sg.service() || spec.group != sg.group() {
+        return Ok(Response::with(status::BadRequest));
+    }
+    if let Err(err) = validate_spec(&spec) {
+        return Ok(Response::with((status::UnprocessableEntity, err.to_string())));
+    }
+    match spec.to_file(state.spec_file(sg.service())) {
+        Ok(_) => Ok(Response::with(status::Ok)),
+        Err(err) => {
+            outputln!("Unable to save spec for {}, {}", spec.ident, err);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+fn service_unload(req: &mut Request) -> IronResult<Response> {
+    if let Some(response) = authenticate(req) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let sg = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    if loaded_spec(&state, &sg).is_none() {
+        return Ok(Response::with(status::NotFound));
+    }
+    match stdfs::remove_file(state.spec_file(sg.service())) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(err) => {
+            outputln!("Unable to remove spec for {}, {}", sg, err);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+fn service_start(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Up)
+}
+
+fn service_stop(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Down)
+}
+
+fn service_restart(req: &mut Request) -> IronResult<Response> {
+    if let Some(response) = authenticate(req) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let sg = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    if loaded_spec(&state, &sg).is_none() {
+        return Ok(Response::with(status::NotFound));
+    }
+    send_command(req, ServiceCommand::Restart(sg))
+}
+
+/// Gossip the TOML configuration in the request body to the service group.
+fn service_config_apply(req: &mut Request) -> IronResult<Response> {
+    if let Some(response) = authenticate(req) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let sg = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    if loaded_spec(&state, &sg).is_none() {
+        return Ok(Response::with(status::NotFound));
+    }
+    let mut body = String::new();
+    if req.body.read_to_string(&mut body).is_err() {
+        return Ok(Response::with(status::BadRequest));
+    }
+    if toml::from_str::<toml::Value>(&body).is_err() {
+        return Ok(Response::with(status::BadRequest));
+    }
+    send_command(req, ServiceCommand::ApplyConfig(sg, body.into_bytes()))
+}
+
+/// Returns a response refusing the request unless it carries the gateway's auth token as a bearer
+/// token. All requests are refused if the gateway has no auth token.
+fn authenticate(req: &mut Request) -> Option<Response> {
+    let auth_token = req.get::<persistent::Read<AuthToken>>().unwrap();
+    let expected = match *auth_token {
+        Some(ref token) => token,
+        None => return Some(Response::with(status::Forbidden)),
+    };
+    match req.headers.get::<headers::Authorization<headers::Bearer>>() {
+        Some(auth) if constant_time_eq(auth.token.as_bytes(), expected.as_bytes()) => None,
+        _ => Some(Response::with(status::Unauthorized)),
+    }
+}
+
+/// Compares two byte strings in time which depends only on their lengths.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn set_desired_state(req: &mut Request, desired_state: DesiredState) -> IronResult<Response> {
+    if let Some(response) = authenticate(req) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let sg = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let mut spec = match loaded_spec(&state, &sg) {
+        Some(spec) => spec,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+    spec.desired_state = desired_state;
+    match spec.to_file(state.spec_file(sg.service())) {
+        Ok(_) => Ok(Response::with(status::Ok)),
+        Err(err) => {
+            outputln!("Unable to save spec for {}, {}", spec.ident, err);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+fn send_command(req: &mut Request, command: ServiceCommand) -> IronResult<Response> {
+    let commands = req.get::<persistent::Read<ManagerCommands>>().unwrap();
+    let sender = commands.lock().expect("Manager commands lock poisoned");
+    match sender.send(command) {
+        Ok(_) => Ok(Response::with(status::Accepted)),
+        Err(_) => Ok(Response::with(status::ServiceUnavailable)),
+    }
+}
+
+/// Returns the spec of the loaded service matching the service group, if there is one.
+fn loaded_spec(state: &manager::FsCfg, sg: &ServiceGroup) -> Option<ServiceSpec> {
+    match ServiceSpec::from_file(state.spec_file(sg.service())) {
+        Ok(ref spec) if spec.group != sg.group() => None,
+        Ok(spec) => Some(spec),
+        Err(_) => None,
+    }
+}
+
+fn spec_from_body(req: &mut Request) -> Result<ServiceSpec> {
+    let mut body = String::new();
+    req.body.read_to_string(&mut body)?;
+    let mut spec = ServiceSpec::from_str(&body)?;
+    spec.start_style = StartStyle::Persistent;
+    Ok(spec)
+}
+
+/// Validates a spec as the Manager will when it loads the service. Its binds can only be
+/// validated when its package is already installed, otherwise the Manager validates them after
+/// installing the package.
+fn validate_spec(spec: &ServiceSpec) -> Result<()> {
+    match PackageInstall::load(&spec.ident, Some(Path::new(&*FS_ROOT_PATH))) {
+        Ok(package) => spec.validate(&package),
+        Err(_) => spec.validate_settings(),
+    }
+}
+
 impl Into<Response> for HealthCheck {
     fn into(self) -> Response {
         let status: status::Status = self.into();
@@ -286,3 +594,172 @@ fn build_service_group(req: &mut Request) -> Result<ServiceGroup> {
                                req.extensions.get::<Router>().unwrap().find("org"))?;
     Ok(sg)
 }
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::mem;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use std::sync::mpsc::{channel, Receiver};
+
+    use hcore::service::ServiceGroup;
+    use hyper::client::{Client, Response};
+    use hyper::header::{Authorization, Bearer};
+    use hyper::status::StatusCode;
+    use tempdir::TempDir;
+
+    use super::Server;
+    use manager::{FsCfg, ServiceCommand};
+    use manager::events::EventBus;
+
+    const TOKEN: &'static str = "sekrit";
+
+    /// Starts a gateway on an ephemeral port, returning its address and the commands it sends to
+    /// the Manager.
+    fn start_gateway(tmp: &TempDir,
+                     auth_token: Option<&str>)
+                     -> (SocketAddr, Receiver<ServiceCommand>) {
+        let fs_cfg = FsCfg::new(tmp.path());
+        fs::create_dir_all(tmp.path().join("specs")).unwrap();
+        let (tx, rx) = channel();
+        let server = Server::new(Arc::new(fs_cfg),
+                                 "127.0.0.1:0".parse().unwrap(),
+                                 auth_token.map(|t| t.to_string()),
+                                 tx,
+                                 EventBus::default());
+        let listening = server.0.http("127.0.0.1:0").unwrap();
+        let addr = listening.socket;
+        // Dropping a listening server waits for it to stop, which it never does, so the server
+        // is left running until the test process exits.
+        mem::forget(listening);
+        (addr, rx)
+    }
+
+    fn write_spec(tmp: &TempDir, name: &str, content: &str) {
+        File::create(tmp.path().join("specs").join(format!("{}.spec", name)))
+            .unwrap()
+            .write_all(content.as_bytes())
+            .unwrap();
+    }
+
+    fn post(addr: &SocketAddr, path: &str, token: Option<&str>, body: &str) -> Response {
+        let client = Client::new();
+        let url = format!("http://{}{}", addr, path);
+        let mut req = client.post(&url).body(body);
+        if let Some(token) = token {
+            req = req.header(Authorization(Bearer { token: token.to_string() }));
+        }
+        req.send().unwrap()
+    }
+
+    #[test]
+    fn write_endpoints_require_auth_token() {
+        let tmp = TempDir::new("http-gateway").unwrap();
+        let (addr, _rx) = start_gateway(&tmp, Some(TOKEN));
+
+        let res = post(&addr, "/services", None, "ident = \"core/redis\"");
+        assert_eq!(res.status, StatusCode::Unauthorized);
+        let res = post(&addr, "/services", Some("wrong"), "ident = \"core/redis\"");
+        assert_eq!(res.status, StatusCode::Unauthorized);
+        assert!(!tmp.path().join("specs/redis.spec").exists());
+    }
+
+    #[test]
+    fn write_endpoints_are_refused_without_gateway_auth_token() {
+        let tmp = TempDir::new("http-gateway").unwrap();
+        let (addr, _rx) = start_gateway(&tmp, None);
+
+        let res = post(&addr, "/services", Some(TOKEN), "ident = \"core/redis\"");
+        assert_eq!(res.status, StatusCode::Forbidden);
+    }
+
+    #[test]
+    fn events_require_auth_token() {
+        let tmp = TempDir::new("http-gateway").unwrap();
+        let (addr, _rx) = start_gateway(&tmp, Some(TOKEN));
+
+        let res = Client::new()
+            .get(&format!("http://{}/events", addr))
+            .send()
+            .unwrap();
+        assert_eq!(res.status, StatusCode::Unauthorized);
+    }
+
+    #[test]
+    fn service_load_saves_spec() {
+        let tmp = TempDir::new("http-gateway").unwrap();
+        let (addr, _rx) = start_gateway(&tmp, Some(TOKEN));
+
+        let res = post(&addr, "/services", Some(TOKEN), "ident = \"core/redis\"");
+        assert_eq!(res.status, StatusCode::Created);
+        assert!(tmp.path().join("specs/redis.spec").is_file());
+
+        let res = post(&addr, "/services", Some(TOKEN), "ident = \"core/redis\"");
+        assert_eq!(res.status, StatusCode::Conflict);
+    }
+
+    #[test]
+    fn service_load_rejects_invalid_spec() {
+        let tmp = TempDir::new("http-gateway").unwrap();
+        let (addr, _rx) = start_gateway(&tmp, Some(TOKEN));
+
+        let res = post(&addr,
+                       "/services",
+                       Some(TOKEN),
+                       "ident = \"core/redis\"\ncanary_batch_percent = 0");
+        assert_eq!(res.status, StatusCode::UnprocessableEntity);
+        assert!(!tmp.path().join("specs/redis.spec").exists());
+    }
+
+    #[test]
+    fn service_restart_sends_command_for_loaded_service() {
+        let tmp = TempDir::new("http-gateway").unwrap();
+        let (addr, rx) = start_gateway(&tmp, Some(TOKEN));
+        write_spec(&tmp, "redis", "ident = \"core/redis\"\ngroup = \"default\"");
+
+        let res = post(&addr, "/services/redis/default/restart", Some(TOKEN), "");
+        assert_eq!(res.status, StatusCode::Accepted);
+        match rx.try_recv() {
+            Ok(ServiceCommand::Restart(sg)) => {
+                assert_eq!(sg, ServiceGroup::new("redis", "default", None).unwrap())
+            }
+            other => panic!("Expected a restart command, got {:?}", other),
+        }
+
+        let res = post(&addr, "/services/nginx/default/restart", Some(TOKEN), "");
+        assert_eq!(res.status, StatusCode::NotFound);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn service_config_apply_sends_command_for_valid_toml() {
+        let tmp = TempDir::new("http-gateway").unwrap();
+        let (addr, rx) = start_gateway(&tmp, Some(TOKEN));
+        write_spec(&tmp, "redis", "ident = \"core/redis\"\ngroup = \"default\"");
+        let client = Client::new();
+        let url = format!("http://{}/services/redis/default/config", addr);
+
+        let res = client
+            .put(&url)
+            .header(Authorization(Bearer { token: TOKEN.to_string() }))
+            .body("port = ")
+            .send()
+            .unwrap();
+        assert_eq!(res.status, StatusCode::BadRequest);
+        assert!(rx.try_recv().is_err());
+
+        let res = client
+            .put(&url)
+            .header(Authorization(Bearer { token: TOKEN.to_string() }))
+            .body("port = 6380")
+            .send()
+            .unwrap();
+        assert_eq!(res.status, StatusCode::Accepted);
+        match rx.try_recv() {
+            Ok(ServiceCommand::ApplyConfig(_, config)) => assert_eq!(config, b"port = 6380"),
+            other => panic!("Expected an apply config command, got {:?}", other),
+        }
+    }
+}
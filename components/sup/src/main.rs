@@ -24,7 +24,6 @@ extern crate libc;
 extern crate clap;
 extern crate url;
 
-use std::net::{SocketAddr, ToSocketAddrs};
 use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
@@ -39,20 +38,23 @@ use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 use url::Url;
 
 use sup::VERSION;
-use sup::config::{GossipListenAddr, GOSSIP_DEFAULT_PORT};
+use sup::config::{self, GossipListenAddr, SupConfig, SUP_CONFIG_ENVVAR};
 use sup::error::{Error, Result};
 use sup::feat;
 use sup::command;
 use sup::http_gateway;
 use sup::manager::{Manager, ManagerConfig};
-use sup::manager::service::{DesiredState, ServiceBind, Topology, UpdateStrategy};
+use sup::manager::service::{DesiredState, MaintenanceWindow, RestartPolicy, ServiceBind,
+                            Topology, UpdateStrategy};
 use sup::manager::service::{ServiceSpec, StartStyle};
+use sup::output::LogFormat;
 
 /// Our output key
 static LOGKEY: &'static str = "MN";
 
 static RING_ENVVAR: &'static str = "HAB_RING";
 static RING_KEY_ENVVAR: &'static str = "HAB_RING_KEY";
+static HTTP_AUTH_TOKEN_ENVVAR: &'static str = "HAB_SUP_HTTP_AUTH_TOKEN";
 
 fn main() {
     env_logger::init().unwrap();
@@ -73,6 +75,7 @@ fn start() -> Result<()> {
         ("run", Some(m)) => sub_run(m),
         ("sh", Some(m)) => sub_sh(m),
         ("start", Some(m)) => sub_start(m),
+        ("status", Some(m)) => sub_status(m),
         ("stop", Some(m)) => sub_stop(m),
         ("unload", Some(m)) => sub_unload(m),
         _ => unreachable!(),
@@ -95,8 +98,24 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         (@subcommand config =>
             (about: "Displays the default configuration options for a service")
             (aliases: &["c", "co", "con", "conf", "confi"])
+            (@setting SubcommandsNegateReqs)
             (@arg PKG_IDENT: +required +takes_value
                 "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+            (@subcommand render =>
+                (about: "Renders the configuration templates and hooks of an installed package \
+                    without running a Supervisor")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg OUTPUT_DIR: --output -o +required +takes_value
+                    "Directory to write the rendered config and hooks directories to")
+                (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
+                    "Use package config from this path, rather than the package itself")
+                (@arg USER_TOML: --("user-toml") +takes_value {file_exists}
+                    "TOML file of configuration to apply in place of the service's user.toml")
+                (@arg CENSUS: --census +takes_value {file_exists}
+                    "JSON file of census data, such as the svc and bind namespaces, to merge \
+                    into the rendered data")
+            )
         )
         (@subcommand load =>
             (about: "Load a service to be started and supervised by Habitat from a package or \
@@ -104,6 +123,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 restarts.")
             (aliases: &["lo", "loa"])
             (@arg PKG_IDENT: +required +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg CONFIG_FILE: --config +takes_value {file_exists}
+                "Path to the Supervisor config file [default: /hab/sup/default/config.toml]")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
@@ -112,9 +133,84 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg DEPOT_URL: --url -u +takes_value {valid_url}
                 "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] [values: standalone, leader, replicated]")
+            (@arg REPLICAS: --replicas +takes_value {valid_replicas}
+                "Members, besides the leader, designated as replicas by the replicated topology")
+            (@arg QUORUM_SIZE: --("quorum-size") +takes_value {valid_quorum_size}
+                "Members the service group must have before it can elect a leader [default: 3]")
+            (@arg LEADER_LEASE: --("leader-lease") +takes_value {valid_leader_lease}
+                "Seconds to wait on an unreachable leader before electing a new one, 0 to wait \
+                until it is confirmed dead [default: 0]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg CANARY_SOAK: --("canary-soak") +takes_value {valid_canary_soak}
+                "Seconds a canary must pass its health check before releasing an update to the \
+                rest of the service group [default: 300]")
+            (@arg CANARY_BATCH: --("canary-batch") +takes_value {valid_canary_batch}
+                "Percentage of the service group to update at once after a canary is released \
+                [default: 100]")
+            (@arg CHANNEL: --channel +takes_value
+                "Depot channel to follow for updated packages [default: the latest package]")
+            (@arg UPDATE_FREQUENCY: --("update-frequency") +takes_value {valid_update_secs}
+                "Seconds between checks for an updated package [default: the Supervisor's]")
+            (@arg UPDATE_JITTER: --("update-jitter") +takes_value {valid_update_secs}
+                "Maximum seconds added at random to each wait between checks [default: 0]")
+            (@arg MAINTENANCE_WINDOW: --("maintenance-window") +takes_value
+                {valid_maintenance_window}
+                "Daily window in UTC, as HH:MM-HH:MM, outside of which updates are not applied")
+            (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                "When to restart the service after it exits; [default: always] \
+                [values: always, on-failure, never]")
+            (@arg RESTART_MAX: --("restart-max") +takes_value {valid_restart_max}
+                "Restarts allowed within the restart window before the service is marked as \
+                failed [default: 5]")
+            (@arg RESTART_WINDOW: --("restart-window") +takes_value {valid_restart_secs}
+                "Seconds over which restarts are counted [default: 300]")
+            (@arg RESTART_BACKOFF: --("restart-backoff") +takes_value {valid_restart_secs}
+                "Seconds to wait before restarting the service, doubled for each restart within \
+                the restart window [default: 1]")
+            (@arg RESTART_BACKOFF_MAX: --("restart-backoff-max") +takes_value {valid_restart_secs}
+                "Maximum seconds to wait before restarting the service [default: 60]")
+            (@arg HEALTH_CHECK_INTERVAL: --("health-check-interval") +takes_value
+                {valid_health_check_interval}
+                "Seconds between health checks [default: 30]")
+            (@arg HEALTH_CHECK_TIMEOUT: --("health-check-timeout") +takes_value
+                {valid_health_check_timeout}
+                "Seconds a health check hook may run before it is killed and reported as \
+                critical, 0 to disable [default: 30]")
+            (@arg HEALTH_CHECK_THRESHOLD: --("health-check-threshold") +takes_value
+                {valid_health_check_threshold}
+                "Consecutive failed health checks before the service reports a failing health \
+                [default: 1]")
+            (@arg SHUTDOWN_TIMEOUT: --("shutdown-timeout") +takes_value {valid_shutdown_timeout}
+                "Seconds the service is given to exit after SIGTERM before it is sent SIGKILL \
+                [default: 8]")
+            (@arg CPU_SHARES: --("cpu-shares") +takes_value {valid_cpu_shares}
+                "Relative share of CPU time for the service's processes, where 1024 is the share \
+                of an unlimited process (Linux only)")
+            (@arg MEMORY_LIMIT: --("memory-limit") +takes_value {valid_resource_limit}
+                "Maximum megabytes of memory the service's processes may use (Linux only)")
+            (@arg PIDS_MAX: --("pids-max") +takes_value {valid_resource_limit}
+                "Maximum number of processes and threads the service may run (Linux only)")
+            (@arg OPEN_FILES_MAX: --("open-files-max") +takes_value {valid_resource_limit}
+                "Maximum number of files each of the service's processes may open")
+            (@arg ISOLATE: --isolate
+                "Run the service in its own PID and mount namespaces, with read-only packages \
+                (Linux only)")
+            (@arg ISOLATE_NETWORK: --("isolate-network")
+                "Also run the service in its own network namespace, with only a loopback \
+                interface (Linux only)")
+            (@arg LOG_FILE: --("log-file")
+                "Write the service's output to a rotated log file in its logs directory")
+            (@arg LOG_FORMAT: --("log-format") +takes_value {valid_log_format}
+                "Format of the lines in the service's log file; [default: text] \
+                [values: text, json]")
+            (@arg LOG_MAX_SIZE: --("log-max-size") +takes_value {valid_log_number}
+                "Megabytes after which the log file is rotated, 0 to disable [default: 10]")
+            (@arg LOG_ROTATE: --("log-rotate") +takes_value {valid_log_number}
+                "Seconds after which the log file is rotated, 0 to disable [default: 0]")
+            (@arg LOG_KEEP: --("log-keep") +takes_value {valid_log_number}
+                "Number of rotated log files to keep [default: 5]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
             (@arg FORCE: --force -f "Load or reload an already loaded service. If the service was \
@@ -147,6 +243,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
             (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg CONFIG_FILE: --config +takes_value {file_exists}
+                "Path to the Supervisor config file [default: /hab/sup/default/config.toml]")
         )
         (@subcommand sh =>
             (about: "Start an interactive Bourne-like shell")
@@ -171,6 +269,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
             (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg CONFIG_FILE: --config +takes_value {file_exists}
+                "Path to the Supervisor config file [default: /hab/sup/default/config.toml]")
             (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
                 "A Habitat package identifier (ex: core/redis) or filepath to a Habitat Artifact \
                 (ex: /home/core-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -179,14 +279,103 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg DEPOT_URL: --url -u +takes_value {valid_url}
                 "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] [values: standalone, leader, replicated]")
+            (@arg REPLICAS: --replicas +takes_value {valid_replicas}
+                "Members, besides the leader, designated as replicas by the replicated topology")
+            (@arg QUORUM_SIZE: --("quorum-size") +takes_value {valid_quorum_size}
+                "Members the service group must have before it can elect a leader [default: 3]")
+            (@arg LEADER_LEASE: --("leader-lease") +takes_value {valid_leader_lease}
+                "Seconds to wait on an unreachable leader before electing a new one, 0 to wait \
+                until it is confirmed dead [default: 0]")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg CANARY_SOAK: --("canary-soak") +takes_value {valid_canary_soak}
+                "Seconds a canary must pass its health check before releasing an update to the \
+                rest of the service group [default: 300]")
+            (@arg CANARY_BATCH: --("canary-batch") +takes_value {valid_canary_batch}
+                "Percentage of the service group to update at once after a canary is released \
+                [default: 100]")
+            (@arg CHANNEL: --channel +takes_value
+                "Depot channel to follow for updated packages [default: the latest package]")
+            (@arg UPDATE_FREQUENCY: --("update-frequency") +takes_value {valid_update_secs}
+                "Seconds between checks for an updated package [default: the Supervisor's]")
+            (@arg UPDATE_JITTER: --("update-jitter") +takes_value {valid_update_secs}
+                "Maximum seconds added at random to each wait between checks [default: 0]")
+            (@arg MAINTENANCE_WINDOW: --("maintenance-window") +takes_value
+                {valid_maintenance_window}
+                "Daily window in UTC, as HH:MM-HH:MM, outside of which updates are not applied")
+            (@arg RESTART_POLICY: --("restart-policy") +takes_value {valid_restart_policy}
+                "When to restart the service after it exits; [default: always] \
+                [values: always, on-failure, never]")
+            (@arg RESTART_MAX: --("restart-max") +takes_value {valid_restart_max}
+                "Restarts allowed within the restart window before the service is marked as \
+                failed [default: 5]")
+            (@arg RESTART_WINDOW: --("restart-window") +takes_value {valid_restart_secs}
+                "Seconds over which restarts are counted [default: 300]")
+            (@arg RESTART_BACKOFF: --("restart-backoff") +takes_value {valid_restart_secs}
+                "Seconds to wait before restarting the service, doubled for each restart within \
+                the restart window [default: 1]")
+            (@arg RESTART_BACKOFF_MAX: --("restart-backoff-max") +takes_value {valid_restart_secs}
+                "Maximum seconds to wait before restarting the service [default: 60]")
+            (@arg HEALTH_CHECK_INTERVAL: --("health-check-interval") +takes_value
+                {valid_health_check_interval}
+                "Seconds between health checks [default: 30]")
+            (@arg HEALTH_CHECK_TIMEOUT: --("health-check-timeout") +takes_value
+                {valid_health_check_timeout}
+                "Seconds a health check hook may run before it is killed and reported as \
+                critical, 0 to disable [default: 30]")
+            (@arg HEALTH_CHECK_THRESHOLD: --("health-check-threshold") +takes_value
+                {valid_health_check_threshold}
+                "Consecutive failed health checks before the service reports a failing health \
+                [default: 1]")
+            (@arg SHUTDOWN_TIMEOUT: --("shutdown-timeout") +takes_value {valid_shutdown_timeout}
+                "Seconds the service is given to exit after SIGTERM before it is sent SIGKILL \
+                [default: 8]")
+            (@arg CPU_SHARES: --("cpu-shares") +takes_value {valid_cpu_shares}
+                "Relative share of CPU time for the service's processes, where 1024 is the share \
+                of an unlimited process (Linux only)")
+            (@arg MEMORY_LIMIT: --("memory-limit") +takes_value {valid_resource_limit}
+                "Maximum megabytes of memory the service's processes may use (Linux only)")
+            (@arg PIDS_MAX: --("pids-max") +takes_value {valid_resource_limit}
+                "Maximum number of processes and threads the service may run (Linux only)")
+            (@arg OPEN_FILES_MAX: --("open-files-max") +takes_value {valid_resource_limit}
+                "Maximum number of files each of the service's processes may open")
+            (@arg ISOLATE: --isolate
+                "Run the service in its own PID and mount namespaces, with read-only packages \
+                (Linux only)")
+            (@arg ISOLATE_NETWORK: --("isolate-network")
+                "Also run the service in its own network namespace, with only a loopback \
+                interface (Linux only)")
+            (@arg LOG_FILE: --("log-file")
+                "Write the service's output to a rotated log file in its logs directory")
+            (@arg LOG_FORMAT: --("log-format") +takes_value {valid_log_format}
+                "Format of the lines in the service's log file; [default: text] \
+                [values: text, json]")
+            (@arg LOG_MAX_SIZE: --("log-max-size") +takes_value {valid_log_number}
+                "Megabytes after which the log file is rotated, 0 to disable [default: 10]")
+            (@arg LOG_ROTATE: --("log-rotate") +takes_value {valid_log_number}
+                "Seconds after which the log file is rotated, 0 to disable [default: 0]")
+            (@arg LOG_KEEP: --("log-keep") +takes_value {valid_log_number}
+                "Number of rotated log files to keep [default: 5]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
                 "Use package config from this path, rather than the package itself")
         )
+        (@subcommand status =>
+            (about: "Show the state of the services run by the Supervisor")
+            (aliases: &["stat", "statu"])
+            (@arg PKG_IDENT: +takes_value
+                "A Habitat package identifier to show only that service (ex: core/redis)")
+            (@arg JSON: --json "Output the state as JSON")
+            (@arg CONFIG_FILE: --config +takes_value {file_exists}
+                "Path to the Supervisor config file [default: /hab/sup/default/config.toml]")
+            (@arg LISTEN_HTTP: --("listen-http") +takes_value
+                "The listen address of the Supervisor's HTTP gateway [default: 0.0.0.0:9631]")
+            (@arg NAME: --("override-name") +takes_value
+                "The name for the state directory if there is more than one Supervisor running \
+                [default: default]")
+        )
         (@subcommand stop =>
             (about: "Stop a running Habitat service.")
             (aliases: &["sto"])
@@ -210,12 +399,30 @@ fn sub_bash(m: &ArgMatches) -> Result<()> {
 }
 
 fn sub_config(m: &ArgMatches) -> Result<()> {
+    if let ("render", Some(m)) = m.subcommand() {
+        return sub_config_render(m);
+    }
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
 
     try!(common::command::package::config::start(&ident, "/"));
     Ok(())
 }
 
+fn sub_config_render(m: &ArgMatches) -> Result<()> {
+    if m.is_present("VERBOSE") {
+        sup::output::set_verbose(true);
+    }
+    if m.is_present("NO_COLOR") {
+        sup::output::set_no_color(true);
+    }
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
+    command::render::run(&ident,
+                         m.value_of("CONFIG_DIR").map(Path::new),
+                         m.value_of("USER_TOML").map(Path::new),
+                         m.value_of("CENSUS").map(Path::new),
+                         Path::new(m.value_of("OUTPUT_DIR").unwrap()))
+}
+
 fn sub_load(m: &ArgMatches) -> Result<()> {
     if m.is_present("VERBOSE") {
         sup::output::set_verbose(true);
@@ -232,7 +439,7 @@ fn sub_load(m: &ArgMatches) -> Result<()> {
             return Err(sup_error!(Error::ServiceLoaded(spec.ident)));
         }
     }
-    let mut spec = spec_from_matches(default_spec.ident, m)?;
+    let mut spec = spec_from_matches(default_spec.ident, m, &cfg)?;
     spec.start_style = StartStyle::Persistent;
     Manager::save_spec_for(&cfg, spec)
 }
@@ -246,7 +453,7 @@ fn sub_unload(m: &ArgMatches) -> Result<()> {
     }
     let cfg = mgrcfg_from_matches(m)?;
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
-    let spec = spec_from_matches(ident, m)?;
+    let spec = spec_from_matches(ident, m, &cfg)?;
     let spec_file = Manager::spec_path_for(&cfg, &spec);
     std::fs::remove_file(&spec_file).map_err(|err| {
                                                  sup_error!(Error::ServiceSpecFileIO(spec_file,
@@ -305,7 +512,7 @@ fn sub_start(m: &ArgMatches) -> Result<()> {
                         }
                     }
                 }
-                Err(_) => Some(spec_from_matches(default_spec.ident, m)?),
+                Err(_) => Some(spec_from_matches(default_spec.ident, m, &cfg)?),
             }
         }
         None => None,
@@ -314,6 +521,21 @@ fn sub_start(m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+fn sub_status(m: &ArgMatches) -> Result<()> {
+    if m.is_present("VERBOSE") {
+        sup::output::set_verbose(true);
+    }
+    if m.is_present("NO_COLOR") {
+        sup::output::set_no_color(true);
+    }
+    let cfg = mgrcfg_from_matches(m)?;
+    let ident = match m.value_of("PKG_IDENT") {
+        Some(ident) => Some(PackageIdent::from_str(ident)?),
+        None => None,
+    };
+    command::status::run(&cfg, ident.as_ref(), m.is_present("JSON"))
+}
+
 fn sub_stop(m: &ArgMatches) -> Result<()> {
     if m.is_present("VERBOSE") {
         sup::output::set_verbose(true);
@@ -332,12 +554,6 @@ fn sub_stop(m: &ArgMatches) -> Result<()> {
 fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     let mut cfg = ManagerConfig::default();
 
-    if let Some(addr_str) = m.value_of("LISTEN_GOSSIP") {
-        cfg.gossip_listen = try!(GossipListenAddr::from_str(addr_str));
-    }
-    if let Some(addr_str) = m.value_of("LISTEN_HTTP") {
-        cfg.http_listen = try!(http_gateway::ListenAddr::from_str(addr_str));
-    }
     if let Some(name_str) = m.value_of("NAME") {
         cfg.name = Some(String::from(name_str));
         outputln!("");
@@ -352,30 +568,52 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
                   Red.bold().paint("CAUTION:".to_string()));
         outputln!("");
     }
-    cfg.organization = m.value_of("ORGANIZATION").map(|org| org.to_string());
-    cfg.gossip_permanent = m.is_present("PERMANENT_PEER");
-    // TODO fn: Clean this up--using a for loop doesn't feel good however an iterator was
-    // causing a lot of developer/compiler type confusion
-    let mut gossip_peers: Vec<SocketAddr> = Vec::new();
-    if let Some(peers) = m.values_of("PEER") {
-        for peer in peers {
-            let peer_addr = if peer.find(':').is_some() {
-                peer.to_string()
-            } else {
-                format!("{}:{}", peer, GOSSIP_DEFAULT_PORT)
-            };
-            let addrs: Vec<SocketAddr> = match peer_addr.to_socket_addrs() {
-                Ok(addrs) => addrs.collect(),
-                Err(e) => {
-                    outputln!("Failed to resolve peer: {}", peer_addr);
-                    return Err(sup_error!(Error::NameLookup(e)));
+    // Options given on the command line take precedence over those in the config file. A
+    // config file named by the flag or the environment must exist, while the one in the state
+    // directory is optional.
+    let config_file = match m.value_of("CONFIG_FILE") {
+        Some(path) => Some(PathBuf::from(path)),
+        None => {
+            match henv::var(SUP_CONFIG_ENVVAR) {
+                Ok(ref path) if !path.is_empty() => Some(PathBuf::from(path)),
+                _ => {
+                    let path = Manager::config_path_for(&cfg);
+                    if path.is_file() { Some(path) } else { None }
                 }
-            };
-            let addr: SocketAddr = addrs[0];
-            gossip_peers.push(addr);
+            }
         }
+    };
+    let sup_cfg = match config_file {
+        Some(ref path) => try!(SupConfig::from_file(path)),
+        None => SupConfig::default(),
+    };
+    cfg.config_file = config_file;
+
+    if let Some(addr_str) = m.value_of("LISTEN_GOSSIP")
+           .or(sup_cfg.listen_gossip.as_ref().map(|s| &**s)) {
+        cfg.gossip_listen = try!(GossipListenAddr::from_str(addr_str));
+    }
+    if let Some(addr_str) = m.value_of("LISTEN_HTTP")
+           .or(sup_cfg.listen_http.as_ref().map(|s| &**s)) {
+        cfg.http_listen = try!(http_gateway::ListenAddr::from_str(addr_str));
     }
-    cfg.gossip_peers = gossip_peers;
+    cfg.organization = m.value_of("ORGANIZATION")
+        .map(|org| org.to_string())
+        .or(sup_cfg.org.clone());
+    cfg.http_auth_token = match henv::var(HTTP_AUTH_TOKEN_ENVVAR) {
+        Ok(ref token) if token.is_empty() => None,
+        Ok(token) => Some(token),
+        Err(_) => None,
+    };
+    cfg.gossip_permanent = m.is_present("PERMANENT_PEER") ||
+                           sup_cfg.permanent_peer.unwrap_or(false);
+    cfg.gossip_peers = match m.values_of("PEER") {
+        Some(peers) => try!(config::resolve_peers(&peers.collect::<Vec<&str>>())),
+        None => try!(config::resolve_peers(&sup_cfg.peers)),
+    };
+    cfg.gossip_timing = sup_cfg.timing();
+    cfg.update_frequency_secs = sup_cfg.update_frequency_secs();
+    cfg.depot_url = sup_cfg.depot_url.clone();
     let ring = match m.value_of("RING") {
         Some(val) => Some(try!(SymKey::get_latest_pair_for(&val, &default_cache_key_path(None)))),
         None => {
@@ -386,12 +624,12 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
                     Some(key)
                 }
                 Err(_) => {
-                    match henv::var(RING_ENVVAR) {
-                        Ok(val) => {
+                    match henv::var(RING_ENVVAR).ok().or(sup_cfg.ring.clone()) {
+                        Some(val) => {
                             Some(try!(SymKey::get_latest_pair_for(&val,
                                                                   &default_cache_key_path(None))))
                         }
-                        Err(_) => None,
+                        None => None,
                     }
                 }
             }
@@ -403,20 +641,113 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     Ok(cfg)
 }
 
-fn spec_from_matches(ident: PackageIdent, m: &ArgMatches) -> Result<ServiceSpec> {
+fn spec_from_matches(ident: PackageIdent,
+                     m: &ArgMatches,
+                     cfg: &ManagerConfig)
+                     -> Result<ServiceSpec> {
     let mut spec = ServiceSpec::default_for(ident);
     if let Some(group) = m.value_of("GROUP") {
         spec.group = group.to_string();
     }
-    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR)
+        .ok()
+        .or(cfg.depot_url.clone())
+        .unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
     spec.depot_url = String::from(url);
     if let Some(topology) = m.value_of("TOPOLOGY") {
         spec.topology = Topology::from_str(topology)?;
     }
+    if let Some(replicas) = m.value_of("REPLICAS") {
+        spec.replicas = replicas.parse().unwrap();
+    }
+    if let Some(quorum_size) = m.value_of("QUORUM_SIZE") {
+        spec.quorum_size = quorum_size.parse().unwrap();
+    }
+    if let Some(lease) = m.value_of("LEADER_LEASE") {
+        spec.leader_lease_secs = lease.parse().unwrap();
+    }
     if let Some(ref strategy) = m.value_of("STRATEGY") {
         spec.update_strategy = UpdateStrategy::from_str(strategy)?;
     }
+    if let Some(soak) = m.value_of("CANARY_SOAK") {
+        spec.canary_soak_secs = soak.parse().unwrap();
+    }
+    if let Some(batch) = m.value_of("CANARY_BATCH") {
+        spec.canary_batch_percent = batch.parse().unwrap();
+    }
+    if let Some(channel) = m.value_of("CHANNEL") {
+        spec.channel = Some(channel.to_string());
+    }
+    if let Some(frequency) = m.value_of("UPDATE_FREQUENCY") {
+        spec.update_frequency_secs = frequency.parse().unwrap();
+    }
+    if let Some(jitter) = m.value_of("UPDATE_JITTER") {
+        spec.update_jitter_secs = jitter.parse().unwrap();
+    }
+    if let Some(window) = m.value_of("MAINTENANCE_WINDOW") {
+        spec.maintenance_window = Some(MaintenanceWindow::from_str(window)?);
+    }
+    if let Some(policy) = m.value_of("RESTART_POLICY") {
+        spec.restart_policy = RestartPolicy::from_str(policy)?;
+    }
+    if let Some(max) = m.value_of("RESTART_MAX") {
+        spec.restart_max = max.parse().unwrap();
+    }
+    if let Some(window) = m.value_of("RESTART_WINDOW") {
+        spec.restart_window_secs = window.parse().unwrap();
+    }
+    if let Some(backoff) = m.value_of("RESTART_BACKOFF") {
+        spec.restart_backoff_secs = backoff.parse().unwrap();
+    }
+    if let Some(backoff_max) = m.value_of("RESTART_BACKOFF_MAX") {
+        spec.restart_backoff_max_secs = backoff_max.parse().unwrap();
+    }
+    if let Some(interval) = m.value_of("HEALTH_CHECK_INTERVAL") {
+        spec.health_check_interval_secs = interval.parse().unwrap();
+    }
+    if let Some(timeout) = m.value_of("HEALTH_CHECK_TIMEOUT") {
+        spec.health_check_timeout_secs = timeout.parse().unwrap();
+    }
+    if let Some(threshold) = m.value_of("HEALTH_CHECK_THRESHOLD") {
+        spec.health_check_failure_threshold = threshold.parse().unwrap();
+    }
+    if let Some(timeout) = m.value_of("SHUTDOWN_TIMEOUT") {
+        spec.shutdown_timeout_secs = timeout.parse().unwrap();
+    }
+    if let Some(shares) = m.value_of("CPU_SHARES") {
+        spec.cpu_shares = Some(shares.parse().unwrap());
+    }
+    if let Some(memory) = m.value_of("MEMORY_LIMIT") {
+        spec.memory_limit_mb = Some(memory.parse().unwrap());
+    }
+    if let Some(pids) = m.value_of("PIDS_MAX") {
+        spec.pids_max = Some(pids.parse().unwrap());
+    }
+    if let Some(files) = m.value_of("OPEN_FILES_MAX") {
+        spec.open_files_max = Some(files.parse().unwrap());
+    }
+    if m.is_present("ISOLATE") {
+        spec.isolate = true;
+    }
+    if m.is_present("ISOLATE_NETWORK") {
+        spec.isolate_network = true;
+    }
+    if m.is_present("LOG_FILE") {
+        spec.log_to_file = true;
+    }
+    if let Some(format) = m.value_of("LOG_FORMAT") {
+        spec.log_format = LogFormat::from_str(format)?;
+    }
+    if let Some(size) = m.value_of("LOG_MAX_SIZE") {
+        spec.log_max_mb = size.parse().unwrap();
+    }
+    if let Some(rotate) = m.value_of("LOG_ROTATE") {
+        spec.log_rotate_secs = rotate.parse().unwrap();
+    }
+    if let Some(keep) = m.value_of("LOG_KEEP") {
+        spec.log_keep = keep.parse().unwrap();
+    }
     if let Some(bind_strs) = m.values_of("BIND") {
         let mut binds = Vec::new();
         for bind_str in bind_strs {
@@ -446,6 +777,14 @@ fn dir_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn file_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_file() {
+        Ok(())
+    } else {
+        Err(format!("File: '{}' cannot be found", &val))
+    }
+}
+
 fn valid_topology(val: String) -> result::Result<(), String> {
     match Topology::from_str(&val) {
         Ok(_) => Ok(()),
@@ -453,6 +792,27 @@ fn valid_topology(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_replicas(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(replicas) if replicas > 0 => Ok(()),
+        _ => Err(format!("Replicas: '{}' is not a positive number of members", &val)),
+    }
+}
+
+fn valid_quorum_size(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(size) if size > 0 => Ok(()),
+        _ => Err(format!("Quorum size: '{}' is not a positive number of members", &val)),
+    }
+}
+
+fn valid_leader_lease(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Leader lease: '{}' is not a number of seconds", &val)),
+    }
+}
+
 fn valid_update_strategy(val: String) -> result::Result<(), String> {
     match UpdateStrategy::from_str(&val) {
         Ok(_) => Ok(()),
@@ -460,6 +820,111 @@ fn valid_update_strategy(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_canary_soak(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Canary soak period: '{}' is not a number of seconds", &val)),
+    }
+}
+
+fn valid_canary_batch(val: String) -> result::Result<(), String> {
+    match val.parse::<u8>() {
+        Ok(percent) if percent > 0 && percent <= 100 => Ok(()),
+        _ => Err(format!("Canary batch: '{}' is not a percentage between 1 and 100", &val)),
+    }
+}
+
+fn valid_update_secs(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Update timing: '{}' is not a number of seconds", &val)),
+    }
+}
+
+fn valid_maintenance_window(val: String) -> result::Result<(), String> {
+    match MaintenanceWindow::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Maintenance window: '{}' is not in the form HH:MM-HH:MM", &val)),
+    }
+}
+
+fn valid_restart_policy(val: String) -> result::Result<(), String> {
+    match RestartPolicy::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Restart policy: '{}' is not valid", &val)),
+    }
+}
+
+fn valid_restart_max(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Restart max: '{}' is not a number of restarts", &val)),
+    }
+}
+
+fn valid_restart_secs(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Restart timing: '{}' is not a number of seconds", &val)),
+    }
+}
+
+fn valid_health_check_interval(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(secs) if secs > 0 => Ok(()),
+        _ => Err(format!("Health check interval: '{}' is not a positive number of seconds", &val)),
+    }
+}
+
+fn valid_health_check_timeout(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Health check timeout: '{}' is not a number of seconds", &val)),
+    }
+}
+
+fn valid_health_check_threshold(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(threshold) if threshold > 0 => Ok(()),
+        _ => Err(format!("Health check threshold: '{}' is not a positive number of checks", &val)),
+    }
+}
+
+fn valid_shutdown_timeout(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Shutdown timeout: '{}' is not a number of seconds", &val)),
+    }
+}
+
+fn valid_cpu_shares(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(shares) if shares >= 2 => Ok(()),
+        _ => Err(format!("CPU shares: '{}' is not a number of at least 2", &val)),
+    }
+}
+
+fn valid_resource_limit(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(limit) if limit > 0 => Ok(()),
+        _ => Err(format!("Resource limit: '{}' is not a positive number", &val)),
+    }
+}
+
+fn valid_log_format(val: String) -> result::Result<(), String> {
+    match LogFormat::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Log format: '{}' is not valid", &val)),
+    }
+}
+
+fn valid_log_number(val: String) -> result::Result<(), String> {
+    match val.parse::<u32>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Log setting: '{}' is not a number", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
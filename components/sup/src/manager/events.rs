@@ -0,0 +1,259 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Events describing changes to the state of the Supervisor's services. The Manager publishes
+//! events to an `EventBus` which the `http_gateway` streams to its clients.
+
+use std::fmt;
+use std::result;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender};
+use std::time::Duration;
+
+use hcore::service::ServiceGroup;
+use serde;
+use time;
+
+use manager::service::HealthCheck;
+
+/// Maximum number of subscribers at once. Each client streaming events from the `http_gateway`
+/// holds one of its worker threads for as long as it is subscribed.
+pub const MAX_SUBSCRIBERS: usize = 4;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventKind {
+    ServiceStarted,
+    ServiceStopped,
+    HealthChanged,
+    LeaderElected,
+    ConfigUpdated,
+    FilesUpdated,
+    PackageUpdated,
+}
+
+impl EventKind {
+    fn as_str(&self) -> &str {
+        match *self {
+            EventKind::ServiceStarted => "service-started",
+            EventKind::ServiceStopped => "service-stopped",
+            EventKind::HealthChanged => "health-changed",
+            EventKind::LeaderElected => "leader-elected",
+            EventKind::ConfigUpdated => "config-updated",
+            EventKind::FilesUpdated => "files-updated",
+            EventKind::PackageUpdated => "package-updated",
+        }
+    }
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl serde::Serialize for EventKind {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Event {
+    pub kind: EventKind,
+    pub service_group: String,
+    pub detail: String,
+    pub timestamp: String,
+}
+
+impl Event {
+    pub fn new<T: ToString>(kind: EventKind, service_group: &ServiceGroup, detail: T) -> Self {
+        Event {
+            kind: kind,
+            service_group: service_group.to_string(),
+            detail: detail.to_string(),
+            timestamp: time::now_utc().rfc3339().to_string(),
+        }
+    }
+}
+
+/// Delivers each published event to every current subscriber.
+#[derive(Clone, Debug, Default)]
+pub struct EventBus {
+    subscribers: Arc<Mutex<Vec<Sender<Event>>>>,
+    active: Arc<AtomicUsize>,
+}
+
+impl EventBus {
+    /// Subscribe to events, or return `None` if there are already `MAX_SUBSCRIBERS` subscribers.
+    pub fn subscribe(&self) -> Option<Subscription> {
+        if self.active.fetch_add(1, Ordering::SeqCst) >= MAX_SUBSCRIBERS {
+            self.active.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        let (tx, rx) = channel();
+        self.subscribers
+            .lock()
+            .expect("Event subscribers lock poisoned")
+            .push(tx);
+        Some(Subscription {
+                 events: rx,
+                 active: self.active.clone(),
+             })
+    }
+
+    /// Send an event to all subscribers, dropping those which have gone away.
+    pub fn publish(&self, event: Event) {
+        debug!("Publishing event {:?}", event);
+        self.subscribers
+            .lock()
+            .expect("Event subscribers lock poisoned")
+            .retain(|tx| tx.send(event.clone()).is_ok());
+    }
+}
+
+/// Receives the events published to an `EventBus` until it is dropped.
+#[derive(Debug)]
+pub struct Subscription {
+    events: Receiver<Event>,
+    active: Arc<AtomicUsize>,
+}
+
+impl Subscription {
+    pub fn recv_timeout(&self, timeout: Duration) -> result::Result<Event, RecvTimeoutError> {
+        self.events.recv_timeout(timeout)
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// The parts of a service's state whose changes are published as events.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceSnapshot {
+    pub up: bool,
+    pub health: HealthCheck,
+    pub package: String,
+    pub config_incarnation: u64,
+    pub files_incarnation: u64,
+}
+
+impl ServiceSnapshot {
+    /// Returns the events describing the changes since an earlier snapshot of the same service.
+    pub fn changes_since(&self,
+                         previous: &ServiceSnapshot,
+                         service_group: &ServiceGroup)
+                         -> Vec<Event> {
+        let mut events = Vec::new();
+        if self.package != previous.package {
+            events.push(Event::new(EventKind::PackageUpdated, service_group, &self.package));
+        }
+        if self.config_incarnation != previous.config_incarnation {
+            events.push(Event::new(EventKind::ConfigUpdated,
+                                   service_group,
+                                   self.config_incarnation));
+        }
+        if self.files_incarnation != previous.files_incarnation {
+            events.push(Event::new(EventKind::FilesUpdated,
+                                   service_group,
+                                   self.files_incarnation));
+        }
+        if self.up != previous.up {
+            let kind = if self.up {
+                EventKind::ServiceStarted
+            } else {
+                EventKind::ServiceStopped
+            };
+            events.push(Event::new(kind, service_group, &self.package));
+        }
+        if self.health != previous.health {
+            events.push(Event::new(EventKind::HealthChanged, service_group, self.health));
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::service::ServiceGroup;
+
+    use super::*;
+    use manager::service::HealthCheck;
+
+    fn snapshot() -> ServiceSnapshot {
+        ServiceSnapshot {
+            up: true,
+            health: HealthCheck::Ok,
+            package: String::from("core/redis/3.2.4/20170101000000"),
+            config_incarnation: 1,
+            files_incarnation: 0,
+        }
+    }
+
+    #[test]
+    fn unchanged_snapshot_has_no_events() {
+        let sg = ServiceGroup::from_str("redis.default").unwrap();
+
+        assert!(snapshot().changes_since(&snapshot(), &sg).is_empty());
+    }
+
+    #[test]
+    fn changed_snapshot_has_events() {
+        let sg = ServiceGroup::from_str("redis.default").unwrap();
+        let mut current = snapshot();
+        current.up = false;
+        current.health = HealthCheck::Critical;
+        let events = current.changes_since(&snapshot(), &sg);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, EventKind::ServiceStopped);
+        assert_eq!(events[1].kind, EventKind::HealthChanged);
+        assert_eq!(events[1].detail, "CRITICAL");
+        assert_eq!(events[1].service_group, "redis.default");
+    }
+
+    #[test]
+    fn event_bus_drops_closed_subscribers() {
+        let sg = ServiceGroup::from_str("redis.default").unwrap();
+        let bus = EventBus::default();
+        let subscription = bus.subscribe().unwrap();
+        drop(bus.subscribe());
+        bus.publish(Event::new(EventKind::LeaderElected, &sg, "member"));
+
+        assert_eq!(subscription
+                       .recv_timeout(Duration::from_secs(1))
+                       .unwrap()
+                       .kind,
+                   EventKind::LeaderElected);
+        assert_eq!(bus.subscribers.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn event_bus_limits_subscribers() {
+        let bus = EventBus::default();
+        let mut subscriptions: Vec<Subscription> =
+            (0..MAX_SUBSCRIBERS).map(|_| bus.subscribe().unwrap()).collect();
+
+        assert!(bus.subscribe().is_none());
+        subscriptions.pop();
+        assert!(bus.subscribe().is_some());
+    }
+}
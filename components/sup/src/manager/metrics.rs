@@ -0,0 +1,333 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prometheus metrics describing the lifecycle of supervised services and the state of the
+//! gossip ring. They are registered with the default registry and so are served by the HTTP
+//! gateway's `/metrics` endpoint along with its own.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use butterfly;
+use butterfly::member::Health;
+use hcore::package::PackageIdent;
+use hcore::service::ServiceGroup;
+use prometheus::{CounterVec, Gauge, GaugeVec, HistogramVec};
+
+use manager::service::HealthCheck;
+
+lazy_static! {
+    static ref SERVICE_UP: GaugeVec = register_gauge_vec!(
+        opts!(
+            "service_up",
+            "Whether the service's process is up (1) or not (0)."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_RESTARTS: CounterVec = register_counter_vec!(
+        opts!(
+            "service_restarts_total",
+            "Total number of times the service's process was restarted after exiting."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_HEALTH: GaugeVec = register_gauge_vec!(
+        opts!(
+            "service_health_check_status",
+            "Result of the service's last health check; 0 ok, 1 warning, 2 critical, \
+            3 unknown."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_HEALTH_DURATION: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "service_health_check_duration_seconds",
+            "Health check durations in seconds."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_HOOK_RUNS: CounterVec = register_counter_vec!(
+        opts!(
+            "service_hook_runs_total",
+            "Total number of times a service's hook was run."),
+        &["service_group", "hook"]).unwrap();
+
+    static ref SERVICE_HOOK_FAILURES: CounterVec = register_counter_vec!(
+        opts!(
+            "service_hook_failures_total",
+            "Total number of times a service's hook failed to run, timed out, or exited \
+            unsuccessfully."),
+        &["service_group", "hook"]).unwrap();
+
+    static ref SERVICE_CONFIG_RELOADS: CounterVec = register_counter_vec!(
+        opts!(
+            "service_config_reloads_total",
+            "Total number of times the service was reloaded or reconfigured with new \
+            configuration."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_PACKAGE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "service_package_info",
+            "The package the service is running (1), or has run (0)."),
+        &["service_group", "ident"]).unwrap();
+
+    /// The hooks and packages for which metrics have been recorded for each service group, so
+    /// that they can be removed along with the service.
+    static ref SERVICE_LABELS: Mutex<HashMap<String, ServiceLabels>> = Mutex::new(HashMap::new());
+
+    static ref BUTTERFLY_MEMBERS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "butterfly_members",
+            "Number of members of the gossip ring by health."),
+        &["health"]).unwrap();
+
+    static ref BUTTERFLY_RUMORS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "butterfly_rumors",
+            "Number of rumors held by kind."),
+        &["kind"]).unwrap();
+
+    static ref BUTTERFLY_SWIM_ROUNDS: Gauge = register_gauge!(
+        opts!(
+            "butterfly_swim_rounds",
+            "Number of SWIM protocol rounds completed.")).unwrap();
+
+    static ref BUTTERFLY_GOSSIP_ROUNDS: Gauge = register_gauge!(
+        opts!(
+            "butterfly_gossip_rounds",
+            "Number of gossip rounds completed.")).unwrap();
+}
+
+#[derive(Default)]
+struct ServiceLabels {
+    hooks: HashSet<String>,
+    idents: HashSet<String>,
+}
+
+fn with_service_labels<F>(service_group: &ServiceGroup, f: F)
+    where F: FnOnce(&mut ServiceLabels)
+{
+    let mut labels = SERVICE_LABELS.lock().expect("Service labels lock poisoned");
+    f(labels.entry(service_group.to_string()).or_insert_with(ServiceLabels::default));
+}
+
+pub fn set_service_up(service_group: &str, up: bool) {
+    SERVICE_UP
+        .with_label_values(&[service_group])
+        .set(if up { 1.0 } else { 0.0 });
+}
+
+pub fn record_restart(service_group: &ServiceGroup) {
+    SERVICE_RESTARTS.with_label_values(&[service_group.as_ref()]).inc();
+}
+
+pub fn record_health_check(service_group: &ServiceGroup,
+                           health_check: HealthCheck,
+                           duration: Duration) {
+    let status = match health_check {
+        HealthCheck::Ok => 0.0,
+        HealthCheck::Warning => 1.0,
+        HealthCheck::Critical => 2.0,
+        HealthCheck::Unknown => 3.0,
+    };
+    SERVICE_HEALTH.with_label_values(&[service_group.as_ref()]).set(status);
+    let secs = duration.as_secs() as f64 + duration.subsec_nanos() as f64 / 1_000_000_000.0;
+    SERVICE_HEALTH_DURATION
+        .with_label_values(&[service_group.as_ref()])
+        .observe(secs);
+}
+
+pub fn record_hook_run(service_group: &ServiceGroup, hook: &str) {
+    with_service_labels(service_group, |labels| {
+        labels.hooks.insert(hook.to_string());
+    });
+    SERVICE_HOOK_RUNS
+        .with_label_values(&[service_group.as_ref(), hook])
+        .inc();
+}
+
+pub fn record_hook_failure(service_group: &ServiceGroup, hook: &str) {
+    with_service_labels(service_group, |labels| {
+        labels.hooks.insert(hook.to_string());
+    });
+    SERVICE_HOOK_FAILURES
+        .with_label_values(&[service_group.as_ref(), hook])
+        .inc();
+}
+
+pub fn record_config_reload(service_group: &ServiceGroup) {
+    SERVICE_CONFIG_RELOADS
+        .with_label_values(&[service_group.as_ref()])
+        .inc();
+}
+
+/// Record the package a service runs, marking the package it ran before, if any, as no longer
+/// running.
+pub fn set_service_package(service_group: &ServiceGroup,
+                           previous: Option<&PackageIdent>,
+                           current: &PackageIdent) {
+    with_service_labels(service_group, |labels| {
+        labels.idents.insert(current.to_string());
+    });
+    if let Some(previous) = previous {
+        SERVICE_PACKAGE
+            .with_label_values(&[service_group.as_ref(), &previous.to_string()])
+            .set(0.0);
+    }
+    SERVICE_PACKAGE
+        .with_label_values(&[service_group.as_ref(), &current.to_string()])
+        .set(1.0);
+}
+
+/// Remove every metric recorded for a service, so that a service which has been unloaded is no
+/// longer reported.
+pub fn remove_service(service_group: &ServiceGroup) {
+    let sg = service_group.as_ref();
+    // Removing label values which were never recorded fails harmlessly.
+    let _ = SERVICE_UP.remove_label_values(&[sg]);
+    let _ = SERVICE_RESTARTS.remove_label_values(&[sg]);
+    let _ = SERVICE_HEALTH.remove_label_values(&[sg]);
+    let _ = SERVICE_HEALTH_DURATION.remove_label_values(&[sg]);
+    let _ = SERVICE_CONFIG_RELOADS.remove_label_values(&[sg]);
+    let labels = SERVICE_LABELS
+        .lock()
+        .expect("Service labels lock poisoned")
+        .remove(sg);
+    if let Some(labels) = labels {
+        for hook in labels.hooks.iter() {
+            let _ = SERVICE_HOOK_RUNS.remove_label_values(&[sg, hook]);
+            let _ = SERVICE_HOOK_FAILURES.remove_label_values(&[sg, hook]);
+        }
+        for ident in labels.idents.iter() {
+            let _ = SERVICE_PACKAGE.remove_label_values(&[sg, ident]);
+        }
+    }
+}
+
+/// Record the state of the gossip ring; called on every turn of the Supervisor's run loop.
+pub fn observe_butterfly(server: &butterfly::Server) {
+    let (mut alive, mut suspect, mut confirmed) = (0, 0, 0);
+    server
+        .member_list
+        .with_members(|member| match server.member_list.health_of(member) {
+                          Some(Health::Alive) => alive += 1,
+                          Some(Health::Suspect) => suspect += 1,
+                          Some(Health::Confirmed) => confirmed += 1,
+                          None => {}
+                      });
+    for &(health, count) in [("alive", alive), ("suspect", suspect), ("confirmed", confirmed)]
+            .iter() {
+        BUTTERFLY_MEMBERS
+            .with_label_values(&[health])
+            .set(count as f64);
+    }
+    let rumors = [("service", server.service_store.len()),
+                  ("service_config", server.service_config_store.len()),
+                  ("service_file", server.service_file_store.len()),
+                  ("service_secret", server.service_secret_store.len()),
+                  ("election", server.election_store.len()),
+                  ("update_election", server.update_store.len())];
+    for &(kind, count) in rumors.iter() {
+        BUTTERFLY_RUMORS
+            .with_label_values(&[kind])
+            .set(count as f64);
+    }
+    BUTTERFLY_SWIM_ROUNDS.set(server.swim_rounds() as f64);
+    BUTTERFLY_GOSSIP_ROUNDS.set(server.gossip_rounds() as f64);
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use hcore::package::PackageIdent;
+    use hcore::service::ServiceGroup;
+
+    use manager::service::HealthCheck;
+    use super::*;
+
+    // Metrics are global, so each test uses a service group of its own.
+
+    #[test]
+    fn service_up_follows_process_state() {
+        set_service_up("metrics-up.default", true);
+        assert_eq!(SERVICE_UP.with_label_values(&["metrics-up.default"]).get(), 1.0);
+
+        set_service_up("metrics-up.default", false);
+        assert_eq!(SERVICE_UP.with_label_values(&["metrics-up.default"]).get(), 0.0);
+    }
+
+    #[test]
+    fn recorders_count_restarts_hooks_and_health_checks() {
+        let sg = ServiceGroup::from_str("metrics-count.default").unwrap();
+
+        record_restart(&sg);
+        record_restart(&sg);
+        record_hook_run(&sg, "health_check");
+        record_hook_run(&sg, "health_check");
+        record_hook_failure(&sg, "health_check");
+        record_health_check(&sg, HealthCheck::Critical, Duration::from_millis(10));
+        record_config_reload(&sg);
+
+        assert_eq!(SERVICE_RESTARTS.with_label_values(&[sg.as_ref()]).get(), 2.0);
+        assert_eq!(SERVICE_HOOK_RUNS.with_label_values(&[sg.as_ref(), "health_check"]).get(),
+                   2.0);
+        assert_eq!(SERVICE_HOOK_FAILURES
+                       .with_label_values(&[sg.as_ref(), "health_check"])
+                       .get(),
+                   1.0);
+        assert_eq!(SERVICE_HEALTH.with_label_values(&[sg.as_ref()]).get(), 2.0);
+        assert_eq!(SERVICE_CONFIG_RELOADS.with_label_values(&[sg.as_ref()]).get(), 1.0);
+    }
+
+    #[test]
+    fn service_package_marks_previous_package_as_no_longer_running() {
+        let sg = ServiceGroup::from_str("metrics-package.default").unwrap();
+        let old = PackageIdent::from_str("core/redis/3.2.3/20170101000000").unwrap();
+        let new = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+
+        set_service_package(&sg, None, &old);
+        set_service_package(&sg, Some(&old), &new);
+
+        assert_eq!(SERVICE_PACKAGE
+                       .with_label_values(&[sg.as_ref(), &old.to_string()])
+                       .get(),
+                   0.0);
+        assert_eq!(SERVICE_PACKAGE
+                       .with_label_values(&[sg.as_ref(), &new.to_string()])
+                       .get(),
+                   1.0);
+    }
+
+    #[test]
+    fn remove_service_drops_its_metrics() {
+        let sg = ServiceGroup::from_str("metrics-remove.default").unwrap();
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        set_service_up(sg.as_ref(), true);
+        record_restart(&sg);
+        record_hook_run(&sg, "run");
+        set_service_package(&sg, None, &ident);
+
+        remove_service(&sg);
+
+        // Looking the metrics up again starts them afresh.
+        assert_eq!(SERVICE_UP.with_label_values(&[sg.as_ref()]).get(), 0.0);
+        assert_eq!(SERVICE_RESTARTS.with_label_values(&[sg.as_ref()]).get(), 0.0);
+        assert_eq!(SERVICE_HOOK_RUNS.with_label_values(&[sg.as_ref(), "run"]).get(), 0.0);
+        assert_eq!(SERVICE_PACKAGE
+                       .with_label_values(&[sg.as_ref(), &ident.to_string()])
+                       .get(),
+                   0.0);
+        assert!(!SERVICE_LABELS.lock().unwrap().contains_key(sg.as_ref()));
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod events;
+pub mod metrics;
 pub mod service;
 mod signals;
 mod service_updater;
@@ -24,12 +26,13 @@ use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::thread;
 use std::sync::{Arc, RwLock};
-use std::sync::mpsc::channel;
+use std::sync::mpsc::{channel, Receiver};
 use std::time::Duration;
 
 use byteorder::{ByteOrder, LittleEndian};
 use butterfly;
 use butterfly::member::Member;
+use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
 use butterfly::trace::Trace;
 use butterfly::server::timing::Timing;
 use butterfly::server::Suitability;
@@ -45,11 +48,12 @@ use time::{SteadyTime, Duration as TimeDuration};
 use toml;
 
 pub use manager::service::{Service, ServiceConfig, ServiceSpec, UpdateStrategy, Topology};
-use self::service::{DesiredState, StartStyle};
-use self::service_updater::ServiceUpdater;
+use self::events::{Event, EventBus, EventKind, ServiceSnapshot};
+use self::service::{start_order, DesiredState, StartStyle};
+use self::service_updater::{self as updater, ServiceUpdater};
 use self::spec_watcher::{SpecWatcher, SpecWatcherEvent};
 use error::{Error, Result, SupError};
-use config::GossipListenAddr;
+use config::{self, GossipListenAddr, SupConfig, DEFAULT_UPDATE_FREQUENCY_SECS, SUP_CONFIG_FILE};
 use census::CensusRing;
 use manager::signals::SignalEvent;
 use http_gateway;
@@ -78,10 +82,13 @@ pub struct FsCfg {
     pub services_data_path: PathBuf,
     specs_path: PathBuf,
     proc_lock_file: PathBuf,
+    /// Name of the Supervisor's state directory, which tells its services' cgroups apart from
+    /// those of the other Supervisors on the host.
+    pub sup_name: String,
 }
 
 impl FsCfg {
-    fn new<T>(sup_svc_root: T) -> Self
+    pub fn new<T>(sup_svc_root: T) -> Self
         where T: Into<PathBuf>
     {
         let sup_svc_root = sup_svc_root.into();
@@ -93,6 +100,10 @@ impl FsCfg {
             specs_path: sup_svc_root.join("specs"),
             data_path: data_path,
             proc_lock_file: sup_svc_root.join(PROC_LOCK_FILE),
+            sup_name: sup_svc_root
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "default".to_string()),
         }
     }
 
@@ -100,18 +111,59 @@ impl FsCfg {
         self.data_path
             .join(format!("{}.health", service_group.service()))
     }
+
+    pub fn spec_file(&self, service_name: &str) -> PathBuf {
+        self.specs_path.join(ServiceSpec::file_name_for(service_name))
+    }
+}
+
+/// Requests made to the Manager by the `http_gateway` which can't be expressed as a change to a
+/// service's spec file.
+#[derive(Debug)]
+pub enum ServiceCommand {
+    Restart(ServiceGroup),
+    ApplyConfig(ServiceGroup, Vec<u8>),
 }
 
-#[derive(Default)]
 pub struct ManagerConfig {
     pub gossip_listen: GossipListenAddr,
     pub http_listen: http_gateway::ListenAddr,
     pub gossip_peers: Vec<SocketAddr>,
     pub gossip_permanent: bool,
+    pub gossip_timing: Timing,
     pub ring: Option<String>,
     pub name: Option<String>,
     custom_state_path: Option<PathBuf>,
     pub organization: Option<String>,
+    /// Bearer token required by the `http_gateway` for requests which modify services. Those
+    /// requests are refused when no token is set.
+    pub http_auth_token: Option<String>,
+    /// Depot used by services which are loaded without one.
+    pub depot_url: Option<String>,
+    pub update_frequency_secs: u64,
+    /// The Supervisor config file these options were read from, if any. It is read again when
+    /// the Supervisor receives SIGHUP.
+    pub config_file: Option<PathBuf>,
+}
+
+impl Default for ManagerConfig {
+    fn default() -> Self {
+        ManagerConfig {
+            gossip_listen: GossipListenAddr::default(),
+            http_listen: http_gateway::ListenAddr::default(),
+            gossip_peers: Vec::new(),
+            gossip_permanent: false,
+            gossip_timing: Timing::default(),
+            ring: None,
+            name: None,
+            custom_state_path: None,
+            organization: None,
+            http_auth_token: None,
+            depot_url: None,
+            update_frequency_secs: DEFAULT_UPDATE_FREQUENCY_SECS,
+            config_file: None,
+        }
+    }
 }
 
 pub struct Manager {
@@ -122,8 +174,15 @@ pub struct Manager {
     updater: ServiceUpdater,
     watcher: SpecWatcher,
     gossip_listen: GossipListenAddr,
+    gossip_peers: Vec<SocketAddr>,
+    gossip_timing: Timing,
     http_listen: http_gateway::ListenAddr,
+    http_auth_token: Option<String>,
     organization: Option<String>,
+    config_file: Option<PathBuf>,
+    events: EventBus,
+    service_snapshots: HashMap<ServiceGroup, ServiceSnapshot>,
+    leaders: HashMap<ServiceGroup, String>,
 }
 
 impl Manager {
@@ -180,12 +239,9 @@ impl Manager {
                                             Box::new(SuitabilityLookup(services.clone())))?;
         outputln!("Butterfly Member ID {}", server.member_id());
         for peer_addr in &cfg.gossip_peers {
-            let mut peer = Member::default();
-            peer.set_address(format!("{}", peer_addr.ip()));
-            peer.set_swim_port(peer_addr.port() as i32);
-            peer.set_gossip_port(peer_addr.port() as i32);
-            server.member_list.add_initial_member(peer);
+            add_initial_peer(&server, peer_addr);
         }
+        updater::set_update_frequency(cfg.update_frequency_secs);
         Ok(Manager {
                updater: ServiceUpdater::new(server.clone()),
                census_ring: CensusRing::new(server.member_id()),
@@ -194,8 +250,15 @@ impl Manager {
                watcher: SpecWatcher::run(&fs_cfg.specs_path)?,
                fs_cfg: Arc::new(fs_cfg),
                gossip_listen: cfg.gossip_listen,
+               gossip_peers: cfg.gossip_peers,
+               gossip_timing: cfg.gossip_timing,
                http_listen: cfg.http_listen,
+               http_auth_token: cfg.http_auth_token,
                organization: cfg.organization,
+               config_file: cfg.config_file,
+               events: EventBus::default(),
+               service_snapshots: HashMap::new(),
+               leaders: HashMap::new(),
            })
     }
 
@@ -224,6 +287,17 @@ impl Manager {
         Ok(member)
     }
 
+    /// The Supervisor config file read when none is named on the command line or in the
+    /// environment.
+    pub fn config_path_for(cfg: &ManagerConfig) -> PathBuf {
+        Self::state_path_from(cfg).join(SUP_CONFIG_FILE)
+    }
+
+    /// The file the Supervisor records the state of its services in.
+    pub fn services_data_path_for(cfg: &ManagerConfig) -> PathBuf {
+        FsCfg::new(Self::state_path_from(cfg)).services_data_path
+    }
+
     pub fn spec_path_for(cfg: &ManagerConfig, spec: &ServiceSpec) -> PathBuf {
         Self::specs_path(&Self::state_path_from(cfg)).join(spec.file_name())
     }
@@ -324,7 +398,9 @@ impl Manager {
         };
         self.butterfly
             .insert_service(service.to_rumor(self.butterfly.member_id()));
-        if service.topology == Topology::Leader {
+        if service.topology.is_elected() {
+            self.butterfly
+                .configure_election(&service.service_group, service.election_config());
             self.butterfly
                 .start_election(service.service_group.clone(), 0);
         }
@@ -338,6 +414,8 @@ impl Manager {
     fn remove_service(&self, service: &mut Service) {
         // JW TODO: Update service rumor to remove service from cluster
         service.stop();
+        service.remove_secrets();
+        metrics::remove_service(&service.service_group);
         if service.start_style == StartStyle::Transient {
             // JW TODO: If we cleanup our Service structure to hold the ServiceSpec instead of
             // deconstruct it (see my comments in `add_service()` in this module) then we could
@@ -355,11 +433,17 @@ impl Manager {
         self.start_initial_services_from_watcher()?;
 
         outputln!("Starting butterfly on {}", self.butterfly.gossip_addr());
-        try!(self.butterfly.start(Timing::default()));
+        try!(self.butterfly.start(self.gossip_timing.clone()));
         debug!("butterfly server started");
         self.persist_state();
         outputln!("Starting http-gateway on {}", self.http_listen);
-        try!(http_gateway::Server::new(self.fs_cfg.clone(), self.http_listen.clone()).start());
+        let (command_tx, command_rx) = channel::<ServiceCommand>();
+        try!(http_gateway::Server::new(self.fs_cfg.clone(),
+                                       self.http_listen.clone(),
+                                       self.http_auth_token.clone(),
+                                       command_tx,
+                                       self.events.clone())
+                     .start());
         debug!("http-gateway server started");
 
         let (event_tx, event_rx) = channel::<Vec<CensusEntryProto>>();
@@ -412,6 +496,7 @@ impl Manager {
                 return Ok(());
             }
             self.update_running_services_from_watcher()?;
+            self.check_for_service_commands(&command_rx);
             service_rumor_offset += self.check_for_updated_packages();
             self.restart_elections();
             self.census_ring
@@ -424,6 +509,7 @@ impl Manager {
 
             if self.census_ring.changed {
                 self.persist_state();
+                self.publish_leader_events();
 
                 let mut censuses = Vec::<CensusEntryProto>::new();
                 for service in self.services
@@ -454,6 +540,8 @@ impl Manager {
                     service_rumor_offset += 1;
                 }
             }
+            self.publish_service_events();
+            metrics::observe_butterfly(&self.butterfly);
             let time_to_wait = (next_check - SteadyTime::now()).num_milliseconds();
             if time_to_wait > 0 {
                 thread::sleep(Duration::from_millis(time_to_wait as u64));
@@ -468,13 +556,17 @@ impl Manager {
     //
     // This function returns true if we are supposed to shut the system down, false if we
     // can keep going.
+    //
+    // SIGHUP also reloads the Supervisor config file, when the Supervisor was started with one,
+    // before it is passed through as any other signal.
     fn check_for_incoming_signals(&mut self) -> bool {
         match signals::check_for_signal() {
             Some(SignalEvent::Shutdown) => {
-                for service in self.services
-                        .write()
-                        .expect("Services lock is poisoned!")
-                        .iter_mut() {
+                let mut services = self.services
+                    .write()
+                    .expect("Services lock is poisoned!");
+                for idx in stop_order(&services) {
+                    let service = &mut services[idx];
                     outputln!("Shutting down {}", service);
                     service
                         .down()
@@ -483,6 +575,9 @@ impl Manager {
                 true
             }
             Some(SignalEvent::Passthrough(signal_code)) => {
+                if signal_code == signals::Signal::SIGHUP as u32 && self.config_file.is_some() {
+                    self.reload_config();
+                }
                 for service in self.services
                         .read()
                         .expect("Services lock is poisoned!")
@@ -501,6 +596,148 @@ impl Manager {
         }
     }
 
+    /// Read the Supervisor config file again and apply the options which can change while the
+    /// Supervisor runs: new initial peers are joined and the update frequency is changed. Other
+    /// options only take effect after a restart.
+    fn reload_config(&mut self) {
+        let path = match self.config_file {
+            Some(ref path) => path.clone(),
+            None => return,
+        };
+        outputln!("Reloading Supervisor config from {}", path.display());
+        let sup_config = match SupConfig::from_file(&path) {
+            Ok(sup_config) => sup_config,
+            Err(err) => {
+                outputln!("Unable to reload Supervisor config, {}", err);
+                return;
+            }
+        };
+        match config::resolve_peers(&sup_config.peers) {
+            Ok(peers) => {
+                for peer_addr in peers {
+                    if !self.gossip_peers.contains(&peer_addr) {
+                        outputln!("Adding peer {}", peer_addr);
+                        add_initial_peer(&self.butterfly, &peer_addr);
+                        self.gossip_peers.push(peer_addr);
+                    }
+                }
+            }
+            Err(err) => outputln!("Unable to resolve peers, {}", err),
+        }
+        updater::set_update_frequency(sup_config.update_frequency_secs());
+    }
+
+    /// Publish events for changes to the state of our services since the last tick.
+    fn publish_service_events(&mut self) {
+        let services = self.services
+            .read()
+            .expect("Services lock is poisoned!");
+        let mut snapshots = HashMap::new();
+        for service in services.iter() {
+            let snapshot = service.snapshot();
+            match self.service_snapshots.get(&service.service_group) {
+                Some(previous) => {
+                    for event in snapshot.changes_since(previous, &service.service_group) {
+                        self.events.publish(event);
+                    }
+                }
+                None => {
+                    if snapshot.up {
+                        self.events
+                            .publish(Event::new(EventKind::ServiceStarted,
+                                                &service.service_group,
+                                                &snapshot.package));
+                    }
+                }
+            }
+            snapshots.insert(service.service_group.clone(), snapshot);
+        }
+        for (service_group, previous) in self.service_snapshots.iter() {
+            if snapshots.contains_key(service_group) {
+                continue;
+            }
+            if previous.up {
+                self.events
+                    .publish(Event::new(EventKind::ServiceStopped,
+                                        service_group,
+                                        &previous.package));
+            }
+            self.leaders.remove(service_group);
+        }
+        self.service_snapshots = snapshots;
+    }
+
+    /// Publish events for newly elected leaders of our services' groups.
+    fn publish_leader_events(&mut self) {
+        let services = self.services
+            .read()
+            .expect("Services lock is poisoned!");
+        for service in services.iter() {
+            let leader_id = match self.census_ring
+                      .census_group_for(&service.service_group)
+                      .and_then(|cg| cg.leader_id.clone()) {
+                Some(leader_id) => leader_id,
+                None => continue,
+            };
+            if self.leaders.get(&service.service_group) != Some(&leader_id) {
+                self.events
+                    .publish(Event::new(EventKind::LeaderElected,
+                                        &service.service_group,
+                                        &leader_id));
+                self.leaders
+                    .insert(service.service_group.clone(), leader_id);
+            }
+        }
+    }
+
+    /// Carry out any requests received by the `http_gateway` since the last tick.
+    fn check_for_service_commands(&mut self, commands: &Receiver<ServiceCommand>) {
+        while let Ok(command) = commands.try_recv() {
+            match command {
+                ServiceCommand::Restart(sg) => {
+                    let mut services = self.services
+                        .write()
+                        .expect("Services lock is poisoned!");
+                    match services.iter_mut().find(|s| same_service_group(&s.service_group, &sg)) {
+                        Some(service) => service.restart(),
+                        None => outputln!("Cannot restart {}, service is not running", sg),
+                    }
+                }
+                ServiceCommand::ApplyConfig(sg, config) => {
+                    let service_group = self.services
+                        .read()
+                        .expect("Services lock is poisoned!")
+                        .iter()
+                        .find(|s| same_service_group(&s.service_group, &sg))
+                        .map(|s| s.service_group.clone());
+                    match service_group {
+                        Some(service_group) => self.apply_service_config(service_group, config),
+                        None => outputln!("Cannot apply configuration to {}, service is not running",
+                                          sg),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Gossip a new configuration for a service group, superseding its current configuration.
+    fn apply_service_config(&self, service_group: ServiceGroup, config: Vec<u8>) {
+        let mut incarnation = 1;
+        self.butterfly
+            .service_config_store
+            .with_rumor(&*service_group,
+                        "service_config",
+                        |rumor| if let Some(sc) = rumor {
+                            incarnation = sc.get_incarnation() + 1;
+                        });
+        outputln!("Applying configuration version {} to {}",
+                  incarnation,
+                  service_group);
+        let mut rumor = ServiceConfigRumor::new(self.butterfly.member_id(), service_group, config);
+        rumor.set_incarnation(incarnation);
+        self.butterfly.insert_service_config(rumor);
+    }
+
     /// Walk each service and check if it has an updated package installed via the Update Strategy.
     /// This updates the Service to point to the new service struct, and then marks it for
     /// restarting.
@@ -516,8 +753,14 @@ impl Manager {
                 .write()
                 .expect("Services lock is poisoned!")
                 .iter_mut() {
-            if self.updater
-                   .check_for_updated_package(service, &self.census_ring) {
+            let updated = if service.rollback() {
+                self.updater.remove(&service.service_group);
+                true
+            } else {
+                self.updater
+                    .check_for_updated_package(service, &self.census_ring)
+            };
+            if updated {
                 let mut rumor = {
                     let list = self.butterfly
                         .service_store
@@ -643,25 +886,39 @@ impl Manager {
         let mut services = self.services
             .write()
             .expect("Services lock is poisend!");
-        for mut service in services.drain(..) {
-            self.remove_service(&mut service);
+        // Services are stopped one at a time, each before the services it binds to.
+        let order = stop_order(&services);
+        let mut stopping: Vec<Option<Service>> = services.drain(..).map(Some).collect();
+        for idx in order {
+            if let Some(mut service) = stopping[idx].take() {
+                self.remove_service(&mut service);
+            }
         }
         release_process_lock(&self.fs_cfg);
         outputln!("Habitat thanks you - shutting down!");
     }
 
     fn start_initial_services_from_watcher(&mut self) -> Result<()> {
+        let mut specs = Vec::new();
         for service_event in self.watcher.initial_events()? {
             match service_event {
                 SpecWatcherEvent::AddService(spec) => {
                     if spec.desired_state == DesiredState::Up {
-                        // JW TODO: Should we retry starting services which we failed to add?
-                        self.add_service(spec);
+                        specs.push(spec);
                     }
                 }
                 _ => warn!("Skipping unexpected watcher event: {:?}", service_event),
             }
         }
+        // Services are added so that each is started after the services it binds to.
+        let order = start_order(&specs);
+        let mut specs: Vec<Option<ServiceSpec>> = specs.into_iter().map(Some).collect();
+        for idx in order {
+            if let Some(spec) = specs[idx].take() {
+                // JW TODO: Should we retry starting services which we failed to add?
+                self.add_service(spec);
+            }
+        }
         Ok(())
     }
 
@@ -674,16 +931,24 @@ impl Manager {
             let spec = service.to_spec();
             active_specs.insert(spec.ident.name.clone(), spec);
         }
+        let mut specs = Vec::new();
         for service_event in self.watcher.new_events(active_specs)? {
             match service_event {
                 SpecWatcherEvent::AddService(spec) => {
                     if spec.desired_state == DesiredState::Up {
-                        self.add_service(spec);
+                        specs.push(spec);
                     }
                 }
                 SpecWatcherEvent::RemoveService(spec) => self.remove_service_for_spec(&spec)?,
             }
         }
+        let order = start_order(&specs);
+        let mut specs: Vec<Option<ServiceSpec>> = specs.into_iter().map(Some).collect();
+        for idx in order {
+            if let Some(spec) = specs[idx].take() {
+                self.add_service(spec);
+            }
+        }
         Ok(())
     }
 
@@ -718,6 +983,23 @@ impl Manager {
     }
 }
 
+fn add_initial_peer(server: &butterfly::Server, peer_addr: &SocketAddr) {
+    let mut peer = Member::default();
+    peer.set_address(format!("{}", peer_addr.ip()));
+    peer.set_swim_port(peer_addr.port() as i32);
+    peer.set_gossip_port(peer_addr.port() as i32);
+    server.member_list.add_initial_member(peer);
+}
+
+/// Returns the indices of the given services in the order they should be stopped, with every
+/// service before the services it binds to.
+fn stop_order(services: &[Service]) -> Vec<usize> {
+    let specs: Vec<ServiceSpec> = services.iter().map(|service| service.to_spec()).collect();
+    let mut order = start_order(&specs);
+    order.reverse();
+    order
+}
+
 #[derive(Debug)]
 struct SuitabilityLookup(Arc<RwLock<Vec<Service>>>);
 
@@ -733,6 +1015,12 @@ impl Suitability for SuitabilityLookup {
     }
 }
 
+/// Compares service groups by service and group name only, as requests from the `http_gateway`
+/// don't carry the organization of the service group.
+fn same_service_group(running: &ServiceGroup, requested: &ServiceGroup) -> bool {
+    running.service() == requested.service() && running.group() == requested.group()
+}
+
 fn obtain_process_lock(fs_cfg: &FsCfg) -> Result<()> {
     match write_process_lock(&fs_cfg.proc_lock_file) {
         Ok(()) => Ok(()),
@@ -802,9 +1090,33 @@ fn write_process_lock<T>(lock_path: T) -> Result<()>
 
 #[cfg(test)]
 mod test {
+    use std::fs;
     use std::path::PathBuf;
+    use std::sync::mpsc::channel;
+
+    use butterfly::member::Member;
+    use hcore::service::ServiceGroup;
+    use tempdir::TempDir;
+
+    use super::{same_service_group, FsCfg, Manager, ManagerConfig, ServiceCommand,
+                STATE_PATH_PREFIX};
+
+    fn manager(tmp: &TempDir) -> Manager {
+        let fs_cfg = FsCfg::new(tmp.path());
+        fs::create_dir_all(&fs_cfg.specs_path).unwrap();
+        Manager::new(ManagerConfig::default(), Member::default(), fs_cfg).unwrap()
+    }
 
-    use super::{Manager, ManagerConfig, STATE_PATH_PREFIX};
+    fn config_incarnation(manager: &Manager, sg: &ServiceGroup) -> Option<u64> {
+        let mut incarnation = None;
+        manager
+            .butterfly
+            .service_config_store
+            .with_rumor(&*sg,
+                        "service_config",
+                        |rumor| incarnation = rumor.map(|sc| sc.get_incarnation()));
+        incarnation
+    }
 
     #[test]
     fn manager_state_path_default() {
@@ -843,4 +1155,42 @@ mod test {
 
         assert_eq!(PathBuf::from("/tmp/partay"), path);
     }
+
+    #[test]
+    fn same_service_group_ignores_organization() {
+        let running = ServiceGroup::new("redis", "default", Some("acme")).unwrap();
+
+        assert!(same_service_group(&running,
+                                   &ServiceGroup::new("redis", "default", None).unwrap()));
+        assert!(!same_service_group(&running,
+                                    &ServiceGroup::new("redis", "prod", None).unwrap()));
+    }
+
+    #[test]
+    fn apply_service_config_supersedes_previous_config() {
+        let tmp = TempDir::new("manager").unwrap();
+        let manager = manager(&tmp);
+        let sg = ServiceGroup::new("redis", "default", None).unwrap();
+
+        manager.apply_service_config(sg.clone(), b"port = 6379".to_vec());
+        assert_eq!(config_incarnation(&manager, &sg), Some(1));
+        manager.apply_service_config(sg.clone(), b"port = 6380".to_vec());
+        assert_eq!(config_incarnation(&manager, &sg), Some(2));
+    }
+
+    #[test]
+    fn check_for_service_commands_ignores_services_which_are_not_running() {
+        let tmp = TempDir::new("manager").unwrap();
+        let mut manager = manager(&tmp);
+        let sg = ServiceGroup::new("redis", "default", None).unwrap();
+        let (tx, rx) = channel();
+        tx.send(ServiceCommand::Restart(sg.clone())).unwrap();
+        tx.send(ServiceCommand::ApplyConfig(sg.clone(), b"port = 6380".to_vec()))
+            .unwrap();
+
+        manager.check_for_service_commands(&rx);
+
+        assert!(rx.try_recv().is_err());
+        assert_eq!(config_incarnation(&manager, &sg), None);
+    }
 }
@@ -16,7 +16,7 @@
 
 use std;
 use std::ascii::AsciiExt;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::fmt;
 use std::fs::File;
@@ -29,6 +29,7 @@ use butterfly::rumor::service::SysInfo;
 use hcore::crypto;
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
+use serde_json;
 use toml;
 
 use config::GossipListenAddr;
@@ -37,10 +38,11 @@ use error::{Error, Result};
 use fs;
 use http_gateway;
 use supervisor::RuntimeConfig;
-use templating::Template;
+use templating::{helpers, Template};
 use util::{self, convert};
 use VERSION;
 use super::ServiceBind;
+use super::schema::ConfigSchema;
 
 static LOGKEY: &'static str = "SC";
 static ENV_VAR_PREFIX: &'static str = "HAB";
@@ -65,6 +67,12 @@ pub struct ServiceConfig {
     pub config_root: PathBuf,
     #[serde(skip_serializing, skip_deserializing)]
     pub incarnation: u64,
+    // Decrypted secrets are only held in memory and are never serialized with the rest of the
+    // configuration; templates reach them through the `secret` helper.
+    #[serde(skip_serializing, skip_deserializing)]
+    secrets: toml::value::Table,
+    #[serde(skip_serializing, skip_deserializing)]
+    pub secret_incarnation: u64,
     // Set to 'true' if we have data that needs to be sent to a configuration file
     #[serde(skip_serializing, skip_deserializing)]
     needs_write: bool,
@@ -95,6 +103,8 @@ impl ServiceConfig {
                svc: Svc::default(),
                bind: Bind::default(),
                incarnation: 0,
+               secrets: toml::value::Table::new(),
+               secret_incarnation: 0,
                needs_write: true,
                supported_bindings: bindings,
                config_root: config_root,
@@ -162,18 +172,44 @@ impl ServiceConfig {
         self.svc.populate(service_group, census_ring);
     }
 
+    pub fn secrets(&self) -> &toml::value::Table {
+        &self.secrets
+    }
+
+    pub fn set_secrets(&mut self, incarnation: u64, secrets: toml::value::Table) {
+        self.secret_incarnation = incarnation;
+        self.secrets = secrets;
+    }
+
+    /// Check a configuration received through gossip against the package's schema before it
+    /// is accepted.
+    pub fn validate_gossip(&self, gossip: &toml::Value) -> Result<()> {
+        let mut cfg = self.cfg.clone();
+        cfg.gossip = Some(gossip.clone());
+        cfg.validate()
+    }
+
     pub fn reload_gossip(&mut self) -> Result<()> {
         self.cfg.load_gossip(&self.pkg.name)
     }
 
-    /// Write the configuration to `config.toml`, and render the templated configuration files.
-    pub fn write(&mut self) -> Result<bool> {
-        let final_toml = try!(self.to_toml());
-        {
-            let mut last_toml = try!(File::create(fs::svc_config_file(&self.pkg.name)));
-            try!(last_toml.write_all(&try!(toml::to_vec(&final_toml))));
-        }
+    /// Replace the user configuration with the given table and drop any gossiped configuration,
+    /// so that the result no longer depends on the files of a running Supervisor.
+    pub fn set_user_config(&mut self, user: toml::value::Table) {
+        self.cfg.user = Some(toml::Value::Table(user));
+        self.cfg.gossip = None;
+    }
+
+    /// Check the merged configuration against the package's schema, if it has one.
+    pub fn validate(&self) -> Result<()> {
+        self.cfg.validate()
+    }
+
+    /// Render the templated configuration files against the given data, returning the contents
+    /// of each file by name.
+    pub fn render_config_files(&self, data: &serde_json::Value) -> Result<Vec<(String, String)>> {
         let mut template = Template::new();
+        template.register_helper("secret", Box::new(helpers::Secret::new(&self.secrets)));
 
         // Register all the templates; this makes them available as partials!
         // I suspect this will be useful, but I think we'll want to make this
@@ -191,11 +227,32 @@ impl ServiceConfig {
             }
         }
 
-        let final_data = convert::toml_to_json(final_toml);
-        let mut should_restart = false;
+        let mut rendered = Vec::with_capacity(config_files.len());
         for config in config_files {
             debug!("Rendering template {}", &config);
-            let template_data = try!(template.render(&config, &final_data));
+            let template_data = try!(template.render(&config, data));
+            rendered.push((config, template_data));
+        }
+        Ok(rendered)
+    }
+
+    /// Write the configuration to `config.toml`, and render the templated configuration files.
+    /// Nothing is written if the configuration does not satisfy the package's schema.
+    pub fn write(&mut self) -> Result<bool> {
+        try!(self.cfg.validate());
+        let final_toml = try!(self.to_toml());
+        {
+            let mut last_toml = try!(File::create(fs::svc_config_file(&self.pkg.name)));
+            try!(last_toml.write_all(&try!(toml::to_vec(&final_toml))));
+        }
+        {
+            let mut provenance =
+                try!(File::create(fs::svc_config_provenance_file(&self.pkg.name)));
+            try!(provenance.write_all(&try!(toml::to_vec(&self.cfg.provenance()))));
+        }
+        let final_data = convert::toml_to_json(final_toml);
+        let mut should_restart = false;
+        for (config, template_data) in try!(self.render_config_files(&final_data)) {
             let template_hash = try!(crypto::hash::hash_string(&template_data));
             let cfg_dest = self.pkg
                 .svc_config_path
@@ -349,6 +406,12 @@ fn serialize_census_group(census_group: &CensusGroup) -> toml::value::Table {
         result.insert("leader".to_string(), l.clone());
         result.insert("first".to_string(), l);
     }
+    let replicas = census_group
+        .replicas()
+        .into_iter()
+        .map(|cm| toml::Value::try_from(cm).expect("Can't convert into TOML Value"))
+        .collect();
+    result.insert("replicas".to_string(), toml::Value::Array(replicas));
 
     result.insert("members".to_string(), toml::Value::Array(members));
     result.insert("member_id".to_string(), toml::Value::Table(member_id));
@@ -361,6 +424,8 @@ struct Cfg {
     user: Option<toml::Value>,
     gossip: Option<toml::Value>,
     environment: Option<toml::Value>,
+    #[serde(skip_serializing, skip_deserializing)]
+    schema: Option<ConfigSchema>,
 }
 
 impl Cfg {
@@ -370,7 +435,9 @@ impl Cfg {
             user: None,
             gossip: None,
             environment: None,
+            schema: None,
         };
+        cfg.schema = try!(ConfigSchema::load(&config_root));
         try!(cfg.load_default(&config_root));
         try!(cfg.load_user(&package.ident.name));
         try!(cfg.load_gossip(&package.ident.name));
@@ -378,23 +445,53 @@ impl Cfg {
         Ok(cfg)
     }
 
-    pub fn to_toml(&self) -> Result<toml::Value> {
-        let mut output_toml = toml::value::Table::new();
+    /// Returns each layer of configuration which is present, named, in the order they are
+    /// merged; later layers take precedence over earlier ones.
+    fn layers(&self) -> Vec<(&'static str, &toml::value::Table)> {
+        let mut layers = Vec::new();
         if let Some(toml::Value::Table(ref default_cfg)) = self.default {
-            try!(toml_merge(&mut output_toml, default_cfg));
+            layers.push(("default", default_cfg));
         }
         if let Some(toml::Value::Table(ref env_cfg)) = self.environment {
-            try!(toml_merge(&mut output_toml, env_cfg));
+            layers.push(("environment", env_cfg));
         }
         if let Some(toml::Value::Table(ref user_cfg)) = self.user {
-            try!(toml_merge(&mut output_toml, user_cfg));
+            layers.push(("user", user_cfg));
         }
         if let Some(toml::Value::Table(ref gossip_cfg)) = self.gossip {
-            try!(toml_merge(&mut output_toml, gossip_cfg));
+            layers.push(("gossip", gossip_cfg));
+        }
+        layers
+    }
+
+    pub fn to_toml(&self) -> Result<toml::Value> {
+        let mut output_toml = toml::value::Table::new();
+        for (_, layer) in self.layers() {
+            try!(toml_merge(&mut output_toml, layer));
         }
         Ok(toml::Value::Table(output_toml))
     }
 
+    /// Returns the layer which supplied the value of each key in the merged configuration,
+    /// keyed by the key's dotted path.
+    fn provenance(&self) -> BTreeMap<String, &'static str> {
+        let mut provenance = BTreeMap::new();
+        for (name, layer) in self.layers() {
+            record_provenance(&mut provenance, "", layer, name);
+        }
+        provenance
+    }
+
+    /// Check the merged configuration against the package's schema, if it has one.
+    fn validate(&self) -> Result<()> {
+        if let Some(ref schema) = self.schema {
+            if let toml::Value::Table(ref cfg) = try!(self.to_toml()) {
+                try!(schema.validate(cfg));
+            }
+        }
+        Ok(())
+    }
+
     fn to_exported(&self, exports: &HashMap<String, String>) -> Result<toml::value::Table> {
         let mut map = toml::value::Table::default();
         let cfg = try!(self.to_toml());
@@ -636,6 +733,39 @@ impl Hab {
 }
 
 
+// Records `layer` as the source of every value in `table`, mirroring how `toml_merge` replaces
+// or merges the values of earlier layers.
+fn record_provenance(provenance: &mut BTreeMap<String, &'static str>,
+                     prefix: &str,
+                     table: &toml::value::Table,
+                     layer: &'static str) {
+    for (key, value) in table.iter() {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match *value {
+            toml::Value::Table(ref inner) => {
+                provenance.remove(&path);
+                record_provenance(provenance, &path, inner, layer);
+            }
+            _ => {
+                let nested = format!("{}.", path);
+                let replaced: Vec<String> = provenance
+                    .keys()
+                    .filter(|k| k.starts_with(&nested))
+                    .cloned()
+                    .collect();
+                for key in replaced {
+                    provenance.remove(&key);
+                }
+                provenance.insert(path, layer);
+            }
+        }
+    }
+}
+
 // Recursively merges the `other` TOML table into `me`
 fn toml_merge(me: &mut toml::value::Table, other: &toml::value::Table) -> Result<()> {
     toml_merge_recurse(me, other, 0)
@@ -831,6 +961,62 @@ mod test {
         assert_eq!(exported_toml["ip"].as_str(), Some("1.2.3.4"));
     }
 
+    #[test]
+    fn provenance_records_the_winning_layer() {
+        let cfg = Cfg {
+            default: Some(toml::Value::Table(toml_from_str("port = 80\n[db]\nhost = \"a\"\n\
+                                                            user = \"admin\""))),
+            environment: None,
+            user: Some(toml::Value::Table(toml_from_str("[db]\nhost = \"b\""))),
+            gossip: Some(toml::Value::Table(toml_from_str("db = \"postgres://c\""))),
+            schema: None,
+        };
+        let provenance = cfg.provenance();
+        assert_eq!(provenance.len(), 2);
+        assert_eq!(provenance["port"], "default");
+        assert_eq!(provenance["db"], "gossip");
+    }
+
+    #[test]
+    fn provenance_merges_nested_tables() {
+        let cfg = Cfg {
+            default: Some(toml::Value::Table(toml_from_str("[db]\nhost = \"a\"\n\
+                                                            user = \"admin\""))),
+            environment: Some(toml::Value::Table(toml_from_str("[db]\nuser = \"app\""))),
+            user: None,
+            gossip: None,
+            schema: None,
+        };
+        let provenance = cfg.provenance();
+        assert_eq!(provenance["db.host"], "default");
+        assert_eq!(provenance["db.user"], "environment");
+    }
+
+    #[test]
+    fn gossip_is_validated_against_the_schema() {
+        let pkg = gen_pkg();
+        let mut sc = ServiceConfig::new(&pkg,
+                                        &runtime_config(),
+                                        root(),
+                                        Vec::new(),
+                                        &GossipListenAddr::default(),
+                                        &ListenAddr::default())
+                .unwrap();
+        sc.cfg.schema = Some(ConfigSchema::from_str("[\"port\"]\ntype = \"integer\"").unwrap());
+        let good = toml::Value::Table(toml_from_str("port = 80"));
+        let bad = toml::Value::Table(toml_from_str("port = \"eighty\""));
+        assert!(sc.validate_gossip(&good).is_ok());
+        match sc.validate_gossip(&bad) {
+            Err(e) => {
+                match e.err {
+                    Error::ConfigSchemaViolation(_) => assert!(true),
+                    wrong => panic!("Unexpected error returned {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("Schema violation was accepted"),
+        }
+    }
+
     #[test]
     fn to_toml_exported_table_cfg() {
         let pkg = gen_exporting_pkg();
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use std::fmt;
+use std::str::FromStr;
+
+use serde_json;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
 pub enum HealthCheck {
@@ -22,6 +25,17 @@ pub enum HealthCheck {
     Unknown,
 }
 
+impl HealthCheck {
+    /// Returns true if the result counts towards the failure threshold of a service's health
+    /// check.
+    pub fn is_failure(&self) -> bool {
+        match *self {
+            HealthCheck::Ok | HealthCheck::Warning => false,
+            HealthCheck::Critical | HealthCheck::Unknown => true,
+        }
+    }
+}
+
 impl Default for HealthCheck {
     fn default() -> HealthCheck {
         HealthCheck::Unknown
@@ -52,6 +66,34 @@ impl fmt::Display for HealthCheck {
     }
 }
 
+/// The result of a service's most recent health check, cached on disk for the `http_gateway`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CachedHealthCheck {
+    pub status: i8,
+    /// RFC 3339 timestamp of when the check finished.
+    pub checked_at: String,
+    pub duration_ms: u64,
+}
+
+impl FromStr for CachedHealthCheck {
+    type Err = serde_json::Error;
+
+    /// Parses a cached health check, accepting the bare status code written by Supervisors which
+    /// didn't record when the check finished or how long it took.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().parse::<i8>() {
+            Ok(status) => {
+                Ok(CachedHealthCheck {
+                       status: status,
+                       checked_at: String::new(),
+                       duration_ms: 0,
+                   })
+            }
+            Err(_) => serde_json::from_str(value),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum SmokeCheck {
     Ok,
@@ -75,3 +117,35 @@ impl fmt::Display for SmokeCheck {
         write!(f, "{}", msg)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn cached_health_check_from_json() {
+        let cached = CachedHealthCheck::from_str(r#"{"status": 2,
+                                                     "checked_at": "2017-05-01T00:00:00Z",
+                                                     "duration_ms": 12}"#)
+                .unwrap();
+
+        assert_eq!(HealthCheck::from(cached.status), HealthCheck::Critical);
+        assert_eq!(cached.checked_at, "2017-05-01T00:00:00Z");
+        assert_eq!(cached.duration_ms, 12);
+    }
+
+    #[test]
+    fn cached_health_check_from_status_code() {
+        let cached = CachedHealthCheck::from_str("1\n").unwrap();
+
+        assert_eq!(HealthCheck::from(cached.status), HealthCheck::Warning);
+        assert_eq!(cached.checked_at, "");
+    }
+
+    #[test]
+    fn cached_health_check_from_garbage_fails() {
+        assert!(CachedHealthCheck::from_str("healthy").is_err());
+    }
+}
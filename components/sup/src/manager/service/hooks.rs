@@ -14,12 +14,17 @@
 
 use std;
 use std::fmt;
-use std::io::BufReader;
+use std::io::{self, BufReader};
 use std::io::prelude::*;
 use std::fs::File;
 use std::path::{Path, PathBuf};
-use std::process::{Child, ExitStatus};
+use std::process::{Child, ChildStderr, ChildStdout, ExitStatus};
 use std::result;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
 
 use ansi_term::Colour;
 use hcore;
@@ -29,12 +34,15 @@ use serde::{Serialize, Serializer};
 use super::health;
 use error::Result;
 use fs;
+use manager::metrics;
 use manager::service::ServiceConfig;
 use supervisor::RuntimeConfig;
 use templating::Template;
 use util;
 
 pub const HOOK_PERMISSIONS: u32 = 0o755;
+/// Milliseconds between checks for whether a hook's process has exited.
+const HOOK_WAIT_INTERVAL_MS: u64 = 50;
 static LOGKEY: &'static str = "HK";
 
 pub fn stdout_log_path<T>(service_group: &ServiceGroup) -> PathBuf
@@ -108,12 +116,25 @@ pub trait Hook: fmt::Debug + Sized {
 
     /// Run a compiled hook.
     fn run(&self, service_group: &ServiceGroup, cfg: &RuntimeConfig) -> Self::ExitValue {
+        self.run_with_timeout(service_group, cfg, None)
+            .unwrap_or_default()
+    }
+
+    /// Run a compiled hook, killing it if it has not exited within the timeout. Returns `None` if
+    /// the hook was killed.
+    fn run_with_timeout(&self,
+                        service_group: &ServiceGroup,
+                        cfg: &RuntimeConfig,
+                        timeout: Option<Duration>)
+                        -> Option<Self::ExitValue> {
+        metrics::record_hook_run(service_group, Self::file_name());
         let mut cmd = match util::create_command(self.path(), cfg) {
             Ok(c) => c,
             Err(err) => {
                 outputln!(preamble service_group,
                     "Hook command failed to be created, {}, {}", Self::file_name(), err);
-                return Self::ExitValue::default();
+                metrics::record_hook_failure(service_group, Self::file_name());
+                return Some(Self::ExitValue::default());
             }
         };
         let mut child = match cmd.spawn() {
@@ -121,17 +142,39 @@ pub trait Hook: fmt::Debug + Sized {
             Err(err) => {
                 outputln!(preamble service_group,
                     "Hook failed to run, {}, {}", Self::file_name(), err);
-                return Self::ExitValue::default();
+                metrics::record_hook_failure(service_group, Self::file_name());
+                return Some(Self::ExitValue::default());
             }
         };
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
+        let child = Arc::new(Mutex::new(child));
+        let watchdog = timeout.map(|timeout| Watchdog::start(child.clone(), timeout));
         let mut hook_output = HookOutput::new(self.stdout_log_path(), self.stderr_log_path());
-        hook_output.stream_output::<Self>(service_group, &mut child);
-        match child.wait() {
-            Ok(status) => self.handle_exit(service_group, &hook_output, &status),
+        hook_output.stream_output::<Self>(service_group, stdout, stderr);
+        let exit = wait_for(&child);
+        if let Some(watchdog) = watchdog {
+            if watchdog.stop() {
+                outputln!(preamble service_group,
+                    "{} timed out after {} seconds and was killed",
+                    Self::file_name(),
+                    timeout.unwrap().as_secs());
+                metrics::record_hook_failure(service_group, Self::file_name());
+                return None;
+            }
+        }
+        match exit {
+            Ok(status) => {
+                if Self::is_failure(&status) {
+                    metrics::record_hook_failure(service_group, Self::file_name());
+                }
+                Some(self.handle_exit(service_group, &hook_output, &status))
+            }
             Err(err) => {
                 outputln!(preamble service_group,
                     "Hook failed to run, {}, {}", Self::file_name(), err);
-                Self::ExitValue::default()
+                metrics::record_hook_failure(service_group, Self::file_name());
+                Some(Self::ExitValue::default())
             }
         }
     }
@@ -142,6 +185,11 @@ pub trait Hook: fmt::Debug + Sized {
                        status: &ExitStatus)
                        -> Self::ExitValue;
 
+    /// Returns true if the hook's exit status means it failed.
+    fn is_failure(status: &ExitStatus) -> bool {
+        !status.success()
+    }
+
     fn path(&self) -> &Path;
 
     fn template(&self) -> &Template;
@@ -243,6 +291,15 @@ impl Hook for HealthCheckHook {
         }
     }
 
+    /// A health check reports its result through its exit code, so only exiting with a code it
+    /// can't report or without one at all is a failure.
+    fn is_failure(status: &ExitStatus) -> bool {
+        match status.code() {
+            Some(code) => code < 0 || code > 3,
+            None => true,
+        }
+    }
+
     fn path(&self) -> &Path {
         &self.render_pair.path
     }
@@ -432,6 +489,124 @@ impl Hook for PostRunHook {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct PostStopHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for PostStopHook {
+    type ExitValue = bool;
+
+    fn file_name() -> &'static str {
+        "post-stop"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        PostStopHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(&self,
+                       service_group: &ServiceGroup,
+                       _: &'a HookOutput,
+                       status: &ExitStatus)
+                       -> Self::ExitValue {
+        match status.code() {
+            Some(0) => true,
+            Some(code) => {
+                outputln!(preamble service_group, "Post-stop failed! '{}' exited with \
+                    status code {}", Self::file_name(), code);
+                false
+            }
+            None => {
+                outputln!(preamble service_group, "Post-stop failed! '{}' exited without a \
+                    status code", Self::file_name());
+                false
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn template(&self) -> &Template {
+        &self.render_pair.template
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PreStopHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for PreStopHook {
+    type ExitValue = bool;
+
+    fn file_name() -> &'static str {
+        "pre-stop"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        PreStopHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(&self,
+                       service_group: &ServiceGroup,
+                       _: &'a HookOutput,
+                       status: &ExitStatus)
+                       -> Self::ExitValue {
+        match status.code() {
+            Some(0) => true,
+            Some(code) => {
+                outputln!(preamble service_group, "Pre-stop failed! '{}' exited with \
+                    status code {}", Self::file_name(), code);
+                false
+            }
+            None => {
+                outputln!(preamble service_group, "Pre-stop failed! '{}' exited without a \
+                    status code", Self::file_name());
+                false
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn template(&self) -> &Template {
+        &self.render_pair.template
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct ReloadHook {
     render_pair: RenderPair,
@@ -695,6 +870,8 @@ pub struct HookTable {
     pub suitability: Option<SuitabilityHook>,
     pub run: Option<RunHook>,
     pub post_run: Option<PostRunHook>,
+    pub pre_stop: Option<PreStopHook>,
+    pub post_stop: Option<PostStopHook>,
     pub smoke_test: Option<SmokeTestHook>,
     cfg_incarnation: u64,
 }
@@ -733,6 +910,12 @@ impl HookTable {
         if let Some(ref hook) = self.post_run {
             self.compile_one(hook, service_group, config);
         }
+        if let Some(ref hook) = self.pre_stop {
+            self.compile_one(hook, service_group, config);
+        }
+        if let Some(ref hook) = self.post_stop {
+            self.compile_one(hook, service_group, config);
+        }
         if let Some(ref hook) = self.smoke_test {
             self.compile_one(hook, service_group, config);
         }
@@ -754,6 +937,8 @@ impl HookTable {
                 self.reconfigure = ReconfigureHook::load(service_group, &hooks, &templates);
                 self.run = RunHook::load(service_group, &hooks, &templates);
                 self.post_run = PostRunHook::load(service_group, &hooks, &templates);
+                self.pre_stop = PreStopHook::load(service_group, &hooks, &templates);
+                self.post_stop = PostStopHook::load(service_group, &hooks, &templates);
                 self.smoke_test = SmokeTestHook::load(service_group, &hooks, &templates);
             }
         }
@@ -775,6 +960,57 @@ impl HookTable {
     }
 }
 
+/// Waits for a hook's process to exit without holding on to it, so that its `Watchdog` can still
+/// kill it in the meantime.
+fn wait_for(child: &Mutex<Child>) -> io::Result<ExitStatus> {
+    loop {
+        if let Some(status) = child.lock().expect("Hook process lock poisoned").try_wait()? {
+            return Ok(status);
+        }
+        thread::sleep(Duration::from_millis(HOOK_WAIT_INTERVAL_MS));
+    }
+}
+
+/// Kills a hook's process if it runs for longer than its timeout.
+///
+/// The process is killed through its `Child`, which refuses to signal a process once it has been
+/// reaped, so a pid which has since been reused by another process is never killed.
+struct Watchdog {
+    cancel: Sender<()>,
+    fired: Arc<AtomicBool>,
+}
+
+impl Watchdog {
+    fn start(child: Arc<Mutex<Child>>, timeout: Duration) -> Self {
+        let (tx, rx) = channel();
+        let fired = Arc::new(AtomicBool::new(false));
+        let watchdog_fired = fired.clone();
+        thread::Builder::new()
+            .name(String::from("hook-watchdog"))
+            .spawn(move || if let Err(RecvTimeoutError::Timeout) = rx.recv_timeout(timeout) {
+                       let mut child = child.lock().expect("Hook process lock poisoned");
+                       // The hook may have exited just as it ran out of time.
+                       if let Ok(None) = child.try_wait() {
+                           watchdog_fired.store(true, Ordering::SeqCst);
+                           if let Err(err) = child.kill() {
+                               debug!("Failed to kill hook process {}, {}", child.id(), err);
+                           }
+                       }
+                   })
+            .expect("unable to start hook-watchdog thread");
+        Watchdog {
+            cancel: tx,
+            fired: fired,
+        }
+    }
+
+    /// Stop watching the process. Returns true if it was killed.
+    fn stop(self) -> bool {
+        let _ = self.cancel.send(());
+        self.fired.load(Ordering::SeqCst)
+    }
+}
+
 pub struct RenderPair {
     pub path: PathBuf,
     pub template: Template,
@@ -837,14 +1073,17 @@ impl<'a> HookOutput<'a> {
         }
     }
 
-    fn stream_output<H: Hook>(&mut self, service_group: &ServiceGroup, process: &mut Child) {
+    fn stream_output<H: Hook>(&mut self,
+                              service_group: &ServiceGroup,
+                              stdout: Option<ChildStdout>,
+                              stderr: Option<ChildStderr>) {
         let mut stdout_log =
             File::create(&self.stdout_log_file).expect("couldn't create log output file");
         let mut stderr_log =
             File::create(&self.stderr_log_file).expect("couldn't create log output file");
 
         let preamble_str = self.stream_preamble::<H>(service_group);
-        if let Some(ref mut stdout) = process.stdout {
+        if let Some(stdout) = stdout {
             for line in BufReader::new(stdout).lines() {
                 if let Some(ref l) = line.ok() {
                     outputln!(preamble preamble_str, l);
@@ -854,7 +1093,7 @@ impl<'a> HookOutput<'a> {
                 }
             }
         }
-        if let Some(ref mut stderr) = process.stderr {
+        if let Some(stderr) = stderr {
             for line in BufReader::new(stderr).lines() {
                 if let Some(ref l) = line.ok() {
                     outputln!(preamble preamble_str, l);
@@ -875,7 +1114,9 @@ impl<'a> HookOutput<'a> {
 #[cfg(not(windows))]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
     use std::fs::{self, DirBuilder};
+    use std::time::Instant;
     use tempdir::TempDir;
     use std::process::{Command, Stdio};
 
@@ -912,7 +1153,9 @@ mod tests {
         let service_group =
             ServiceGroup::new("dummy", "service", None).expect("couldn't create ServiceGroup");
 
-        hook_output.stream_output::<InitHook>(&service_group, &mut child);
+        hook_output.stream_output::<InitHook>(&service_group,
+                                              child.stdout.take(),
+                                              child.stderr.take());
 
         let mut stdout = String::new();
         hook_output
@@ -932,4 +1175,86 @@ mod tests {
 
         fs::remove_dir_all(tmp_dir).expect("remove temp dir");
     }
+
+    #[test]
+    fn watchdog_kills_hook_which_runs_too_long() {
+        let child = Command::new("sleep")
+            .arg("5")
+            .spawn()
+            .expect("couldn't run sleep");
+        let child = Arc::new(Mutex::new(child));
+        let watchdog = Watchdog::start(child.clone(), Duration::from_millis(100));
+
+        let status = wait_for(&child).expect("couldn't wait for sleep");
+
+        assert!(watchdog.stop());
+        assert!(!status.success());
+    }
+
+    #[test]
+    fn watchdog_leaves_exited_hook_alone() {
+        let child = Command::new("true").spawn().expect("couldn't run true");
+        let child = Arc::new(Mutex::new(child));
+        let watchdog = Watchdog::start(child.clone(), Duration::from_millis(100));
+
+        let status = wait_for(&child).expect("couldn't wait for true");
+        thread::sleep(Duration::from_millis(300));
+
+        assert!(!watchdog.stop());
+        assert!(status.success());
+    }
+
+    fn pre_stop_hook(tmp_dir: &TempDir, script: &str) -> PreStopHook {
+        let template_path = tmp_dir.path().join("pre-stop.hbs");
+        let path = tmp_dir.path().join(PreStopHook::file_name());
+        for p in &[&template_path, &path] {
+            File::create(p)
+                .and_then(|mut f| f.write_all(script.as_bytes()))
+                .expect("couldn't write hook");
+        }
+        hcore::util::perm::set_permissions(&path, HOOK_PERMISSIONS)
+            .expect("couldn't make hook executable");
+        PreStopHook {
+            render_pair: RenderPair::new(&path, &template_path).expect("couldn't load template"),
+            stdout_log_path: tmp_dir.path().join("pre-stop.stdout.log"),
+            stderr_log_path: tmp_dir.path().join("pre-stop.stderr.log"),
+        }
+    }
+
+    fn current_user_runtime_cfg() -> RuntimeConfig {
+        RuntimeConfig::new(hcore::os::users::get_current_username().expect("no current user"),
+                           hcore::os::users::get_current_groupname().expect("no current group"),
+                           HashMap::new())
+    }
+
+    #[test]
+    fn stop_hook_reports_exit_status() {
+        let tmp_dir = TempDir::new("habitat_hooks_test").expect("create temp dir");
+        let service_group =
+            ServiceGroup::new("dummy", "service", None).expect("couldn't create ServiceGroup");
+        let cfg = current_user_runtime_cfg();
+
+        let hook = pre_stop_hook(&tmp_dir, "#!/bin/sh\nexit 0\n");
+        assert_eq!(hook.run_with_timeout(&service_group, &cfg, Some(Duration::from_secs(5))),
+                   Some(true));
+        let hook = pre_stop_hook(&tmp_dir, "#!/bin/sh\nexit 1\n");
+        assert_eq!(hook.run_with_timeout(&service_group, &cfg, Some(Duration::from_secs(5))),
+                   Some(false));
+    }
+
+    #[test]
+    fn stop_hook_is_killed_after_timeout() {
+        let tmp_dir = TempDir::new("habitat_hooks_test").expect("create temp dir");
+        let service_group =
+            ServiceGroup::new("dummy", "service", None).expect("couldn't create ServiceGroup");
+        let hook = pre_stop_hook(&tmp_dir, "#!/bin/sh\nexec sleep 10\n");
+        let started_at = Instant::now();
+
+        let exit = hook.run_with_timeout(&service_group,
+                                         &current_user_runtime_cfg(),
+                                         Some(Duration::from_millis(200)));
+
+        assert_eq!(exit, None);
+        assert!(started_at.elapsed() < Duration::from_secs(10));
+    }
 }
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 mod health;
+mod schema;
 mod spec;
 mod config;
 pub mod hooks;
 
 use std;
-use std::collections::HashMap;
+use std::cmp;
+use std::collections::{HashMap, VecDeque};
 use std::env;
 use std::fmt;
 use std::fs::File;
@@ -32,15 +34,18 @@ use std::time::{Duration, Instant};
 
 use ansi_term::Colour::{Yellow, Red, Green};
 use butterfly;
+use butterfly::rumor::election::ElectionConfig;
 use butterfly::rumor::service::Service as ServiceRumor;
 use common::ui::UI;
 use hcore::fs::FS_ROOT_PATH;
 use hcore::service::ServiceGroup;
-use hcore::crypto::hash;
+use hcore::crypto::{default_cache_key_path, hash};
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::util::deserialize_using_from_str;
 use hcore::util::perm::{set_owner, set_permissions};
 use serde;
+use serde_json;
+use time;
 use toml;
 
 use self::hooks::{HOOK_PERMISSIONS, Hook, HookTable};
@@ -48,20 +53,26 @@ use config::GossipListenAddr;
 use error::{Error, Result, SupError};
 use http_gateway;
 use fs;
-use manager::{self, signals};
+use manager::{self, metrics, signals};
+use manager::events::ServiceSnapshot;
 use census::{CensusRing, ElectionStatus};
 use supervisor::{Supervisor, RuntimeConfig};
 use util;
 
 pub use self::config::{ServiceConfig, Pkg};
-pub use self::health::{HealthCheck, SmokeCheck};
-pub use self::spec::{DesiredState, ServiceBind, ServiceSpec, StartStyle};
+pub use self::health::{CachedHealthCheck, HealthCheck, SmokeCheck};
+pub use self::spec::{start_order, DesiredState, MaintenanceWindow, ServiceBind, ServiceSpec,
+                     StartStyle};
 
 static LOGKEY: &'static str = "SR";
 
+/// Number of times a freshly updated service may exit before it is rolled back.
+const UPDATE_PROBATION_MAX_RESTARTS: u32 = 3;
+
 lazy_static! {
-    static ref HEALTH_CHECK_INTERVAL: Duration = {
-        Duration::from_millis(30_000)
+    /// Length of time after an update during which a failing release is rolled back.
+    static ref UPDATE_PROBATION_PERIOD: Duration = {
+        Duration::from_secs(300)
     };
 }
 
@@ -83,16 +94,44 @@ pub struct Service {
     pub spec_ident: PackageIdent,
     pub start_style: StartStyle,
     pub topology: Topology,
+    /// The service's role in its service group's election: leader, follower or replica.
+    pub role: Option<String>,
+    pub replicas: u64,
+    pub quorum_size: u64,
+    pub leader_lease_secs: u64,
     pub update_strategy: UpdateStrategy,
+    pub canary_soak_secs: u64,
+    pub canary_batch_percent: u8,
+    pub channel: Option<String>,
+    pub update_frequency_secs: u64,
+    pub update_jitter_secs: u64,
+    pub maintenance_window: Option<MaintenanceWindow>,
+    pub restart_policy: RestartPolicy,
+    pub restart_max: u32,
+    pub restart_window_secs: u64,
+    pub restart_backoff_secs: u64,
+    pub restart_backoff_max_secs: u64,
+    #[serde(skip_serializing)]
+    restart_history: VecDeque<Instant>,
+    #[serde(skip_serializing)]
+    next_restart: Option<Instant>,
     #[serde(skip_serializing)]
     spec_binds: Vec<ServiceBind>,
     hooks: HookTable,
     config_from: Option<PathBuf>,
+    pub health_check_interval_secs: u64,
+    pub health_check_timeout_secs: u64,
+    pub health_check_failure_threshold: u32,
+    #[serde(skip_serializing)]
+    health_check_failures: u32,
     #[serde(skip_serializing)]
-    last_health_check: Instant,
+    last_health_check: Option<Instant>,
     #[serde(skip_serializing)]
     manager_fs_cfg: Arc<manager::FsCfg>,
     supervisor: Supervisor,
+    #[serde(skip_serializing)]
+    probation: Option<UpdateProbation>,
+    pub rollback: Option<Rollback>,
 }
 
 impl Service {
@@ -104,6 +143,9 @@ impl Service {
            organization: Option<&str>)
            -> Result<Service> {
         spec.validate(&package)?;
+        let resource_limits = spec.resource_limits();
+        let isolation = spec.isolation();
+        let log_config = spec.log_config();
         let spec_file = manager_fs_cfg.specs_path.join(spec.file_name());
         let service_group = ServiceGroup::new(&package.ident().name, spec.group, organization)?;
         let runtime_cfg = Self::runtime_config_from(&package)?;
@@ -118,7 +160,16 @@ impl Service {
                                          &http_listen)?;
         let hook_template_path = svc_cfg.config_root.join("hooks");
         let hooks_path = fs::svc_hooks_path(service_group.service());
+        metrics::set_service_package(&service_group, None, package.ident());
         let locked_package = Arc::new(RwLock::new(package));
+        let supervisor = Supervisor::new(locked_package.clone(),
+                                         &manager_fs_cfg.sup_name,
+                                         &service_group,
+                                         runtime_cfg,
+                                         resource_limits,
+                                         isolation,
+                                         log_config,
+                                         spec.shutdown_timeout_secs);
         Ok(Service {
                config: svc_cfg,
                current_service_files: HashMap::new(),
@@ -132,7 +183,7 @@ impl Service {
                needs_reload: false,
                needs_reconfiguration: false,
                manager_fs_cfg: manager_fs_cfg,
-               supervisor: Supervisor::new(locked_package.clone(), &service_group, runtime_cfg),
+               supervisor: supervisor,
                package: locked_package,
                service_group: service_group,
                smoke_check: SmokeCheck::default(),
@@ -141,9 +192,32 @@ impl Service {
                spec_file: spec_file,
                start_style: spec.start_style,
                topology: spec.topology,
+               role: None,
+               replicas: spec.replicas,
+               quorum_size: spec.quorum_size,
+               leader_lease_secs: spec.leader_lease_secs,
                update_strategy: spec.update_strategy,
+               canary_soak_secs: spec.canary_soak_secs,
+               canary_batch_percent: spec.canary_batch_percent,
+               channel: spec.channel,
+               update_frequency_secs: spec.update_frequency_secs,
+               update_jitter_secs: spec.update_jitter_secs,
+               maintenance_window: spec.maintenance_window,
+               restart_policy: spec.restart_policy,
+               restart_max: spec.restart_max,
+               restart_window_secs: spec.restart_window_secs,
+               restart_backoff_secs: spec.restart_backoff_secs,
+               restart_backoff_max_secs: spec.restart_backoff_max_secs,
+               restart_history: VecDeque::new(),
+               next_restart: None,
                config_from: spec.config_from,
-               last_health_check: Instant::now() - *HEALTH_CHECK_INTERVAL,
+               health_check_interval_secs: spec.health_check_interval_secs,
+               health_check_timeout_secs: spec.health_check_timeout_secs,
+               health_check_failure_threshold: spec.health_check_failure_threshold,
+               health_check_failures: 0,
+               last_health_check: None,
+               probation: None,
+               rollback: None,
            })
     }
 
@@ -173,7 +247,9 @@ impl Service {
                     UpdateStrategy::AtOnce => {
                         util::pkg::maybe_install_newer(&mut ui, &spec, package)?
                     }
-                    UpdateStrategy::None | UpdateStrategy::Rolling => package,
+                    UpdateStrategy::None |
+                    UpdateStrategy::Rolling |
+                    UpdateStrategy::Canary => package,
                 }
             }
             Err(_) => {
@@ -243,15 +319,20 @@ impl Service {
     }
 
     pub fn stop(&mut self) {
+        let running = self.pre_stop();
         if let Err(err) = self.supervisor.stop() {
             outputln!(preamble self.service_group, "Service stop failed: {}", err);
         }
+        if running {
+            self.post_stop();
+        }
     }
 
     fn reload(&mut self) {
         self.needs_reload = false;
         if self.is_down() || self.hooks.reload.is_none() {
-            if let Some(err) = self.supervisor.restart().err() {
+            self.next_restart = None;
+            if let Some(err) = self.restart_process().err() {
                 outputln!(preamble self.service_group, "Service restart failed: {}", err);
             }
         } else {
@@ -261,7 +342,77 @@ impl Service {
     }
 
     pub fn down(&mut self) -> Result<()> {
-        self.supervisor.down()
+        let running = self.pre_stop();
+        self.supervisor.down()?;
+        if running {
+            self.post_stop();
+        }
+        Ok(())
+    }
+
+    /// Restart the service's process, running the stop hooks if it is running.
+    fn restart_process(&mut self) -> Result<()> {
+        if self.pre_stop() {
+            self.supervisor.stop()?;
+            self.post_stop();
+        }
+        self.supervisor.restart()
+    }
+
+    /// Run the pre-stop hook, if the service's process is running, so that it can drain
+    /// connections before it is sent a SIGTERM. Returns true if the process is running, in which
+    /// case the post-stop hook should be run once it has been stopped.
+    fn pre_stop(&self) -> bool {
+        if self.is_down() {
+            return false;
+        }
+        if let Some(ref hook) = self.hooks.pre_stop {
+            hook.run_with_timeout(&self.service_group,
+                                  self.runtime_cfg(),
+                                  Some(self.stop_hook_timeout()));
+        }
+        true
+    }
+
+    /// Run the post-stop hook to clean up after the service's process has stopped.
+    fn post_stop(&self) {
+        if let Some(ref hook) = self.hooks.post_stop {
+            hook.run_with_timeout(&self.service_group,
+                                  self.runtime_cfg(),
+                                  Some(self.stop_hook_timeout()));
+        }
+    }
+
+    /// Returns how long each stop hook may run before it is killed. The hooks run on the
+    /// Manager's main loop, so they are given no longer than the service's process is given to
+    /// shut down.
+    fn stop_hook_timeout(&self) -> Duration {
+        Duration::from_secs(self.supervisor.shutdown_timeout_secs)
+    }
+
+    /// Returns the parts of the service's state whose changes are published as events.
+    pub fn snapshot(&self) -> ServiceSnapshot {
+        ServiceSnapshot {
+            up: !self.is_down(),
+            health: self.health_check,
+            package: self.package().to_string(),
+            config_incarnation: self.config.incarnation,
+            files_incarnation: self.current_service_files.values().sum(),
+        }
+    }
+
+    /// Restart the service's process on request, regardless of its restart policy. A service
+    /// which has failed is started again.
+    pub fn restart(&mut self) {
+        if !self.initialized {
+            return;
+        }
+        outputln!(preamble self.service_group, "Restarting service on request");
+        self.restart_history.clear();
+        self.next_restart = None;
+        if let Some(err) = self.restart_process().err() {
+            outputln!(preamble self.service_group, "Service restart failed: {}", err);
+        }
     }
 
     pub fn send_signal(&self, signal: u32) -> Result<()> {
@@ -278,9 +429,72 @@ impl Service {
         self.supervisor.child.is_none()
     }
 
+    /// Returns true if the process has exited and the restart policy will not start it again.
+    fn is_halted(&self) -> bool {
+        self.is_down() && (self.supervisor.is_down() || self.supervisor.is_failed())
+    }
+
+    /// Returns true if the process has exited and is waiting out its restart backoff.
+    fn is_backing_off(&self) -> bool {
+        self.is_down() && self.next_restart.map_or(false, |at| Instant::now() < at)
+    }
+
     /// Instructs the service's process supervisor to reap dead children.
     fn check_process(&mut self) {
-        self.supervisor.check_process()
+        let was_up = !self.is_down();
+        self.supervisor.check_process();
+        if was_up && self.is_down() {
+            if let Some(ref mut probation) = self.probation {
+                probation.restarts += 1;
+            }
+            self.post_stop();
+            self.schedule_restart();
+        }
+    }
+
+    /// Apply the restart policy to a process which has exited, either scheduling a restart after
+    /// a backoff or leaving the service down.
+    fn schedule_restart(&mut self) {
+        let succeeded = self.supervisor.exit_code == Some(0);
+        if !self.restart_policy.restarts_after(succeeded) {
+            outputln!(preamble self.service_group,
+                      "Not restarting service; restart policy is {}",
+                      self.restart_policy);
+            if succeeded {
+                if let Err(err) = self.supervisor.down() {
+                    outputln!(preamble self.service_group,
+                              "Error marking exited service as down: {}", err);
+                }
+            } else {
+                self.supervisor.fail();
+            }
+            return;
+        }
+        let now = Instant::now();
+        let window = Duration::from_secs(self.restart_window_secs);
+        while self.restart_history
+                  .front()
+                  .map_or(false, |at| now.duration_since(*at) >= window) {
+            self.restart_history.pop_front();
+        }
+        let restarts = self.restart_history.len() as u32;
+        if restarts >= self.restart_max {
+            outputln!(preamble self.service_group,
+                      "{}; restarted {} times in {} seconds",
+                      Red.bold().paint("Service failed"),
+                      restarts,
+                      self.restart_window_secs);
+            self.supervisor.fail();
+            return;
+        }
+        let backoff = restart_backoff_secs(self.restart_backoff_secs,
+                                           self.restart_backoff_max_secs,
+                                           restarts);
+        outputln!(preamble self.service_group,
+                  "Restarting service in {} seconds", backoff);
+        self.restart_history.push_back(now);
+        metrics::record_restart(&self.service_group);
+        self.next_restart = Some(now + Duration::from_secs(backoff));
     }
 
     pub fn tick(&mut self, butterfly: &butterfly::Server, census_ring: &CensusRing) -> bool {
@@ -292,12 +506,17 @@ impl Service {
             }
         }
         service_rumor_written = self.update_configuration(butterfly, census_ring);
+        self.role = census_ring
+            .census_group_for(&self.service_group)
+            .and_then(|census_group| census_group.me())
+            .and_then(|me| me.role())
+            .map(|role| role.to_string());
 
         match self.topology {
             Topology::Standalone => {
                 self.execute_hooks();
             }
-            Topology::Leader => {
+            Topology::Leader | Topology::Replicated => {
                 let census_group = census_ring
                     .census_group_for(&self.service_group)
                     .expect("Service Group's census entry missing from list!");
@@ -334,9 +553,16 @@ impl Service {
                             .as_ref()
                             .expect("No leader with finished election");
                         if self.last_election_status != *current_election_status {
-                            outputln!(preamble self.service_group,
-                                      "Executing hooks; {} is the leader",
-                                      Green.bold().paint(leader_id.to_string()));
+                            if self.topology == Topology::Replicated {
+                                outputln!(preamble self.service_group,
+                                          "Executing hooks; {} is the leader, replicated to {}",
+                                          Green.bold().paint(leader_id.to_string()),
+                                          census_group.replica_ids().join(", "));
+                            } else {
+                                outputln!(preamble self.service_group,
+                                          "Executing hooks; {} is the leader",
+                                          Green.bold().paint(leader_id.to_string()));
+                            }
                             self.last_election_status = *current_election_status;
                         }
                         self.execute_hooks()
@@ -347,12 +573,59 @@ impl Service {
         service_rumor_written
     }
 
+    /// Returns the settings the service group's leader elections are run with.
+    pub fn election_config(&self) -> ElectionConfig {
+        let replicas = match self.topology {
+            Topology::Replicated => self.replicas,
+            _ => 0,
+        };
+        ElectionConfig {
+            quorum_size: self.quorum_size,
+            replicas: replicas,
+            lease_secs: self.leader_lease_secs,
+        }
+    }
+
     pub fn to_spec(&self) -> ServiceSpec {
         let mut spec = ServiceSpec::default_for(self.spec_ident.clone());
         spec.group = self.service_group.group().to_string();
         spec.depot_url = self.depot_url.clone();
         spec.topology = self.topology;
+        spec.replicas = self.replicas;
+        spec.quorum_size = self.quorum_size;
+        spec.leader_lease_secs = self.leader_lease_secs;
         spec.update_strategy = self.update_strategy;
+        spec.canary_soak_secs = self.canary_soak_secs;
+        spec.canary_batch_percent = self.canary_batch_percent;
+        spec.channel = self.channel.clone();
+        spec.update_frequency_secs = self.update_frequency_secs;
+        spec.update_jitter_secs = self.update_jitter_secs;
+        spec.maintenance_window = self.maintenance_window;
+        spec.restart_policy = self.restart_policy;
+        spec.restart_max = self.restart_max;
+        spec.restart_window_secs = self.restart_window_secs;
+        spec.restart_backoff_secs = self.restart_backoff_secs;
+        spec.restart_backoff_max_secs = self.restart_backoff_max_secs;
+        spec.health_check_interval_secs = self.health_check_interval_secs;
+        spec.health_check_timeout_secs = self.health_check_timeout_secs;
+        spec.health_check_failure_threshold = self.health_check_failure_threshold;
+        spec.shutdown_timeout_secs = self.supervisor.shutdown_timeout_secs;
+        let limits = &self.supervisor.resource_limits;
+        spec.cpu_shares = limits.cpu_shares;
+        spec.memory_limit_mb = limits.memory_bytes.map(|bytes| bytes / (1024 * 1024));
+        spec.pids_max = limits.pids_max;
+        spec.open_files_max = limits.open_files_max;
+        if let Some(ref isolation) = self.supervisor.isolation {
+            spec.isolate = true;
+            spec.isolate_network = isolation.network;
+        }
+        if let Some(ref log) = self.supervisor.log_config {
+            spec.log_to_file = true;
+            spec.log_format = log.format;
+            spec.log_max_mb = log.max_bytes / (1024 * 1024);
+            spec.log_rotate_secs = log.rotate_secs;
+            spec.log_keep = log.keep;
+        }
         spec.binds = self.spec_binds.clone();
         spec.start_style = self.start_style;
         spec.config_from = self.config_from.clone();
@@ -386,7 +659,11 @@ impl Service {
         self.persist_service_files(butterfly);
 
         let svc_cfg_updated = self.persist_service_config(butterfly);
-        if svc_cfg_updated || census_ring.changed {
+        let secrets_updated = self.persist_service_secrets(butterfly);
+        if secrets_updated {
+            self.needs_reconfiguration = true;
+        }
+        if svc_cfg_updated || secrets_updated || census_ring.changed {
             if svc_cfg_updated {
                 service_rumor_written = self.update_service_rumor_cfg(butterfly);
                 if let Some(err) = self.config.reload_gossip().err() {
@@ -412,20 +689,130 @@ impl Service {
         service_rumor_written
     }
 
+    /// Returns the result of the most recent health check.
+    pub fn health_check(&self) -> HealthCheck {
+        self.health_check
+    }
+
     pub fn package(&self) -> RwLockReadGuard<PackageInstall> {
         self.package.read().expect("Package lock poisoned")
     }
 
+    /// Replace the running package with an updated release.
+    ///
+    /// The previously running release is kept for a probation period, during which the service is
+    /// rolled back to it if the new release fails its health check or keeps crashing.
     pub fn update_package(&mut self, package: PackageInstall) {
+        let previous = self.package().clone();
+        if self.swap_package(package) {
+            self.probation = Some(UpdateProbation::new(previous));
+        }
+    }
+
+    /// Revert to the previously running release if the release installed by the last update has
+    /// failed during its probation period. The service is pinned to the previous release so that
+    /// it will not be updated again.
+    ///
+    /// Returns true if the service was rolled back.
+    pub fn rollback(&mut self) -> bool {
+        match self.probation_failure() {
+            Some(reason) => self.roll_back(reason),
+            None => false,
+        }
+    }
+
+    /// Revert to the previously running release right away, as though the release installed by
+    /// the last update had failed its probation for the given reason. The service is pinned to
+    /// the previous release like it is by `rollback`.
+    ///
+    /// Returns true if the service was rolled back, which it can only be while on probation.
+    pub fn fail_update(&mut self, reason: RollbackReason) -> bool {
+        if self.probation.is_none() {
+            return false;
+        }
+        self.roll_back(reason)
+    }
+
+    /// Keep the release installed by the last update on probation for at least the given length
+    /// of time after the update, such as for as long as a canary may take to soak.
+    pub fn extend_probation(&mut self, period: Duration) {
+        if let Some(ref mut probation) = self.probation {
+            probation.period = cmp::max(probation.period, period);
+        }
+    }
+
+    fn roll_back(&mut self, reason: RollbackReason) -> bool {
+        let probation = self.probation.take().expect("Probation missing for failed update");
+        let from = self.package().ident().clone();
+        let to = probation.previous.ident().clone();
+        outputln!(preamble self.service_group,
+                  "Rolling back from {} to {}; {}",
+                  Red.bold().paint(from.to_string()),
+                  Green.bold().paint(to.to_string()),
+                  reason);
+        if !self.swap_package(probation.previous) {
+            return false;
+        }
+        self.pin_release();
+        self.rollback = Some(Rollback {
+                                 from: from,
+                                 to: to,
+                                 reason: reason,
+                                 rolled_back_at: time::now_utc().rfc3339().to_string(),
+                             });
+        true
+    }
+
+    /// Pin the service to the release it is running, so that it will not be updated again. The
+    /// pin is saved to the service's spec so that it outlasts a restart of the Supervisor.
+    pub fn pin_release(&mut self) {
+        let ident = self.package().ident().clone();
+        self.spec_ident = ident.clone();
+        self.update_strategy = UpdateStrategy::None;
+        if let Err(err) = self.to_spec().to_file(&self.spec_file) {
+            outputln!(preamble self.service_group,
+                      "Failed to pin service spec to {}: {}", ident, err);
+        }
+    }
+
+    /// Returns the reason the release installed by the last update has failed, if it has failed
+    /// within its probation period.
+    fn probation_failure(&mut self) -> Option<RollbackReason> {
+        let (expired, failure) = match self.probation {
+            Some(ref probation) => {
+                (probation.is_expired(),
+                 probation.failure(self.health_check, self.supervisor.is_failed()))
+            }
+            None => return None,
+        };
+        if expired {
+            debug!("{}, Updated release has passed its probation period",
+                   self.service_group);
+            self.probation = None;
+            None
+        } else {
+            failure
+        }
+    }
+
+    /// Load a package into the service and stop the running process so that it is restarted with
+    /// the new package. Returns false if the package could not be loaded.
+    fn swap_package(&mut self, package: PackageInstall) -> bool {
         let runtime_cfg = match Self::runtime_config_from(&package) {
             Ok(c) => c,
             Err(err) => {
                 outputln!(preamble self.service_group,
                           "Unable to extract svc_user, svc_group, and env_vars \
                           from updated package, {}", err);
-                return;
+                return false;
             }
         };
+        // Stop the process before loading the new package's hooks, so that it is stopped with
+        // the stop hooks of the package it was started from.
+        if let Err(err) = self.down() {
+            outputln!(preamble self.service_group,
+                      "Error stopping process while updating package: {}", err);
+        }
         let config_root = self.config_from
             .clone()
             .unwrap_or(package.installed_path.clone());
@@ -440,13 +827,16 @@ impl Service {
             outputln!(preamble self.service_group,
                 "Failed to reload service config with updated package: {}", err);
         }
+        metrics::set_service_package(&self.service_group,
+                                     Some(self.package().ident()),
+                                     package.ident());
         *self.package.write().expect("Package lock poisoned") = package;
-
-        if let Err(err) = self.supervisor.down() {
-            outputln!(preamble self.service_group,
-                      "Error stopping process while updating package: {}", err);
-        }
+        self.health_check = HealthCheck::default();
+        self.health_check_failures = 0;
+        self.restart_history.clear();
+        self.next_restart = None;
         self.initialized = false;
+        true
     }
 
     pub fn to_rumor<T: ToString>(&self, member_id: T) -> ServiceRumor {
@@ -586,6 +976,11 @@ impl Service {
         fs::svc_logs_path(&self.service_group.service())
     }
 
+    /// Returns the path to the service's decrypted secrets.
+    fn svc_secrets_file(&self) -> PathBuf {
+        fs::svc_secrets_file(&self.service_group.service())
+    }
+
     /// this function wraps create_dir_all so we can give friendly error
     /// messages to the user.
     fn create_dir_all<P: AsRef<Path>>(path: P) -> Result<()> {
@@ -597,7 +992,7 @@ impl Service {
         }
     }
 
-    fn cache_health_check(&self, check_result: HealthCheck) {
+    fn cache_health_check(&self, check_result: HealthCheck, duration: Duration) {
         let state_file = self.manager_fs_cfg
             .data_path
             .join(format!("{}.health", self.service_group.service()));
@@ -611,10 +1006,13 @@ impl Service {
                 return;
             }
         };
+        let cached = CachedHealthCheck {
+            status: check_result as i8,
+            checked_at: time::now_utc().rfc3339().to_string(),
+            duration_ms: duration.as_secs() * 1_000 + (duration.subsec_nanos() / 1_000_000) as u64,
+        };
         let mut writer = BufWriter::new(file);
-        if let Some(err) = writer
-               .write_all((check_result as i8).to_string().as_bytes())
-               .err() {
+        if let Some(err) = serde_json::to_writer(&mut writer, &cached).err() {
             warn!("Couldn't write to temporary health check state file, {}, {}",
                   self.service_group,
                   err);
@@ -661,11 +1059,22 @@ impl Service {
             }
         } else {
             self.check_process();
-            if Instant::now().duration_since(self.last_health_check) >= *HEALTH_CHECK_INTERVAL {
+            if self.is_halted() {
+                return;
+            }
+            let interval = Duration::from_secs(self.health_check_interval_secs);
+            if self.last_health_check
+                   .map_or(true, |checked_at| checked_at.elapsed() >= interval) {
                 self.run_health_check_hook();
             }
+            if self.is_backing_off() {
+                return;
+            }
 
             if self.needs_reload || self.is_down() || self.needs_reconfiguration {
+                if self.needs_reload || self.needs_reconfiguration {
+                    metrics::record_config_reload(&self.service_group);
+                }
                 self.reload();
                 if self.needs_reconfiguration {
                     self.reconfigure()
@@ -691,12 +1100,42 @@ impl Service {
         if let Some((incarnation, config)) =
             butterfly.service_config_for(&*self.service_group, Some(self.config.incarnation)) {
             self.config.incarnation = incarnation;
+            if let Err(e) = self.config.validate_gossip(&config) {
+                outputln!(preamble self.service_group,
+                          "Rejected configuration incarnation {}: {}",
+                          incarnation,
+                          Red.bold().paint(format!("{}", e)));
+                return false;
+            }
             self.write_butterfly_service_config(config)
         } else {
             false
         }
     }
 
+    /// Decrypt service secrets from gossip data into memory and write them to the service's
+    /// tmpfs-backed secrets file.
+    ///
+    /// Returns true if the secrets changed and false if there were no updates.
+    fn persist_service_secrets(&mut self, butterfly: &butterfly::Server) -> bool {
+        let secret_incarnation = Some(self.config.secret_incarnation);
+        if let Some((incarnation, secrets)) =
+            butterfly.service_secret_for(&*self.service_group,
+                                         secret_incarnation,
+                                         default_cache_key_path(None)) {
+            let secrets = secrets.as_table().cloned().unwrap_or_default();
+            if let Err(e) = self.write_service_secrets(&secrets) {
+                outputln!(preamble self.service_group,
+                          "Service secrets from butterfly failed to write: {}",
+                          Red.bold().paint(format!("{}", e)));
+            }
+            self.config.set_secrets(incarnation, secrets);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Write service files from gossip data to disk.
     ///
     /// Returnst rue if a file was changed, added, or removed, and false if there were no updates.
@@ -729,17 +1168,45 @@ impl Service {
         Ok(())
     }
 
+    /// Run the health check and record its result. A failing result only becomes the service's
+    /// health once `health_check_failure_threshold` checks in a row have failed; a health check
+    /// hook which runs for longer than `health_check_timeout_secs` is killed and counts as
+    /// critical.
     fn run_health_check_hook(&mut self) {
+        let started_at = Instant::now();
         let check_result = if let Some(ref hook) = self.hooks.health_check {
-            hook.run(&self.service_group, self.runtime_cfg())
+            let timeout = match self.health_check_timeout_secs {
+                0 => None,
+                secs => Some(Duration::from_secs(secs)),
+            };
+            hook.run_with_timeout(&self.service_group, self.runtime_cfg(), timeout)
+                .unwrap_or(HealthCheck::Critical)
         } else {
             match self.supervisor.status() {
                 (true, _) => HealthCheck::Ok,
                 (false, _) => HealthCheck::Critical,
             }
         };
-        self.last_health_check = Instant::now();
-        self.cache_health_check(check_result);
+        let duration = started_at.elapsed();
+        self.last_health_check = Some(Instant::now());
+        if check_result.is_failure() {
+            self.health_check_failures += 1;
+        } else {
+            self.health_check_failures = 0;
+        }
+        if self.health_check_failures == 0 ||
+           self.health_check_failures >= self.health_check_failure_threshold {
+            self.health_check = check_result;
+        } else {
+            outputln!(preamble self.service_group,
+                "Health check failed ({} of {} before reporting {})",
+                self.health_check_failures,
+                self.health_check_failure_threshold,
+                check_result);
+        }
+        let health_check = self.health_check;
+        metrics::record_health_check(&self.service_group, health_check, duration);
+        self.cache_health_check(health_check, duration);
     }
 
     /// Update our own service rumor with a new configuration from the packages exported
@@ -773,6 +1240,41 @@ impl Service {
         false
     }
 
+    /// Write the decrypted secrets to a file on tmpfs which only the service user can read.
+    fn write_service_secrets(&self, secrets: &toml::value::Table) -> Result<()> {
+        let (user, group) = try!(util::users::get_user_and_group(&self.package()));
+        let on_disk_path = self.svc_secrets_file();
+        let secrets_path = on_disk_path.parent().expect("Secrets file has no parent directory");
+        try!(Self::create_dir_all(secrets_path));
+        try!(set_owner(secrets_path, &user, &group));
+        try!(set_permissions(secrets_path, 0o700));
+
+        let encoded = try!(toml::ser::to_vec(&toml::Value::Table(secrets.clone())));
+        let new_filename = on_disk_path.with_extension("write");
+        {
+            // Restrict the file before anything is written to it.
+            let mut new_file = try!(File::create(&new_filename));
+            try!(set_owner(&new_filename, &user, &group));
+            try!(set_permissions(&new_filename, 0o600));
+            try!(new_file.write_all(&encoded));
+        }
+        try!(std::fs::rename(&new_filename, &on_disk_path));
+        Ok(())
+    }
+
+    /// Remove the service's decrypted secrets from tmpfs.
+    pub fn remove_secrets(&self) {
+        let secrets_file = self.svc_secrets_file();
+        if secrets_file.exists() {
+            if let Err(e) = std::fs::remove_file(&secrets_file) {
+                outputln!(preamble self.service_group,
+                          "Unable to remove service secrets {}: {}",
+                          secrets_file.display(),
+                          e);
+            }
+        }
+    }
+
     fn write_butterfly_service_file(&mut self,
                                     filename: String,
                                     incarnation: u64,
@@ -933,15 +1435,25 @@ impl fmt::Display for Service {
 pub enum Topology {
     Standalone,
     Leader,
+    Replicated,
 }
 
 impl Topology {
     fn as_str(&self) -> &str {
         match *self {
             Topology::Leader => "leader",
+            Topology::Replicated => "replicated",
             Topology::Standalone => "standalone",
         }
     }
+
+    /// Returns true if members of the service group elect a leader before running.
+    pub fn is_elected(&self) -> bool {
+        match *self {
+            Topology::Leader | Topology::Replicated => true,
+            Topology::Standalone => false,
+        }
+    }
 }
 
 impl FromStr for Topology {
@@ -950,6 +1462,7 @@ impl FromStr for Topology {
     fn from_str(topology: &str) -> result::Result<Self, Self::Err> {
         match topology {
             "leader" => Ok(Topology::Leader),
+            "replicated" => Ok(Topology::Replicated),
             "standalone" => Ok(Topology::Standalone),
             _ => Err(sup_error!(Error::InvalidTopology(String::from(topology)))),
         }
@@ -989,6 +1502,7 @@ pub enum UpdateStrategy {
     None,
     AtOnce,
     Rolling,
+    Canary,
 }
 
 impl UpdateStrategy {
@@ -997,6 +1511,7 @@ impl UpdateStrategy {
             UpdateStrategy::None => "none",
             UpdateStrategy::AtOnce => "at-once",
             UpdateStrategy::Rolling => "rolling",
+            UpdateStrategy::Canary => "canary",
         }
     }
 }
@@ -1009,6 +1524,7 @@ impl FromStr for UpdateStrategy {
             "none" => Ok(UpdateStrategy::None),
             "at-once" => Ok(UpdateStrategy::AtOnce),
             "rolling" => Ok(UpdateStrategy::Rolling),
+            "canary" => Ok(UpdateStrategy::Canary),
             _ => Err(sup_error!(Error::InvalidUpdateStrategy(String::from(strategy)))),
         }
     }
@@ -1042,6 +1558,157 @@ impl serde::Serialize for UpdateStrategy {
     }
 }
 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RestartPolicy {
+    Always,
+    OnFailure,
+    Never,
+}
+
+impl RestartPolicy {
+    fn as_str(&self) -> &str {
+        match *self {
+            RestartPolicy::Always => "always",
+            RestartPolicy::OnFailure => "on-failure",
+            RestartPolicy::Never => "never",
+        }
+    }
+
+    /// Returns true if a process which exited, successfully or not, should be restarted.
+    pub fn restarts_after(&self, succeeded: bool) -> bool {
+        match *self {
+            RestartPolicy::Always => true,
+            RestartPolicy::OnFailure => !succeeded,
+            RestartPolicy::Never => false,
+        }
+    }
+}
+
+impl FromStr for RestartPolicy {
+    type Err = SupError;
+
+    fn from_str(policy: &str) -> result::Result<Self, Self::Err> {
+        match policy {
+            "always" => Ok(RestartPolicy::Always),
+            "on-failure" => Ok(RestartPolicy::OnFailure),
+            "never" => Ok(RestartPolicy::Never),
+            _ => Err(sup_error!(Error::InvalidRestartPolicy(String::from(policy)))),
+        }
+    }
+}
+
+impl fmt::Display for RestartPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> RestartPolicy {
+        RestartPolicy::Always
+    }
+}
+
+impl serde::Deserialize for RestartPolicy {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for RestartPolicy {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Number of seconds to wait before the next restart of a service which has already been
+/// restarted `restarts` times within its restart window.
+fn restart_backoff_secs(backoff: u64, ceiling: u64, restarts: u32) -> u64 {
+    let factor = 1u64.checked_shl(restarts).unwrap_or(u64::max_value());
+    cmp::min(backoff.saturating_mul(factor), ceiling)
+}
+
+/// The previously running release of a freshly updated service.
+#[derive(Debug)]
+struct UpdateProbation {
+    previous: PackageInstall,
+    started: Instant,
+    period: Duration,
+    restarts: u32,
+}
+
+impl UpdateProbation {
+    fn new(previous: PackageInstall) -> Self {
+        UpdateProbation {
+            previous: previous,
+            started: Instant::now(),
+            period: *UPDATE_PROBATION_PERIOD,
+            restarts: 0,
+        }
+    }
+
+    /// Returns true once the updated release has outlasted its probation period.
+    fn is_expired(&self) -> bool {
+        self.started.elapsed() >= self.period
+    }
+
+    /// Returns the reason the updated release has failed, if it has, given the result of its
+    /// latest health check and whether its process supervisor has given up restarting it.
+    fn failure(&self, health_check: HealthCheck, failed: bool) -> Option<RollbackReason> {
+        if health_check == HealthCheck::Critical {
+            Some(RollbackReason::HealthCheckCritical)
+        } else if self.restarts >= UPDATE_PROBATION_MAX_RESTARTS || failed {
+            Some(RollbackReason::CrashLoop(self.restarts))
+        } else {
+            None
+        }
+    }
+}
+
+/// A record of a service which was reverted to its previous release after a failed update.
+#[derive(Debug, Serialize)]
+pub struct Rollback {
+    pub from: PackageIdent,
+    pub to: PackageIdent,
+    pub reason: RollbackReason,
+    pub rolled_back_at: String,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RollbackReason {
+    HealthCheckCritical,
+    CrashLoop(u32),
+    /// A canary did not pass its soak period, of the given number of seconds, before its deadline.
+    SoakDeadline(u64),
+}
+
+impl fmt::Display for RollbackReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RollbackReason::HealthCheckCritical => write!(f, "health check reported CRITICAL"),
+            RollbackReason::CrashLoop(restarts) => {
+                write!(f, "service exited {} times after updating", restarts)
+            }
+            RollbackReason::SoakDeadline(secs) => {
+                write!(f, "canary did not stay healthy for its {} second soak period in time",
+                       secs)
+            }
+        }
+    }
+}
+
+impl serde::Serialize for RollbackReason {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 fn serialize_lock<S>(x: &Arc<RwLock<PackageInstall>>, s: S) -> result::Result<S::Ok, S::Error>
     where S: serde::Serializer
 {
@@ -1050,13 +1717,28 @@ fn serialize_lock<S>(x: &Arc<RwLock<PackageInstall>>, s: S) -> result::Result<S:
 
 #[cfg(test)]
 mod test {
+    use std::path::PathBuf;
     use std::str::FromStr;
+    use std::time::Instant;
 
+    use hcore::package::{PackageIdent, PackageInstall};
     use toml;
 
-    use super::{Topology, UpdateStrategy};
+    use super::{restart_backoff_secs, HealthCheck, RestartPolicy, RollbackReason, Topology,
+                UpdateProbation, UpdateStrategy, UPDATE_PROBATION_MAX_RESTARTS,
+                UPDATE_PROBATION_PERIOD};
     use error::Error::*;
 
+    fn probation() -> UpdateProbation {
+        let previous =
+            PackageInstall::new_from_parts(PackageIdent::from_str("core/redis/3.2.4/20170514150022")
+                                               .unwrap(),
+                                           PathBuf::from("/"),
+                                           PathBuf::from("/fakeo"),
+                                           PathBuf::from("/fakeo/here"));
+        UpdateProbation::new(previous)
+    }
+
     #[test]
     fn topology_default() {
         // This should always be the default topology, if this default gets changed, we have
@@ -1070,6 +1752,15 @@ mod test {
         let topology = Topology::from_str(topology_str).unwrap();
 
         assert_eq!(topology, Topology::Leader);
+        assert_eq!(Topology::from_str("replicated").unwrap(),
+                   Topology::Replicated);
+    }
+
+    #[test]
+    fn topology_is_elected() {
+        assert!(!Topology::Standalone.is_elected());
+        assert!(Topology::Leader.is_elected());
+        assert!(Topology::Replicated.is_elected());
     }
 
     #[test]
@@ -1121,6 +1812,49 @@ mod test {
         assert!(toml.starts_with(r#"key = "leader""#))
     }
 
+    #[test]
+    fn restart_policy_from_str() {
+        assert_eq!(RestartPolicy::from_str("on-failure").unwrap(),
+                   RestartPolicy::OnFailure);
+        assert_eq!("on-failure", RestartPolicy::OnFailure.to_string());
+        match RestartPolicy::from_str("sometimes") {
+            Err(e) => {
+                match e.err {
+                    InvalidRestartPolicy(s) => assert_eq!("sometimes", s),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("String should fail to parse"),
+        }
+    }
+
+    #[test]
+    fn restart_policy_restarts_after() {
+        assert!(RestartPolicy::Always.restarts_after(true));
+        assert!(RestartPolicy::Always.restarts_after(false));
+        assert!(!RestartPolicy::OnFailure.restarts_after(true));
+        assert!(RestartPolicy::OnFailure.restarts_after(false));
+        assert!(!RestartPolicy::Never.restarts_after(false));
+    }
+
+    #[test]
+    fn restart_backoff_doubles_up_to_ceiling() {
+        assert_eq!(restart_backoff_secs(1, 60, 0), 1);
+        assert_eq!(restart_backoff_secs(1, 60, 3), 8);
+        assert_eq!(restart_backoff_secs(1, 60, 6), 60);
+        assert_eq!(restart_backoff_secs(5, 60, 200), 60);
+    }
+
+    #[test]
+    fn rollback_reason_to_string() {
+        assert_eq!("health check reported CRITICAL",
+                   RollbackReason::HealthCheckCritical.to_string());
+        assert_eq!("service exited 3 times after updating",
+                   RollbackReason::CrashLoop(3).to_string());
+        assert_eq!("canary did not stay healthy for its 300 second soak period in time",
+                   RollbackReason::SoakDeadline(300).to_string());
+    }
+
     #[test]
     fn update_strategy_default() {
         // This should always be the default update strategy, if this default gets changed, we have
@@ -1152,6 +1886,14 @@ mod test {
         }
     }
 
+    #[test]
+    fn update_strategy_from_str_canary() {
+        let strategy = UpdateStrategy::from_str("canary").unwrap();
+
+        assert_eq!(strategy, UpdateStrategy::Canary);
+        assert_eq!("canary", strategy.to_string())
+    }
+
     #[test]
     fn update_strategy_to_string() {
         let strategy = UpdateStrategy::AtOnce;
@@ -1184,4 +1926,46 @@ mod test {
 
         assert!(toml.starts_with(r#"key = "at-once""#));
     }
+
+    #[test]
+    fn update_probation_expires_after_probation_period() {
+        let mut probation = probation();
+        assert!(!probation.is_expired());
+
+        probation.started = Instant::now() - *UPDATE_PROBATION_PERIOD;
+        assert!(probation.is_expired());
+
+        probation.period = *UPDATE_PROBATION_PERIOD * 2;
+        assert!(!probation.is_expired());
+    }
+
+    #[test]
+    fn update_probation_fails_on_critical_health_check() {
+        let probation = probation();
+
+        assert_eq!(probation.failure(HealthCheck::Critical, false),
+                   Some(RollbackReason::HealthCheckCritical));
+        assert_eq!(probation.failure(HealthCheck::Warning, false), None);
+        assert_eq!(probation.failure(HealthCheck::Unknown, false), None);
+        assert_eq!(probation.failure(HealthCheck::Ok, false), None);
+    }
+
+    #[test]
+    fn update_probation_fails_on_crash_loop() {
+        let mut probation = probation();
+        probation.restarts = UPDATE_PROBATION_MAX_RESTARTS - 1;
+        assert_eq!(probation.failure(HealthCheck::Ok, false), None);
+
+        probation.restarts = UPDATE_PROBATION_MAX_RESTARTS;
+        assert_eq!(probation.failure(HealthCheck::Ok, false),
+                   Some(RollbackReason::CrashLoop(UPDATE_PROBATION_MAX_RESTARTS)));
+    }
+
+    #[test]
+    fn update_probation_fails_when_restarts_are_given_up() {
+        let probation = probation();
+
+        assert_eq!(probation.failure(HealthCheck::Ok, true),
+                   Some(RollbackReason::CrashLoop(0)));
+    }
 }
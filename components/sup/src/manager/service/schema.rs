@@ -0,0 +1,311 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validation of a service's merged configuration against the schema shipped by its package.
+//!
+//! A package may include a `schema.toml` alongside its `default.toml`. Each top level key of the
+//! schema is the dotted path of a configuration key, and its value describes what is allowed
+//! there:
+//!
+//! ```toml
+//! ["port"]
+//! type = "integer"
+//! required = true
+//! min = 1
+//! max = 65535
+//!
+//! ["log.level"]
+//! type = "string"
+//! allowed = ["debug", "info", "warn"]
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::result;
+use std::str::FromStr;
+
+use hcore::util::deserialize_using_from_str;
+use serde;
+use toml;
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "CS";
+pub const SCHEMA_FILE: &'static str = "schema.toml";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ValueType {
+    Array,
+    Boolean,
+    Datetime,
+    Float,
+    Integer,
+    String,
+    Table,
+}
+
+impl ValueType {
+    fn as_str(&self) -> &str {
+        match *self {
+            ValueType::Array => "array",
+            ValueType::Boolean => "boolean",
+            ValueType::Datetime => "datetime",
+            ValueType::Float => "float",
+            ValueType::Integer => "integer",
+            ValueType::String => "string",
+            ValueType::Table => "table",
+        }
+    }
+
+    fn matches(&self, value: &toml::Value) -> bool {
+        match (*self, value) {
+            (ValueType::Array, &toml::Value::Array(_)) |
+            (ValueType::Boolean, &toml::Value::Boolean(_)) |
+            (ValueType::Datetime, &toml::Value::Datetime(_)) |
+            (ValueType::Integer, &toml::Value::Integer(_)) |
+            (ValueType::String, &toml::Value::String(_)) |
+            (ValueType::Table, &toml::Value::Table(_)) => true,
+            // An integer is an acceptable float, so `1` may be given where `1.0` is expected.
+            (ValueType::Float, &toml::Value::Float(_)) |
+            (ValueType::Float, &toml::Value::Integer(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for ValueType {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "array" => Ok(ValueType::Array),
+            "boolean" => Ok(ValueType::Boolean),
+            "datetime" => Ok(ValueType::Datetime),
+            "float" => Ok(ValueType::Float),
+            "integer" => Ok(ValueType::Integer),
+            "string" => Ok(ValueType::String),
+            "table" => Ok(ValueType::Table),
+            _ => Err(sup_error!(Error::InvalidSchemaType(value.to_string()))),
+        }
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl serde::Deserialize for ValueType {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+/// The rules for a single configuration key.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeySchema {
+    #[serde(rename = "type")]
+    pub value_type: Option<ValueType>,
+    #[serde(default)]
+    pub required: bool,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub allowed: Option<Vec<toml::Value>>,
+}
+
+impl KeySchema {
+    fn check(&self, key: &str, value: &toml::Value, violations: &mut Vec<String>) {
+        if let Some(value_type) = self.value_type {
+            if !value_type.matches(value) {
+                violations.push(format!("{} must be of type {}, found {}",
+                                        key,
+                                        value_type,
+                                        value.type_str()));
+                return;
+            }
+        }
+        let number = match *value {
+            toml::Value::Integer(i) => Some(i as f64),
+            toml::Value::Float(f) => Some(f),
+            _ => None,
+        };
+        if let Some(number) = number {
+            if let Some(min) = self.min {
+                if number < min {
+                    violations.push(format!("{} must be at least {}, found {}", key, min, value));
+                }
+            }
+            if let Some(max) = self.max {
+                if number > max {
+                    violations.push(format!("{} must be at most {}, found {}", key, max, value));
+                }
+            }
+        }
+        if let Some(ref allowed) = self.allowed {
+            if !allowed.contains(value) {
+                let allowed: Vec<String> = allowed.iter().map(|v| v.to_string()).collect();
+                violations.push(format!("{} must be one of {}, found {}",
+                                        key,
+                                        allowed.join(", "),
+                                        value));
+            }
+        }
+    }
+}
+
+/// The schema a package's merged configuration must satisfy before it is rendered.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ConfigSchema(BTreeMap<String, KeySchema>);
+
+impl ConfigSchema {
+    /// Load the schema from the package's configuration root, if the package has one.
+    pub fn load<T: AsRef<Path>>(config_root: T) -> Result<Option<Self>> {
+        let path = config_root.as_ref().join(SCHEMA_FILE);
+        let mut file = match File::open(&path) {
+            Ok(file) => file,
+            Err(e) => {
+                debug!("Failed to open {}: {}", SCHEMA_FILE, e);
+                return Ok(None);
+            }
+        };
+        let mut content = String::new();
+        if let Err(e) = file.read_to_string(&mut content) {
+            outputln!("Failed to read {}: {}", path.display(), e);
+            return Ok(None);
+        }
+        Ok(Some(try!(Self::from_str(&content))))
+    }
+
+    /// Check the configuration against every rule in the schema, returning all of the
+    /// violations at once.
+    pub fn validate(&self, cfg: &toml::value::Table) -> Result<()> {
+        let mut violations = Vec::new();
+        for (key, rules) in self.0.iter() {
+            match lookup(cfg, key) {
+                Some(value) => rules.check(key, value, &mut violations),
+                None if rules.required => violations.push(format!("{} is required", key)),
+                None => (),
+            }
+        }
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(sup_error!(Error::ConfigSchemaViolation(violations)))
+        }
+    }
+}
+
+impl FromStr for ConfigSchema {
+    type Err = SupError;
+
+    fn from_str(toml: &str) -> result::Result<Self, Self::Err> {
+        let schema = try!(toml::from_str(toml).map_err(|e| sup_error!(Error::TomlParser(e))));
+        Ok(ConfigSchema(schema))
+    }
+}
+
+/// Find the value at a dotted path in a table.
+fn lookup<'a>(table: &'a toml::value::Table, path: &str) -> Option<&'a toml::Value> {
+    let mut fields = path.split('.');
+    let mut current = match fields.next() {
+        Some(field) => table.get(field),
+        None => None,
+    };
+    for field in fields {
+        current = current.and_then(|value| value.get(field));
+    }
+    current
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use toml;
+
+    use super::*;
+    use error::Error;
+
+    const SCHEMA: &'static str = r#"
+    ["port"]
+    type = "integer"
+    required = true
+    min = 1
+    max = 65535
+
+    ["log.level"]
+    type = "string"
+    allowed = ["debug", "info"]
+
+    ["ratio"]
+    type = "float"
+    "#;
+
+    fn cfg(toml: &str) -> toml::value::Table {
+        toml::from_str(toml).unwrap()
+    }
+
+    fn violations(toml: &str) -> Vec<String> {
+        let schema = ConfigSchema::from_str(SCHEMA).unwrap();
+        match schema.validate(&cfg(toml)) {
+            Ok(()) => vec![],
+            Err(e) => {
+                match e.err {
+                    Error::ConfigSchemaViolation(violations) => violations,
+                    wrong => panic!("Unexpected error returned {:?}", wrong),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn valid_config_passes() {
+        assert!(violations("port = 80\nratio = 1\n[log]\nlevel = \"info\"").is_empty());
+    }
+
+    #[test]
+    fn missing_required_key() {
+        assert_eq!(violations("ratio = 0.5"), vec!["port is required"]);
+    }
+
+    #[test]
+    fn wrong_type() {
+        assert_eq!(violations("port = \"80\""),
+                   vec!["port must be of type integer, found string"]);
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(violations("port = 70000"),
+                   vec!["port must be at most 65535, found 70000"]);
+    }
+
+    #[test]
+    fn value_not_allowed() {
+        assert_eq!(violations("port = 80\n[log]\nlevel = \"trace\""),
+                   vec!["log.level must be one of \"debug\", \"info\", found \"trace\""]);
+    }
+
+    #[test]
+    fn unknown_type_is_an_error() {
+        assert!(ConfigSchema::from_str("[\"port\"]\ntype = \"number\"").is_err());
+    }
+}
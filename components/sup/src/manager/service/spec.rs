@@ -19,6 +19,8 @@ use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
 
+use hcore::fs::{FS_ROOT_PATH, pkg_root_path};
+use hcore::os::process::{DEFAULT_SHUTDOWN_TIMEOUT_SECS, Isolation, ResourceLimits};
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
 use hcore::url::DEFAULT_DEPOT_URL;
@@ -27,11 +29,26 @@ use rand::{Rng, thread_rng};
 use serde;
 use toml;
 
-use super::{Topology, UpdateStrategy};
+use super::{RestartPolicy, Topology, UpdateStrategy};
 use error::{Error, Result, SupError};
+use fs::{SVC_ROOT, svc_data_path, svc_var_path};
+use output::{LogConfig, LogFormat};
 
 static LOGKEY: &'static str = "SS";
 static DEFAULT_GROUP: &'static str = "default";
+const DEFAULT_CANARY_SOAK_SECS: u64 = 300;
+const DEFAULT_CANARY_BATCH_PERCENT: u8 = 100;
+const DEFAULT_RESTART_MAX: u32 = 5;
+const DEFAULT_RESTART_WINDOW_SECS: u64 = 300;
+const DEFAULT_RESTART_BACKOFF_SECS: u64 = 1;
+const DEFAULT_RESTART_BACKOFF_MAX_SECS: u64 = 60;
+const DEFAULT_HEALTH_CHECK_INTERVAL_SECS: u64 = 30;
+const DEFAULT_HEALTH_CHECK_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_HEALTH_CHECK_FAILURE_THRESHOLD: u32 = 1;
+const DEFAULT_QUORUM_SIZE: u64 = 3;
+const DEFAULT_LOG_MAX_MB: u64 = 10;
+const DEFAULT_LOG_KEEP: u32 = 5;
+const MINUTES_PER_DAY: u32 = 24 * 60;
 const SPEC_FILE_EXT: &'static str = "spec";
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -79,7 +96,73 @@ pub struct ServiceSpec {
     pub group: String,
     pub depot_url: String,
     pub topology: Topology,
+    /// Number of members, besides the leader, designated as replicas by the `replicated`
+    /// topology.
+    pub replicas: u64,
+    /// Minimum number of members a service group must have before it can complete an election.
+    pub quorum_size: u64,
+    /// Number of seconds followers wait on an unreachable leader before electing a new one. A
+    /// value of 0 waits until the leader is confirmed dead.
+    pub leader_lease_secs: u64,
     pub update_strategy: UpdateStrategy,
+    /// Number of seconds a canary must report a passing health check before the rest of the
+    /// service group is released to update.
+    pub canary_soak_secs: u64,
+    /// Percentage of the remaining service group members which may update at the same time once
+    /// a canary has been released.
+    pub canary_batch_percent: u8,
+    /// Depot channel to follow for updated packages. When unset, the latest package in the depot
+    /// is used.
+    pub channel: Option<String>,
+    /// Number of seconds between checks for an updated package, or 0 to use the Supervisor's
+    /// update frequency.
+    pub update_frequency_secs: u64,
+    /// Maximum number of seconds added at random to each wait between checks, so that
+    /// Supervisors started together don't all poll the depot at the same time.
+    pub update_jitter_secs: u64,
+    /// Daily window, in UTC, outside of which updated packages are not applied.
+    pub maintenance_window: Option<MaintenanceWindow>,
+    pub restart_policy: RestartPolicy,
+    /// Number of restarts allowed within `restart_window_secs` before the service is marked as
+    /// failed and no longer restarted.
+    pub restart_max: u32,
+    pub restart_window_secs: u64,
+    /// Number of seconds to wait before restarting a service which has exited. The wait doubles
+    /// for each restart within `restart_window_secs`, up to `restart_backoff_max_secs`.
+    pub restart_backoff_secs: u64,
+    pub restart_backoff_max_secs: u64,
+    pub health_check_interval_secs: u64,
+    /// Number of seconds a health check hook may run before it is killed and the check reported
+    /// as critical. A value of 0 disables the timeout.
+    pub health_check_timeout_secs: u64,
+    /// Number of consecutive failed health checks required before the service reports a failing
+    /// health.
+    pub health_check_failure_threshold: u32,
+    /// Number of seconds the service is given to exit after SIGTERM before it is sent SIGKILL.
+    pub shutdown_timeout_secs: u64,
+    /// Relative share of CPU time given to the service's processes under contention. 1024 is the
+    /// share of an unlimited process.
+    pub cpu_shares: Option<u64>,
+    pub memory_limit_mb: Option<u64>,
+    /// Maximum number of processes and threads the service may run at the same time.
+    pub pids_max: Option<u64>,
+    pub open_files_max: Option<u64>,
+    /// Whether the service's run hook runs in its own PID and mount namespaces, in which
+    /// packages and service directories are read-only, except for the service's own data and var
+    /// directories.
+    pub isolate: bool,
+    /// Whether the service also runs in its own network namespace, with only a loopback
+    /// interface. Implies `isolate`.
+    pub isolate_network: bool,
+    /// Whether the service's output is written to a log file in its `logs` directory.
+    pub log_to_file: bool,
+    pub log_format: LogFormat,
+    /// Size in megabytes at which the log file is rotated, or 0 to never rotate by size.
+    pub log_max_mb: u64,
+    /// Seconds after which the log file is rotated, or 0 to never rotate by age.
+    pub log_rotate_secs: u64,
+    /// Number of rotated log files to keep.
+    pub log_keep: u32,
     pub binds: Vec<ServiceBind>,
     pub config_from: Option<PathBuf>,
     #[serde(
@@ -153,14 +236,130 @@ impl ServiceSpec {
     }
 
     pub fn file_name(&self) -> String {
-        format!("{}.{}", &self.ident.name, SPEC_FILE_EXT)
+        Self::file_name_for(&self.ident.name)
+    }
+
+    /// Returns true if this service binds to the service group of the given spec.
+    pub fn binds_to(&self, other: &ServiceSpec) -> bool {
+        self.binds.iter().any(|bind| bind.is_bound_to(other))
+    }
+
+    /// Returns the file name of the spec for the service with the given name.
+    pub fn file_name_for(service_name: &str) -> String {
+        format!("{}.{}", service_name, SPEC_FILE_EXT)
     }
 
     pub fn validate(&self, package: &PackageInstall) -> Result<()> {
         self.validate_binds(package)?;
+        self.validate_settings()
+    }
+
+    /// Validates the parts of the spec which don't depend on the service's package.
+    pub fn validate_settings(&self) -> Result<()> {
+        self.validate_topology()?;
+        self.validate_canary()?;
+        self.validate_restart()?;
+        self.validate_health_check()?;
+        self.validate_resource_limits()?;
+        self.validate_isolation()?;
+        Ok(())
+    }
+
+    fn validate_topology(&self) -> Result<()> {
+        if self.quorum_size == 0 {
+            return Err(sup_error!(Error::InvalidQuorumSize));
+        }
+        if self.topology == Topology::Replicated && self.replicas == 0 {
+            return Err(sup_error!(Error::InvalidReplicas));
+        }
+        Ok(())
+    }
+
+    fn validate_canary(&self) -> Result<()> {
+        if self.canary_batch_percent == 0 || self.canary_batch_percent > 100 {
+            return Err(sup_error!(Error::InvalidCanaryBatchPercent(self.canary_batch_percent)));
+        }
+        Ok(())
+    }
+
+    fn validate_restart(&self) -> Result<()> {
+        if self.restart_backoff_max_secs < self.restart_backoff_secs {
+            return Err(sup_error!(Error::InvalidRestartBackoff(self.restart_backoff_secs,
+                                                               self.restart_backoff_max_secs)));
+        }
+        Ok(())
+    }
+
+    fn validate_health_check(&self) -> Result<()> {
+        if self.health_check_interval_secs == 0 {
+            return Err(sup_error!(Error::InvalidHealthCheckInterval));
+        }
+        if self.health_check_failure_threshold == 0 {
+            return Err(sup_error!(Error::InvalidHealthCheckThreshold));
+        }
+        Ok(())
+    }
+
+    fn validate_resource_limits(&self) -> Result<()> {
+        if self.cpu_shares.map_or(false, |shares| shares < 2) {
+            return Err(sup_error!(Error::InvalidResourceLimit("cpu_shares", 2)));
+        }
+        if self.memory_limit_mb == Some(0) {
+            return Err(sup_error!(Error::InvalidResourceLimit("memory_limit_mb", 1)));
+        }
+        if self.pids_max == Some(0) {
+            return Err(sup_error!(Error::InvalidResourceLimit("pids_max", 1)));
+        }
+        if self.open_files_max == Some(0) {
+            return Err(sup_error!(Error::InvalidResourceLimit("open_files_max", 1)));
+        }
+        Ok(())
+    }
+
+    /// Returns the limits on the resources available to the service's processes.
+    pub fn resource_limits(&self) -> ResourceLimits {
+        ResourceLimits {
+            cpu_shares: self.cpu_shares,
+            memory_bytes: self.memory_limit_mb.map(|mb| mb * 1024 * 1024),
+            pids_max: self.pids_max,
+            open_files_max: self.open_files_max,
+        }
+    }
+
+    fn validate_isolation(&self) -> Result<()> {
+        if (self.isolate || self.isolate_network) && !cfg!(target_os = "linux") {
+            return Err(sup_error!(Error::IsolationNotSupported));
+        }
         Ok(())
     }
 
+    /// Returns the namespaces the service's run hook is isolated in, or `None` if it runs in the
+    /// Supervisor's.
+    pub fn isolation(&self) -> Option<Isolation> {
+        if !self.isolate && !self.isolate_network {
+            return None;
+        }
+        Some(Isolation {
+                 network: self.isolate_network,
+                 read_only_paths: vec![pkg_root_path(Some(&*FS_ROOT_PATH)), SVC_ROOT.clone()],
+                 writable_paths: vec![svc_data_path(&self.ident.name),
+                                      svc_var_path(&self.ident.name)],
+             })
+    }
+
+    /// Returns how the service's output is written to its log file, or `None` if it is not.
+    pub fn log_config(&self) -> Option<LogConfig> {
+        if !self.log_to_file {
+            return None;
+        }
+        Some(LogConfig {
+                 format: self.log_format,
+                 max_bytes: self.log_max_mb * 1024 * 1024,
+                 rotate_secs: self.log_rotate_secs,
+                 keep: self.log_keep,
+             })
+    }
+
     fn validate_binds(&self, package: &PackageInstall) -> Result<()> {
         let missing: Vec<String> = package
             .binds()?
@@ -187,7 +386,36 @@ impl Default for ServiceSpec {
             group: DEFAULT_GROUP.to_string(),
             depot_url: DEFAULT_DEPOT_URL.to_string(),
             topology: Topology::default(),
+            replicas: 0,
+            quorum_size: DEFAULT_QUORUM_SIZE,
+            leader_lease_secs: 0,
             update_strategy: UpdateStrategy::default(),
+            canary_soak_secs: DEFAULT_CANARY_SOAK_SECS,
+            canary_batch_percent: DEFAULT_CANARY_BATCH_PERCENT,
+            channel: None,
+            update_frequency_secs: 0,
+            update_jitter_secs: 0,
+            maintenance_window: None,
+            restart_policy: RestartPolicy::default(),
+            restart_max: DEFAULT_RESTART_MAX,
+            restart_window_secs: DEFAULT_RESTART_WINDOW_SECS,
+            restart_backoff_secs: DEFAULT_RESTART_BACKOFF_SECS,
+            restart_backoff_max_secs: DEFAULT_RESTART_BACKOFF_MAX_SECS,
+            health_check_interval_secs: DEFAULT_HEALTH_CHECK_INTERVAL_SECS,
+            health_check_timeout_secs: DEFAULT_HEALTH_CHECK_TIMEOUT_SECS,
+            health_check_failure_threshold: DEFAULT_HEALTH_CHECK_FAILURE_THRESHOLD,
+            shutdown_timeout_secs: DEFAULT_SHUTDOWN_TIMEOUT_SECS,
+            cpu_shares: None,
+            memory_limit_mb: None,
+            pids_max: None,
+            open_files_max: None,
+            isolate: false,
+            isolate_network: false,
+            log_to_file: false,
+            log_format: LogFormat::default(),
+            log_max_mb: DEFAULT_LOG_MAX_MB,
+            log_rotate_secs: 0,
+            log_keep: DEFAULT_LOG_KEEP,
             binds: vec![],
             config_from: None,
             desired_state: DesiredState::default(),
@@ -231,6 +459,13 @@ impl FromStr for ServiceBind {
     }
 }
 
+impl ServiceBind {
+    /// Returns true if this bind is to the service group of the given service spec.
+    pub fn is_bound_to(&self, spec: &ServiceSpec) -> bool {
+        self.service_group.service() == spec.ident.name && self.service_group.group() == spec.group
+    }
+}
+
 impl fmt::Display for ServiceBind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}:{}", self.name, self.service_group)
@@ -253,6 +488,114 @@ impl serde::Serialize for ServiceBind {
     }
 }
 
+/// A daily window of time, in UTC, written as `HH:MM-HH:MM`. A window which ends before it starts
+/// spans midnight.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MaintenanceWindow {
+    // Minutes after midnight at which the window opens and closes.
+    start: u32,
+    end: u32,
+}
+
+impl MaintenanceWindow {
+    /// Returns true if the window is open at the given minute of the day.
+    pub fn contains(&self, minute: u32) -> bool {
+        if self.start < self.end {
+            minute >= self.start && minute < self.end
+        } else {
+            minute >= self.start || minute < self.end
+        }
+    }
+
+    /// Returns the number of minutes from the given minute of the day until the window next
+    /// opens, or 0 if it is open.
+    pub fn minutes_until_open(&self, minute: u32) -> u32 {
+        if self.contains(minute) {
+            0
+        } else {
+            (self.start + MINUTES_PER_DAY - minute) % MINUTES_PER_DAY
+        }
+    }
+
+    fn parse_time(value: &str) -> Option<u32> {
+        let parts: Vec<&str> = value.trim().splitn(2, ':').collect();
+        if parts.len() != 2 || parts[0].is_empty() || parts[1].len() != 2 {
+            return None;
+        }
+        match (parts[0].parse::<u32>(), parts[1].parse::<u32>()) {
+            (Ok(hour), Ok(minute)) if hour < 24 && minute < 60 => Some(hour * 60 + minute),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for MaintenanceWindow {
+    type Err = SupError;
+
+    fn from_str(window: &str) -> result::Result<Self, Self::Err> {
+        let times: Vec<&str> = window.splitn(2, '-').collect();
+        if times.len() != 2 {
+            return Err(sup_error!(Error::BadMaintenanceWindow(window.to_string())));
+        }
+        match (Self::parse_time(times[0]), Self::parse_time(times[1])) {
+            (Some(start), Some(end)) if start != end => {
+                Ok(MaintenanceWindow {
+                       start: start,
+                       end: end,
+                   })
+            }
+            _ => Err(sup_error!(Error::BadMaintenanceWindow(window.to_string()))),
+        }
+    }
+}
+
+impl fmt::Display for MaintenanceWindow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "{:02}:{:02}-{:02}:{:02}",
+               self.start / 60,
+               self.start % 60,
+               self.end / 60,
+               self.end % 60)
+    }
+}
+
+impl serde::Deserialize for MaintenanceWindow {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for MaintenanceWindow {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Returns the indices of the given specs in the order their services should be started, with
+/// every service after the services it binds to. Services which bind to each other in a cycle
+/// keep their relative order.
+pub fn start_order(specs: &[ServiceSpec]) -> Vec<usize> {
+    let mut order = Vec::with_capacity(specs.len());
+    let mut pending: Vec<usize> = (0..specs.len()).collect();
+    while !pending.is_empty() {
+        let next = pending
+            .iter()
+            .position(|&i| {
+                          !pending
+                               .iter()
+                               .any(|&j| j != i && specs[i].binds_to(&specs[j]))
+                      })
+            .unwrap_or(0);
+        order.push(pending.remove(next));
+    }
+    order
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum StartStyle {
     Persistent,
@@ -302,6 +645,7 @@ mod test {
 
     use super::*;
     use error::Error::*;
+    use fs::{SVC_ROOT, svc_data_path, svc_var_path};
 
     fn file_from_str<P: AsRef<Path>>(path: P, content: &str) {
         fs::create_dir_all(path.as_ref()
@@ -352,6 +696,172 @@ mod test {
         assert_eq!(spec.start_style, StartStyle::Persistent);
     }
 
+    #[test]
+    fn service_spec_from_str_replicated() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            topology = "replicated"
+            replicas = 2
+            leader_lease_secs = 10
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.topology, Topology::Replicated);
+        assert_eq!(spec.replicas, 2);
+        assert_eq!(spec.quorum_size, DEFAULT_QUORUM_SIZE);
+        assert_eq!(spec.leader_lease_secs, 10);
+        assert!(spec.validate_topology().is_ok());
+    }
+
+    #[test]
+    fn service_spec_replicated_requires_replicas() {
+        let mut spec = ServiceSpec::default();
+        spec.topology = Topology::Replicated;
+        match spec.validate_topology() {
+            Err(e) => {
+                match e.err {
+                    InvalidReplicas => assert!(true),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("Replicated topology without replicas should not validate"),
+        }
+    }
+
+    #[test]
+    fn service_spec_from_str_canary() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            update_strategy = "canary"
+            canary_soak_secs = 120
+            canary_batch_percent = 50
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.update_strategy, UpdateStrategy::Canary);
+        assert_eq!(spec.canary_soak_secs, 120);
+        assert_eq!(spec.canary_batch_percent, 50);
+    }
+
+    #[test]
+    fn service_spec_from_str_update_polling() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            update_strategy = "at-once"
+            channel = "unstable"
+            update_frequency_secs = 600
+            update_jitter_secs = 120
+            maintenance_window = "01:00-03:00"
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.channel, Some(String::from("unstable")));
+        assert_eq!(spec.update_frequency_secs, 600);
+        assert_eq!(spec.update_jitter_secs, 120);
+        assert_eq!(spec.maintenance_window,
+                   Some(MaintenanceWindow::from_str("01:00-03:00").unwrap()));
+    }
+
+    #[test]
+    fn service_spec_from_str_invalid_maintenance_window() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            maintenance_window = "25:00-03:00"
+            "#;
+
+        match ServiceSpec::from_str(toml) {
+            Err(e) => {
+                match e.err {
+                    ServiceSpecParse(_) => assert!(true),
+                    e => panic!("Unexpected error returned: {:?}", e),
+                }
+            }
+            Ok(_) => panic!("Spec TOML should fail to parse"),
+        }
+    }
+
+    #[test]
+    fn service_spec_from_str_restart_policy() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            restart_policy = "never"
+            restart_max = 10
+            restart_window_secs = 60
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.restart_policy, RestartPolicy::Never);
+        assert_eq!(spec.restart_max, 10);
+        assert_eq!(spec.restart_window_secs, 60);
+        assert_eq!(spec.restart_backoff_secs, DEFAULT_RESTART_BACKOFF_SECS);
+        assert_eq!(spec.restart_backoff_max_secs, DEFAULT_RESTART_BACKOFF_MAX_SECS);
+    }
+
+    #[test]
+    fn service_spec_from_str_health_check() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            health_check_interval_secs = 5
+            health_check_failure_threshold = 2
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+
+        assert_eq!(spec.health_check_interval_secs, 5);
+        assert_eq!(spec.health_check_timeout_secs, DEFAULT_HEALTH_CHECK_TIMEOUT_SECS);
+        assert_eq!(spec.health_check_failure_threshold, 2);
+    }
+
+    #[test]
+    fn service_spec_from_str_resource_limits() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            cpu_shares = 512
+            memory_limit_mb = 64
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+        let limits = spec.resource_limits();
+
+        assert_eq!(limits.cpu_shares, Some(512));
+        assert_eq!(limits.memory_bytes, Some(67108864));
+        assert_eq!(limits.pids_max, None);
+        assert_eq!(limits.open_files_max, None);
+    }
+
+    #[test]
+    fn service_spec_from_str_isolation() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            isolate_network = true
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+        let isolation = spec.isolation().unwrap();
+
+        assert!(isolation.network);
+        assert!(isolation.read_only_paths.contains(&*SVC_ROOT));
+        assert_eq!(isolation.writable_paths,
+                   vec![svc_data_path("name"), svc_var_path("name")]);
+
+        let spec = ServiceSpec::from_str(r#"ident = "origin/name""#).unwrap();
+        assert_eq!(spec.isolation(), None);
+    }
+
+    #[test]
+    fn service_spec_from_str_log_config() {
+        let toml = r#"
+            ident = "origin/name/1.2.3/20170223130020"
+            log_to_file = true
+            log_format = "json"
+            log_max_mb = 1
+            "#;
+        let spec = ServiceSpec::from_str(toml).unwrap();
+        let config = spec.log_config().unwrap();
+
+        assert_eq!(config.format, LogFormat::Json);
+        assert_eq!(config.max_bytes, 1048576);
+        assert_eq!(config.rotate_secs, 0);
+        assert_eq!(config.keep, DEFAULT_LOG_KEEP);
+    }
+
     #[test]
     fn service_spec_from_str_missing_ident() {
         let toml = r#""#;
@@ -411,7 +921,36 @@ mod test {
             group: String::from("jobs"),
             depot_url: String::from("http://example.com/depot"),
             topology: Topology::Leader,
+            replicas: 2,
+            quorum_size: 5,
+            leader_lease_secs: 15,
             update_strategy: UpdateStrategy::AtOnce,
+            canary_soak_secs: 600,
+            canary_batch_percent: 25,
+            channel: Some(String::from("stable")),
+            update_frequency_secs: 300,
+            update_jitter_secs: 60,
+            maintenance_window: Some(MaintenanceWindow::from_str("22:00-02:30").unwrap()),
+            restart_policy: RestartPolicy::OnFailure,
+            restart_max: 3,
+            restart_window_secs: 120,
+            restart_backoff_secs: 2,
+            restart_backoff_max_secs: 30,
+            health_check_interval_secs: 10,
+            health_check_timeout_secs: 5,
+            health_check_failure_threshold: 3,
+            shutdown_timeout_secs: 20,
+            cpu_shares: Some(512),
+            memory_limit_mb: Some(256),
+            pids_max: Some(100),
+            open_files_max: Some(4096),
+            isolate: true,
+            isolate_network: true,
+            log_to_file: true,
+            log_format: LogFormat::Json,
+            log_max_mb: 20,
+            log_rotate_secs: 3600,
+            log_keep: 3,
             binds: vec![ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()],
             config_from: Some(PathBuf::from("/only/for/development")),
@@ -424,7 +963,36 @@ mod test {
         assert!(toml.contains(r#"group = "jobs""#));
         assert!(toml.contains(r#"depot_url = "http://example.com/depot""#));
         assert!(toml.contains(r#"topology = "leader""#));
+        assert!(toml.contains(r#"replicas = 2"#));
+        assert!(toml.contains(r#"quorum_size = 5"#));
+        assert!(toml.contains(r#"leader_lease_secs = 15"#));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
+        assert!(toml.contains(r#"canary_soak_secs = 600"#));
+        assert!(toml.contains(r#"canary_batch_percent = 25"#));
+        assert!(toml.contains(r#"channel = "stable""#));
+        assert!(toml.contains(r#"update_frequency_secs = 300"#));
+        assert!(toml.contains(r#"update_jitter_secs = 60"#));
+        assert!(toml.contains(r#"maintenance_window = "22:00-02:30""#));
+        assert!(toml.contains(r#"restart_policy = "on-failure""#));
+        assert!(toml.contains(r#"restart_max = 3"#));
+        assert!(toml.contains(r#"restart_window_secs = 120"#));
+        assert!(toml.contains(r#"restart_backoff_secs = 2"#));
+        assert!(toml.contains(r#"restart_backoff_max_secs = 30"#));
+        assert!(toml.contains(r#"health_check_interval_secs = 10"#));
+        assert!(toml.contains(r#"health_check_timeout_secs = 5"#));
+        assert!(toml.contains(r#"health_check_failure_threshold = 3"#));
+        assert!(toml.contains(r#"shutdown_timeout_secs = 20"#));
+        assert!(toml.contains(r#"cpu_shares = 512"#));
+        assert!(toml.contains(r#"memory_limit_mb = 256"#));
+        assert!(toml.contains(r#"pids_max = 100"#));
+        assert!(toml.contains(r#"open_files_max = 4096"#));
+        assert!(toml.contains(r#"isolate = true"#));
+        assert!(toml.contains(r#"isolate_network = true"#));
+        assert!(toml.contains(r#"log_to_file = true"#));
+        assert!(toml.contains(r#"log_format = "json""#));
+        assert!(toml.contains(r#"log_max_mb = 20"#));
+        assert!(toml.contains(r#"log_rotate_secs = 3600"#));
+        assert!(toml.contains(r#"log_keep = 3"#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
         assert!(toml.contains(r#"desired_state = "down""#));
@@ -539,7 +1107,36 @@ mod test {
             group: String::from("jobs"),
             depot_url: String::from("http://example.com/depot"),
             topology: Topology::Leader,
+            replicas: 2,
+            quorum_size: 5,
+            leader_lease_secs: 15,
             update_strategy: UpdateStrategy::AtOnce,
+            canary_soak_secs: 600,
+            canary_batch_percent: 25,
+            channel: Some(String::from("stable")),
+            update_frequency_secs: 300,
+            update_jitter_secs: 60,
+            maintenance_window: Some(MaintenanceWindow::from_str("22:00-02:30").unwrap()),
+            restart_policy: RestartPolicy::OnFailure,
+            restart_max: 3,
+            restart_window_secs: 120,
+            restart_backoff_secs: 2,
+            restart_backoff_max_secs: 30,
+            health_check_interval_secs: 10,
+            health_check_timeout_secs: 5,
+            health_check_failure_threshold: 3,
+            shutdown_timeout_secs: 20,
+            cpu_shares: Some(512),
+            memory_limit_mb: Some(256),
+            pids_max: Some(100),
+            open_files_max: Some(4096),
+            isolate: true,
+            isolate_network: true,
+            log_to_file: true,
+            log_format: LogFormat::Json,
+            log_max_mb: 20,
+            log_rotate_secs: 3600,
+            log_keep: 3,
             binds: vec![ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()],
             config_from: Some(PathBuf::from("/only/for/development")),
@@ -553,7 +1150,36 @@ mod test {
         assert!(toml.contains(r#"group = "jobs""#));
         assert!(toml.contains(r#"depot_url = "http://example.com/depot""#));
         assert!(toml.contains(r#"topology = "leader""#));
+        assert!(toml.contains(r#"replicas = 2"#));
+        assert!(toml.contains(r#"quorum_size = 5"#));
+        assert!(toml.contains(r#"leader_lease_secs = 15"#));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
+        assert!(toml.contains(r#"canary_soak_secs = 600"#));
+        assert!(toml.contains(r#"canary_batch_percent = 25"#));
+        assert!(toml.contains(r#"channel = "stable""#));
+        assert!(toml.contains(r#"update_frequency_secs = 300"#));
+        assert!(toml.contains(r#"update_jitter_secs = 60"#));
+        assert!(toml.contains(r#"maintenance_window = "22:00-02:30""#));
+        assert!(toml.contains(r#"restart_policy = "on-failure""#));
+        assert!(toml.contains(r#"restart_max = 3"#));
+        assert!(toml.contains(r#"restart_window_secs = 120"#));
+        assert!(toml.contains(r#"restart_backoff_secs = 2"#));
+        assert!(toml.contains(r#"restart_backoff_max_secs = 30"#));
+        assert!(toml.contains(r#"health_check_interval_secs = 10"#));
+        assert!(toml.contains(r#"health_check_timeout_secs = 5"#));
+        assert!(toml.contains(r#"health_check_failure_threshold = 3"#));
+        assert!(toml.contains(r#"shutdown_timeout_secs = 20"#));
+        assert!(toml.contains(r#"cpu_shares = 512"#));
+        assert!(toml.contains(r#"memory_limit_mb = 256"#));
+        assert!(toml.contains(r#"pids_max = 100"#));
+        assert!(toml.contains(r#"open_files_max = 4096"#));
+        assert!(toml.contains(r#"isolate = true"#));
+        assert!(toml.contains(r#"isolate_network = true"#));
+        assert!(toml.contains(r#"log_to_file = true"#));
+        assert!(toml.contains(r#"log_format = "json""#));
+        assert!(toml.contains(r#"log_max_mb = 20"#));
+        assert!(toml.contains(r#"log_rotate_secs = 3600"#));
+        assert!(toml.contains(r#"log_keep = 3"#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
         assert!(toml.contains(r#"desired_state = "down""#));
@@ -587,6 +1213,69 @@ mod test {
         assert_eq!(String::from("hoopa.spec"), spec.file_name());
     }
 
+    #[test]
+    fn start_order_starts_bound_services_first() {
+        let mut web = ServiceSpec::default_for(PackageIdent::from_str("core/web").unwrap());
+        web.binds = vec![ServiceBind::from_str("backend:app.default").unwrap()];
+        let mut app = ServiceSpec::default_for(PackageIdent::from_str("core/app").unwrap());
+        app.binds = vec![ServiceBind::from_str("database:postgresql.default").unwrap()];
+        let db = ServiceSpec::default_for(PackageIdent::from_str("core/postgresql").unwrap());
+        let cache = ServiceSpec::default_for(PackageIdent::from_str("core/redis").unwrap());
+
+        assert_eq!(start_order(&[web, app, cache, db]), vec![2, 3, 1, 0]);
+    }
+
+    #[test]
+    fn start_order_keeps_cycles_in_order() {
+        let mut a = ServiceSpec::default_for(PackageIdent::from_str("core/a").unwrap());
+        a.binds = vec![ServiceBind::from_str("b:b.default").unwrap()];
+        let mut b = ServiceSpec::default_for(PackageIdent::from_str("core/b").unwrap());
+        b.binds = vec![ServiceBind::from_str("a:a.default").unwrap()];
+        let mut c = ServiceSpec::default_for(PackageIdent::from_str("core/c").unwrap());
+        c.binds = vec![ServiceBind::from_str("a:a.prod").unwrap()];
+
+        assert_eq!(start_order(&[a, b, c]), vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn maintenance_window_contains() {
+        let window = MaintenanceWindow::from_str("02:00-04:30").unwrap();
+
+        assert!(!window.contains(119));
+        assert!(window.contains(120));
+        assert!(window.contains(269));
+        assert!(!window.contains(270));
+        assert_eq!(window.minutes_until_open(60), 60);
+        assert_eq!(window.minutes_until_open(300), 1260);
+        assert_eq!(window.minutes_until_open(180), 0);
+    }
+
+    #[test]
+    fn maintenance_window_spans_midnight() {
+        let window = MaintenanceWindow::from_str("23:00-01:00").unwrap();
+
+        assert!(window.contains(23 * 60 + 30));
+        assert!(window.contains(30));
+        assert!(!window.contains(60));
+        assert_eq!(window.minutes_until_open(22 * 60), 60);
+        assert_eq!(window.to_string(), "23:00-01:00");
+    }
+
+    #[test]
+    fn maintenance_window_from_str_invalid() {
+        for window in &["", "02:00", "02:00-02:00", "2:0-4:00", "02:60-03:00", "a:00-b:00"] {
+            match MaintenanceWindow::from_str(window) {
+                Err(e) => {
+                    match e.err {
+                        BadMaintenanceWindow(val) => assert_eq!(*window, val),
+                        wrong => panic!("Unexpected error returned: {:?}", wrong),
+                    }
+                }
+                Ok(_) => panic!("Window '{}' should fail to parse", window),
+            }
+        }
+    }
+
     #[test]
     fn service_bind_from_str() {
         let bind_str = "name:service.group@organization";
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError};
 use std::thread;
 use std::time::Duration;
@@ -25,21 +27,31 @@ use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
 use hcore::crypto::default_cache_key_path;
 use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
-use time::{SteadyTime, Duration as TimeDuration};
+use rand::{Rng, thread_rng};
+use time::{self, SteadyTime, Duration as TimeDuration};
 
 use {PRODUCT, VERSION};
+use config::DEFAULT_UPDATE_FREQUENCY_SECS;
 use error::Result;
-use census::CensusRing;
-use manager::service::{Service, Topology, UpdateStrategy};
+use census::{CensusGroup, CensusRing};
+use manager::service::{HealthCheck, MaintenanceWindow, RollbackReason, Service,
+                       UpdateStrategy};
 
 static LOGKEY: &'static str = "SU";
-const UPDATE_STRATEGY_FREQUENCY_MS: i64 = 60_000;
+/// A canary must pass its soak period within this many times the period's length after updating,
+/// or at least `CANARY_SOAK_DEADLINE_MIN_SECS`, else the update fails.
+const CANARY_SOAK_DEADLINE_FACTOR: u64 = 3;
+const CANARY_SOAK_DEADLINE_MIN_SECS: u64 = 60;
+// Number of seconds between checks for updated packages, or 0 for the default. It is shared by
+// every worker so that a reloaded Supervisor config applies to those already running.
+static UPDATE_FREQUENCY_SECS: AtomicUsize = ATOMIC_USIZE_INIT;
 
 type UpdaterStateList = HashMap<ServiceGroup, UpdaterState>;
 
 enum UpdaterState {
     AtOnce(Receiver<PackageInstall>),
     Rolling(RollingState),
+    Canary(CanaryState),
 }
 
 enum RollingState {
@@ -59,6 +71,82 @@ enum FollowerState {
     Updating(Receiver<PackageInstall>),
 }
 
+enum CanaryState {
+    AwaitingElection,
+    InElection,
+    Canary(CanaryLeaderState),
+    Follower(CanaryFollowerState),
+}
+
+enum CanaryLeaderState {
+    Polling(Receiver<PackageInstall>),
+    /// The canary is running an updated package and must report a passing health check for the
+    /// service's soak period.
+    Soaking(Soak),
+    Waiting,
+}
+
+/// The progress of a canary through its soak period.
+struct Soak {
+    /// Length of time the canary's health check must pass for.
+    period: TimeDuration,
+    /// Time by which the canary must have passed its soak period.
+    deadline: SteadyTime,
+    /// Time at which the canary's health check started passing, if it is passing.
+    passing_since: Option<SteadyTime>,
+}
+
+#[derive(Debug, PartialEq)]
+enum SoakStatus {
+    Soaking,
+    /// The canary's health check stopped passing, so its soak period starts over.
+    Restarted,
+    Passed,
+    /// The deadline passed before the canary passed its soak period.
+    Failed,
+}
+
+impl Soak {
+    fn new(period_secs: u64, now: SteadyTime) -> Self {
+        Soak {
+            period: TimeDuration::seconds(period_secs as i64),
+            deadline: now + TimeDuration::seconds(canary_soak_deadline_secs(period_secs) as i64),
+            passing_since: None,
+        }
+    }
+
+    /// Record the canary's latest health check, returning how far it is through its soak.
+    fn check(&mut self, health_check: HealthCheck, now: SteadyTime) -> SoakStatus {
+        match health_check {
+            HealthCheck::Ok | HealthCheck::Warning => {
+                let since = *self.passing_since.get_or_insert(now);
+                if now - since >= self.period {
+                    return SoakStatus::Passed;
+                }
+            }
+            HealthCheck::Critical | HealthCheck::Unknown => {
+                if self.passing_since.take().is_some() && now < self.deadline {
+                    return SoakStatus::Restarted;
+                }
+            }
+        }
+        if now >= self.deadline {
+            SoakStatus::Failed
+        } else {
+            SoakStatus::Soaking
+        }
+    }
+}
+
+/// Canary followers hold the update election term they last observed. The canary advances the
+/// term once it has soaked successfully, releasing its followers to update. Waiting followers also
+/// hold the release the canary is soaking, if any, so that they can tell when the canary has
+/// rolled back instead.
+enum CanaryFollowerState {
+    Waiting(u64, Option<PackageIdent>),
+    Updating(Receiver<PackageInstall>),
+}
+
 pub struct ServiceUpdater {
     states: UpdaterStateList,
     butterfly: butterfly::Server,
@@ -91,14 +179,27 @@ impl ServiceUpdater {
                     .or_insert(UpdaterState::Rolling(RollingState::AwaitingElection));
                 true
             }
+            UpdateStrategy::Canary => {
+                self.states
+                    .entry(service.service_group.clone())
+                    .or_insert(UpdaterState::Canary(CanaryState::AwaitingElection));
+                true
+            }
         }
     }
 
+    /// Stop coordinating updates for the given service group.
+    pub fn remove(&mut self, service_group: &ServiceGroup) {
+        self.states.remove(service_group);
+    }
+
     pub fn check_for_updated_package(&mut self,
                                      service: &mut Service,
                                      census_ring: &CensusRing)
                                      -> bool {
         let mut updated = false;
+        // Set once the service is pinned to its release, which ends its updates.
+        let mut pinned = false;
         match self.states.get_mut(&service.service_group) {
             Some(&mut UpdaterState::AtOnce(ref mut rx)) => {
                 match rx.try_recv() {
@@ -114,31 +215,8 @@ impl ServiceUpdater {
                 *rx = Worker::new(service).start(&service.service_group, None);
             }
             Some(&mut UpdaterState::Rolling(ref mut st @ RollingState::AwaitingElection)) => {
-                if let Some(census_group) = census_ring.census_group_for(&service.service_group) {
-                    if service.topology == Topology::Leader {
-                        debug!("Rolling Update, determining proper suitability because we're in \
-                                a leader topology");
-                        match (census_group.me(), census_group.leader()) {
-                            (Some(me), Some(leader)) => {
-                                let suitability = if me == leader {
-                                    u64::min_value()
-                                } else {
-                                    u64::max_value()
-                                };
-                                self.butterfly
-                                    .start_update_election(service.service_group.clone(),
-                                                           suitability,
-                                                           0);
-                                *st = RollingState::InElection
-                            }
-                            _ => return false,
-                        }
-                    } else {
-                        debug!("Rolling update, using default suitability");
-                        self.butterfly
-                            .start_update_election(service.service_group.clone(), 0, 0);
-                        *st = RollingState::InElection;
-                    }
+                if start_update_election(&self.butterfly, service, census_ring) {
+                    *st = RollingState::InElection;
                 }
             }
             Some(&mut UpdaterState::Rolling(ref mut st @ RollingState::InElection)) => {
@@ -266,15 +344,299 @@ impl ServiceUpdater {
                     *state = FollowerState::Waiting;
                 }
             }
+            Some(&mut UpdaterState::Canary(ref mut st @ CanaryState::AwaitingElection)) => {
+                if start_update_election(&self.butterfly, service, census_ring) {
+                    *st = CanaryState::InElection;
+                }
+            }
+            Some(&mut UpdaterState::Canary(ref mut st @ CanaryState::InElection)) => {
+                if let Some(census_group) = census_ring.census_group_for(&service.service_group) {
+                    match (census_group.me(), census_group.update_leader()) {
+                        (Some(me), Some(leader)) => {
+                            if me == leader {
+                                outputln!(preamble service.service_group,
+                                          "Elected as the canary for updates");
+                                *st = CanaryState::Canary(CanaryLeaderState::Waiting);
+                            } else {
+                                debug!("We're a canary follower");
+                                let term = census_group.update_election_term();
+                                *st = CanaryState::Follower(CanaryFollowerState::Waiting(term,
+                                                                                         None));
+                            }
+                        }
+                        _ => return false,
+                    }
+                }
+            }
+            Some(&mut UpdaterState::Canary(CanaryState::Canary(ref mut state))) => {
+                let mut released = false;
+                match *state {
+                    CanaryLeaderState::Polling(ref mut rx) => {
+                        match rx.try_recv() {
+                            Ok(package) => {
+                                debug!("Canary Update, polling found a new package");
+                                service.update_package(package);
+                                updated = true;
+                            }
+                            Err(TryRecvError::Empty) => return false,
+                            Err(TryRecvError::Disconnected) => {
+                                outputln!(preamble service.service_group,
+                                    "Service Updater has died {}", "; restarting...");
+                                *rx = Worker::new(service).start(&service.service_group, None);
+                            }
+                        }
+                    }
+                    CanaryLeaderState::Soaking(ref mut soak) => {
+                        match soak.check(service.health_check(), SteadyTime::now()) {
+                            SoakStatus::Soaking => (),
+                            SoakStatus::Restarted => {
+                                outputln!(preamble service.service_group,
+                                          "Canary health check is {}; restarting soak period",
+                                          service.health_check());
+                            }
+                            SoakStatus::Passed => {
+                                outputln!(preamble service.service_group,
+                                          "Canary passed its soak period; releasing update to \
+                                          the service group");
+                                self.butterfly
+                                    .advance_update_election_term(&service.service_group);
+                                released = true;
+                            }
+                            SoakStatus::Failed => {
+                                // Rolling back returns the canary to the release its followers
+                                // run, which tells them the update was abandoned.
+                                let reason = RollbackReason::SoakDeadline(service.canary_soak_secs);
+                                if service.fail_update(reason) {
+                                    updated = true;
+                                } else {
+                                    service.pin_release();
+                                }
+                                pinned = true;
+                            }
+                        }
+                    }
+                    CanaryLeaderState::Waiting => {
+                        match census_ring.census_group_for(&service.service_group) {
+                            Some(census_group) => {
+                                if census_group
+                                       .members()
+                                       .iter()
+                                       .any(|cm| {
+                                                cm.pkg.as_ref().unwrap() !=
+                                                census_group.me().unwrap().pkg.as_ref().unwrap()
+                                            }) {
+                                    debug!("Canary still waiting for followers...");
+                                    return false;
+                                }
+                                let rx = Worker::new(service).start(&service.service_group, None);
+                                *state = CanaryLeaderState::Polling(rx);
+                            }
+                            None => {
+                                panic!("Expected census list to have service group '{}'!",
+                                       &*service.service_group)
+                            }
+                        }
+                    }
+                }
+                if updated && !pinned {
+                    // The canary is rolled back if it fails its soak, so the release it replaced
+                    // must be kept until then.
+                    let deadline_secs = canary_soak_deadline_secs(service.canary_soak_secs);
+                    service.extend_probation(Duration::from_secs(deadline_secs));
+                    *state = CanaryLeaderState::Soaking(Soak::new(service.canary_soak_secs,
+                                                                  SteadyTime::now()));
+                } else if released {
+                    *state = CanaryLeaderState::Waiting;
+                }
+            }
+            Some(&mut UpdaterState::Canary(CanaryState::Follower(ref mut state))) => {
+                let census_group = match census_ring.census_group_for(&service.service_group) {
+                    Some(census_group) => census_group,
+                    None => {
+                        panic!("Expected census list to have service group '{}'!",
+                               &*service.service_group)
+                    }
+                };
+                let mut next = None;
+                match *state {
+                    CanaryFollowerState::Waiting(ref mut term, ref mut soaking) => {
+                        let (leader, me) = match (census_group.update_leader(),
+                                                  census_group.me()) {
+                            (Some(leader), Some(me)) => (leader, me),
+                            _ => return false,
+                        };
+                        if canary_rolled_back(soaking.as_ref(),
+                                              leader.pkg.as_ref(),
+                                              me.pkg.as_ref()) {
+                            outputln!(preamble service.service_group,
+                                      "Canary rolled back its update to {}; pinning to the \
+                                      running release",
+                                      soaking.as_ref().unwrap());
+                            service.pin_release();
+                            pinned = true;
+                        } else if leader.pkg == me.pkg {
+                            debug!("We're not in an update");
+                            *term = census_group.update_election_term();
+                            *soaking = None;
+                            return false;
+                        } else {
+                            *soaking = leader.pkg.clone();
+                            if census_group.update_election_term() <= *term {
+                                debug!("We're in an update but the canary is still soaking");
+                                return false;
+                            }
+                            if !canary_batch_ready(census_group, service.canary_batch_percent) {
+                                debug!("We're in an update but it's not our batch's turn");
+                                return false;
+                            }
+                            debug!("We're in an update and it's our batch's turn");
+                            next = Some(Worker::new(service)
+                                            .start(&service.service_group, leader.pkg.clone()));
+                        }
+                    }
+                    CanaryFollowerState::Updating(ref mut rx) => {
+                        match rx.try_recv() {
+                            Ok(package) => {
+                                service.update_package(package);
+                                updated = true
+                            }
+                            Err(TryRecvError::Empty) => return false,
+                            Err(TryRecvError::Disconnected) => {
+                                outputln!(preamble service.service_group,
+                                    "Service Updater has died {}", "; restarting...");
+                                let package = census_group.update_leader().unwrap().pkg.clone();
+                                *rx = Worker::new(service).start(&service.service_group, package);
+                            }
+                        }
+                    }
+                }
+                if let Some(rx) = next {
+                    *state = CanaryFollowerState::Updating(rx);
+                } else if updated {
+                    *state = CanaryFollowerState::Waiting(census_group.update_election_term(),
+                                                          None);
+                }
+            }
             None => {}
         }
+        if pinned {
+            self.states.remove(&service.service_group);
+        }
         updated
     }
 }
 
+/// Start an update election for the service's group, returning true if one was started.
+///
+/// The suitability reported by the service's suitability hook decides which member is elected. In
+/// a leader or replicated topology, the leader declares the lowest suitability so that it is
+/// updated last.
+fn start_update_election(butterfly: &butterfly::Server,
+                         service: &Service,
+                         census_ring: &CensusRing)
+                         -> bool {
+    if let Some(census_group) = census_ring.census_group_for(&service.service_group) {
+        if service.topology.is_elected() {
+            debug!("Update election, determining proper suitability because we're in a leader \
+                    topology");
+            match (census_group.me(), census_group.leader()) {
+                (Some(me), Some(leader)) => {
+                    let suitability = if me == leader {
+                        u64::min_value()
+                    } else {
+                        cmp::max(service.suitability().unwrap_or(u64::max_value()),
+                                 u64::min_value() + 1)
+                    };
+                    butterfly.start_update_election(service.service_group.clone(), suitability, 0);
+                    true
+                }
+                _ => false,
+            }
+        } else {
+            let suitability = service.suitability().unwrap_or(u64::min_value());
+            debug!("Update election, using suitability {}", suitability);
+            butterfly.start_update_election(service.service_group.clone(), suitability, 0);
+            true
+        }
+    } else {
+        false
+    }
+}
+
+/// Determine if this member's batch of canary followers may update to the canary's package.
+///
+/// Followers are split into batches of `batch_percent` of the alive population, ordered by member
+/// id. A batch may update once every member in the batches before it runs the canary's package.
+fn canary_batch_ready(census_group: &CensusGroup, batch_percent: u8) -> bool {
+    let (leader, me) = match (census_group.update_leader(), census_group.me()) {
+        (Some(leader), Some(me)) => (leader, me),
+        _ => return false,
+    };
+    let followers = census_group.update_followers();
+    let position = match followers
+              .iter()
+              .position(|cm| cm.member_id == me.member_id) {
+        Some(position) => position,
+        None => return false,
+    };
+    let size = batch_size(followers.len(), batch_percent);
+    let batch_start = (position / size) * size;
+    followers[..batch_start]
+        .iter()
+        .all(|cm| cm.pkg == leader.pkg)
+}
+
+/// Returns the number of seconds after updating by which a canary must have passed its soak.
+fn canary_soak_deadline_secs(soak_secs: u64) -> u64 {
+    cmp::max(soak_secs.saturating_mul(CANARY_SOAK_DEADLINE_FACTOR),
+             CANARY_SOAK_DEADLINE_MIN_SECS)
+}
+
+/// Determine if the canary has rolled back the update a follower is waiting on: the canary was
+/// soaking a release other than the follower's, and is running the follower's release again.
+fn canary_rolled_back(soaking: Option<&PackageIdent>,
+                      canary: Option<&PackageIdent>,
+                      me: Option<&PackageIdent>)
+                      -> bool {
+    match soaking {
+        Some(soaking) => canary == me && me != Some(soaking),
+        None => false,
+    }
+}
+
+/// Set the number of seconds between checks for updated packages.
+pub fn set_update_frequency(secs: u64) {
+    UPDATE_FREQUENCY_SECS.store(secs as usize, Ordering::SeqCst);
+}
+
+fn update_frequency_secs() -> u64 {
+    match UPDATE_FREQUENCY_SECS.load(Ordering::SeqCst) {
+        0 => DEFAULT_UPDATE_FREQUENCY_SECS,
+        secs => secs as u64,
+    }
+}
+
+/// Returns the number of members in each batch for a population, always at least one.
+fn batch_size(population: usize, batch_percent: u8) -> usize {
+    let percent = cmp::min(cmp::max(batch_percent, 1), 100) as usize;
+    cmp::max((population * percent + 99) / 100, 1)
+}
+
+/// Sleeps until the given time has passed.
+fn wait_until(time: SteadyTime) {
+    let time_to_wait = (time - SteadyTime::now()).num_milliseconds();
+    if time_to_wait > 0 {
+        thread::sleep(Duration::from_millis(time_to_wait as u64));
+    }
+}
+
 struct Worker {
     current: PackageIdent,
     spec_ident: PackageIdent,
+    channel: Option<String>,
+    update_frequency_secs: u64,
+    update_jitter_secs: u64,
+    maintenance_window: Option<MaintenanceWindow>,
     depot: depot_client::Client,
     ui: UI,
 }
@@ -284,6 +646,10 @@ impl Worker {
         Worker {
             current: service.package().ident().clone(),
             spec_ident: service.spec_ident.clone(),
+            channel: service.channel.clone(),
+            update_frequency_secs: service.update_frequency_secs,
+            update_jitter_secs: service.update_jitter_secs,
+            maintenance_window: service.maintenance_window,
             depot: depot_client::Client::new(&service.depot_url, PRODUCT, VERSION, None).unwrap(),
             ui: UI::default(),
         }
@@ -309,34 +675,36 @@ impl Worker {
     fn run_once(&mut self, sender: SyncSender<PackageInstall>, ident: PackageIdent) {
         outputln!("Updating from {} to {}", self.current, ident);
         loop {
-            let next_check = SteadyTime::now() +
-                             TimeDuration::milliseconds(UPDATE_STRATEGY_FREQUENCY_MS);
+            let next_check = self.next_check();
             match self.install(&ident, true) {
                 Ok(package) => {
+                    self.wait_for_maintenance_window(package.ident());
                     self.current = package.ident().clone();
                     sender.send(package).expect("Main thread has gone away!");
                     break;
                 }
                 Err(e) => warn!("Failed to install updated package: {:?}", e),
             }
-            let time_to_wait = (next_check - SteadyTime::now()).num_milliseconds();
-            if time_to_wait > 0 {
-                thread::sleep(Duration::from_millis(time_to_wait as u64));
-            }
+            wait_until(next_check);
         }
     }
 
     fn run_poll(&mut self, sender: SyncSender<PackageInstall>) {
+        // Workers for a group of Supervisors which were started together would otherwise all
+        // check with the depot at the same time.
+        wait_until(SteadyTime::now() + TimeDuration::seconds(self.jitter_secs() as i64));
         loop {
-            let next_check = SteadyTime::now() +
-                             TimeDuration::milliseconds(UPDATE_STRATEGY_FREQUENCY_MS);
-            match self.depot.show_package(&self.spec_ident) {
+            let next_check = self.next_check();
+            match self.depot
+                      .show_package(&self.spec_ident,
+                                    self.channel.as_ref().map(String::as_str)) {
                 Ok(remote) => {
                     let latest: PackageIdent = remote.get_ident().clone().into();
                     if latest > self.current {
                         outputln!("Updating from {} to {}", self.current, latest);
                         match self.install(&latest, true) {
                             Ok(package) => {
+                                self.wait_for_maintenance_window(&latest);
                                 self.current = latest;
                                 sender.send(package).expect("Main thread has gone away!");
                                 break;
@@ -349,10 +717,51 @@ impl Worker {
                 }
                 Err(e) => warn!("Updater failed to get latest package: {:?}", e),
             }
-            let time_to_wait = (next_check - SteadyTime::now()).num_milliseconds();
-            if time_to_wait > 0 {
-                thread::sleep(Duration::from_millis(time_to_wait as u64));
+            wait_until(next_check);
+        }
+    }
+
+    /// Returns the time of the next check for an updated package. Checks are the service's update
+    /// frequency apart, or the Supervisor's if the service doesn't set one, plus a random jitter.
+    fn next_check(&self) -> SteadyTime {
+        let frequency = if self.update_frequency_secs > 0 {
+            self.update_frequency_secs
+        } else {
+            update_frequency_secs()
+        };
+        SteadyTime::now() + TimeDuration::seconds((frequency + self.jitter_secs()) as i64)
+    }
+
+    fn jitter_secs(&self) -> u64 {
+        if self.update_jitter_secs == 0 {
+            0
+        } else {
+            thread_rng().gen_range(0, self.update_jitter_secs + 1)
+        }
+    }
+
+    /// Blocks until the service's maintenance window is open. Returns immediately if the service
+    /// has no maintenance window.
+    fn wait_for_maintenance_window(&self, ident: &PackageIdent) {
+        let window = match self.maintenance_window {
+            Some(window) => window,
+            None => return,
+        };
+        let mut waiting = false;
+        loop {
+            let now = time::now_utc();
+            let minutes = window.minutes_until_open((now.tm_hour * 60 + now.tm_min) as u32);
+            if minutes == 0 {
+                return;
+            }
+            if !waiting {
+                outputln!("Holding {} until the maintenance window {} UTC opens",
+                          ident,
+                          window);
+                waiting = true;
             }
+            let secs = (minutes as u64 * 60).saturating_sub(now.tm_sec as u64);
+            thread::sleep(Duration::from_secs(cmp::max(secs, 1)));
         }
     }
 
@@ -383,3 +792,122 @@ impl Worker {
         Ok(pkg)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use time::{Duration, SteadyTime};
+
+    use manager::service::HealthCheck;
+    use super::{batch_size, canary_rolled_back, canary_soak_deadline_secs, Soak, SoakStatus};
+
+    fn ident(ident: &str) -> PackageIdent {
+        PackageIdent::from_str(ident).unwrap()
+    }
+
+    #[test]
+    fn batch_size_full_population() {
+        assert_eq!(batch_size(7, 100), 7);
+    }
+
+    #[test]
+    fn batch_size_rounds_up() {
+        assert_eq!(batch_size(10, 25), 3);
+        assert_eq!(batch_size(3, 50), 2);
+    }
+
+    #[test]
+    fn batch_size_is_at_least_one() {
+        assert_eq!(batch_size(0, 50), 1);
+        assert_eq!(batch_size(4, 0), 1);
+    }
+
+    #[test]
+    fn canary_rolled_back_when_canary_returns_to_our_release() {
+        let old = ident("core/redis/3.2.3/20170101000000");
+        let new = ident("core/redis/3.2.4/20170514150022");
+
+        assert!(canary_rolled_back(Some(&new), Some(&old), Some(&old)));
+    }
+
+    #[test]
+    fn canary_not_rolled_back_while_soaking_or_idle() {
+        let old = ident("core/redis/3.2.3/20170101000000");
+        let new = ident("core/redis/3.2.4/20170514150022");
+
+        assert!(!canary_rolled_back(Some(&new), Some(&new), Some(&old)));
+        assert!(!canary_rolled_back(None, Some(&old), Some(&old)));
+        // We already run the release the canary soaked.
+        assert!(!canary_rolled_back(Some(&new), Some(&new), Some(&new)));
+    }
+
+    #[test]
+    fn canary_soak_deadline_is_a_multiple_of_soak_period() {
+        assert_eq!(canary_soak_deadline_secs(300), 900);
+        assert_eq!(canary_soak_deadline_secs(0), 60);
+    }
+
+    #[test]
+    fn soak_passes_after_healthy_soak_period() {
+        let start = SteadyTime::now();
+        let mut soak = Soak::new(300, start);
+
+        assert_eq!(soak.check(HealthCheck::Unknown, start), SoakStatus::Soaking);
+        assert_eq!(soak.check(HealthCheck::Ok, start + Duration::seconds(10)),
+                   SoakStatus::Soaking);
+        assert_eq!(soak.check(HealthCheck::Warning, start + Duration::seconds(200)),
+                   SoakStatus::Soaking);
+        assert_eq!(soak.check(HealthCheck::Ok, start + Duration::seconds(310)),
+                   SoakStatus::Passed);
+    }
+
+    #[test]
+    fn soak_restarts_when_health_check_fails() {
+        let start = SteadyTime::now();
+        let mut soak = Soak::new(300, start);
+
+        assert_eq!(soak.check(HealthCheck::Ok, start), SoakStatus::Soaking);
+        assert_eq!(soak.check(HealthCheck::Critical, start + Duration::seconds(200)),
+                   SoakStatus::Restarted);
+        assert_eq!(soak.check(HealthCheck::Critical, start + Duration::seconds(210)),
+                   SoakStatus::Soaking);
+        assert_eq!(soak.check(HealthCheck::Ok, start + Duration::seconds(220)),
+                   SoakStatus::Soaking);
+        assert_eq!(soak.check(HealthCheck::Ok, start + Duration::seconds(500)),
+                   SoakStatus::Soaking);
+        assert_eq!(soak.check(HealthCheck::Ok, start + Duration::seconds(520)),
+                   SoakStatus::Passed);
+    }
+
+    #[test]
+    fn soak_fails_at_deadline() {
+        let start = SteadyTime::now();
+        let mut soak = Soak::new(300, start);
+
+        assert_eq!(soak.check(HealthCheck::Critical, start + Duration::seconds(899)),
+                   SoakStatus::Soaking);
+        assert_eq!(soak.check(HealthCheck::Critical, start + Duration::seconds(900)),
+                   SoakStatus::Failed);
+    }
+
+    #[test]
+    fn soak_fails_at_deadline_when_health_check_keeps_flapping() {
+        let start = SteadyTime::now();
+        let mut soak = Soak::new(300, start);
+
+        for secs in (0..9).map(|i| i * 100) {
+            let health_check = if secs % 200 == 0 {
+                HealthCheck::Ok
+            } else {
+                HealthCheck::Critical
+            };
+            assert!(soak.check(health_check, start + Duration::seconds(secs)) !=
+                    SoakStatus::Failed);
+        }
+        assert_eq!(soak.check(HealthCheck::Ok, start + Duration::seconds(900)),
+                   SoakStatus::Failed);
+    }
+}
@@ -24,14 +24,32 @@
 //! is turned on, then every line printed is annotated with its preamble, logkey, and precise
 //! location. Without verbose, it prints simply the preamble and logkey. Coloring does what it says
 //! on the tin :)
+//!
+//! Output from a running service can additionally be written to a rotated log file in the
+//! service's `logs` directory with a `ServiceLog`, either as the raw lines or as JSON lines.
 
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::result;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
 use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use ansi_term::Colour::{White, Cyan, Green};
+use hcore::util::deserialize_using_from_str;
+use serde;
+use serde_json;
+use time;
 
+use error::{Error, SupError};
 use PROGRAM_NAME;
 
+static LOGKEY: &'static str = "OP";
+/// File name of a service's log, within its `logs` directory.
+pub const SERVICE_LOG_FILE: &'static str = "output.log";
+
 static mut VERBOSE: AtomicBool = ATOMIC_BOOL_INIT;
 // I am sorry this isn't named the other way; I can't get an atomic initializer that defaults to
 // true. Them's the breaks.
@@ -148,9 +166,243 @@ impl<'a> fmt::Display for StructuredOutput<'a> {
     }
 }
 
+/// The output stream of a service a line was read from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+impl OutputStream {
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OutputStream::Stdout => "stdout",
+            OutputStream::Stderr => "stderr",
+        }
+    }
+}
+
+/// Format of the lines written to a service's log file.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LogFormat {
+    /// Each line exactly as the service wrote it.
+    Text,
+    /// Each line as a JSON object with its timestamp, service group and stream.
+    Json,
+}
+
+impl LogFormat {
+    fn as_str(&self) -> &str {
+        match *self {
+            LogFormat::Text => "text",
+            LogFormat::Json => "json",
+        }
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = SupError;
+
+    fn from_str(format: &str) -> result::Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(sup_error!(Error::InvalidLogFormat(String::from(format)))),
+        }
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Default for LogFormat {
+    fn default() -> LogFormat {
+        LogFormat::Text
+    }
+}
+
+impl serde::Deserialize for LogFormat {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for LogFormat {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// How a service's log file is written and rotated.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct LogConfig {
+    pub format: LogFormat,
+    /// Size in bytes at which the log is rotated, or 0 to never rotate by size.
+    pub max_bytes: u64,
+    /// Seconds after which the log is rotated, or 0 to never rotate by age.
+    pub rotate_secs: u64,
+    /// Number of rotated logs to keep.
+    pub keep: u32,
+}
+
+#[derive(Serialize)]
+struct JsonLine<'a> {
+    timestamp: String,
+    service_group: &'a str,
+    stream: &'a str,
+    message: &'a str,
+}
+
+/// A service's log file. The current log is written to `path`; when it is rotated it is renamed
+/// with the suffix `.1`, and older logs move up by one, up to `keep` logs.
+///
+/// The time the current log was started is recorded alongside it, with the suffix `.started`, so
+/// that it is rotated by age however often the service, or the Supervisor, restarts.
+#[derive(Debug)]
+pub struct ServiceLog {
+    path: PathBuf,
+    service_group: String,
+    config: LogConfig,
+    file: File,
+    size: u64,
+    started_at: SystemTime,
+}
+
+impl ServiceLog {
+    pub fn open<P: Into<PathBuf>>(path: P,
+                                  service_group: &str,
+                                  config: LogConfig)
+                                  -> io::Result<Self> {
+        let path = path.into();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        // A log which was started before its start was recorded is taken to start now.
+        let started_at = match read_started_at(&path) {
+            Some(started_at) if size > 0 => started_at,
+            _ => write_started_at(&path)?,
+        };
+        Ok(ServiceLog {
+               path: path,
+               service_group: service_group.to_string(),
+               config: config,
+               file: file,
+               size: size,
+               started_at: started_at,
+           })
+    }
+
+    /// Append a line of a service's output, without its line ending, rotating the log first if
+    /// it is due.
+    pub fn write_line(&mut self, stream: OutputStream, line: &str) -> io::Result<()> {
+        if self.needs_rotation() {
+            self.rotate()?;
+        }
+        let mut buf = match self.config.format {
+            LogFormat::Text => line.to_string(),
+            LogFormat::Json => {
+                let json = JsonLine {
+                    timestamp: time::now_utc().rfc3339().to_string(),
+                    service_group: &self.service_group,
+                    stream: stream.as_str(),
+                    message: line,
+                };
+                serde_json::to_string(&json)
+                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+            }
+        };
+        buf.push('\n');
+        self.file.write_all(buf.as_bytes())?;
+        self.size += buf.len() as u64;
+        Ok(())
+    }
+
+    fn needs_rotation(&self) -> bool {
+        (self.config.max_bytes > 0 && self.size >= self.config.max_bytes) ||
+        (self.config.rotate_secs > 0 &&
+         SystemTime::now()
+             .duration_since(self.started_at)
+             .map(|age| age >= Duration::from_secs(self.config.rotate_secs))
+             .unwrap_or(false))
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        if self.config.keep == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let oldest = rotated_path(&self.path, self.config.keep);
+            if oldest.exists() {
+                fs::remove_file(&oldest)?;
+            }
+            for n in (1..self.config.keep).rev() {
+                let from = rotated_path(&self.path, n);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, n + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+        self.file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        self.size = 0;
+        self.started_at = write_started_at(&self.path)?;
+        Ok(())
+    }
+}
+
+fn started_at_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().expect("Log path has no file name").to_os_string();
+    name.push(".started");
+    path.with_file_name(name)
+}
+
+/// Returns the recorded time a log was started, or `None` if it wasn't recorded.
+fn read_started_at(path: &Path) -> Option<SystemTime> {
+    let mut buf = String::new();
+    if File::open(started_at_path(path))
+           .and_then(|mut file| file.read_to_string(&mut buf))
+           .is_err() {
+        return None;
+    }
+    buf.trim()
+        .parse::<u64>()
+        .ok()
+        .map(|secs| UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+/// Records that a log was started now, returning the time recorded.
+fn write_started_at(path: &Path) -> io::Result<SystemTime> {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.as_secs())
+        .unwrap_or(0);
+    let mut file = File::create(started_at_path(path))?;
+    write!(file, "{}", secs)?;
+    Ok(UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+fn rotated_path(path: &Path, n: u32) -> PathBuf {
+    let mut name = path.file_name().expect("Log path has no file name").to_os_string();
+    name.push(format!(".{}", n));
+    path.with_file_name(name)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::StructuredOutput;
+    use std::fs;
+    use std::io::{Read, Write};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use serde_json;
+    use tempdir::TempDir;
+
+    use super::{started_at_path, LogConfig, LogFormat, OutputStream, ServiceLog,
+                StructuredOutput};
     use ansi_term::Colour::{White, Cyan};
 
     use PROGRAM_NAME;
@@ -188,4 +440,92 @@ mod tests {
                            Cyan.paint(progname),
                            White.bold().paint("SOT")));
     }
+
+    fn log_config(format: LogFormat, max_bytes: u64) -> LogConfig {
+        LogConfig {
+            format: format,
+            max_bytes: max_bytes,
+            rotate_secs: 0,
+            keep: 2,
+        }
+    }
+
+    fn record_started_secs_ago(path: &::std::path::Path, secs: u64) {
+        let started = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() - secs;
+        let mut file = fs::File::create(started_at_path(path)).unwrap();
+        write!(file, "{}", started).unwrap();
+    }
+
+    fn read(path: &::std::path::Path) -> String {
+        let mut buf = String::new();
+        fs::File::open(path).unwrap().read_to_string(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn service_log_writes_json_lines() {
+        let tmp = TempDir::new("service-log").unwrap();
+        let path = tmp.path().join("output.log");
+        let mut log = ServiceLog::open(&path, "redis.default", log_config(LogFormat::Json, 0))
+            .unwrap();
+        log.write_line(OutputStream::Stderr, "oh no").unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(read(&path).trim()).unwrap();
+        assert_eq!(json["service_group"].as_str(), Some("redis.default"));
+        assert_eq!(json["stream"].as_str(), Some("stderr"));
+        assert_eq!(json["message"].as_str(), Some("oh no"));
+        assert!(json["timestamp"].is_string());
+    }
+
+    #[test]
+    fn service_log_rotates_by_size() {
+        let tmp = TempDir::new("service-log").unwrap();
+        let path = tmp.path().join("output.log");
+        let mut log = ServiceLog::open(&path, "redis.default", log_config(LogFormat::Text, 4))
+            .unwrap();
+        for line in &["one", "two", "three", "four"] {
+            log.write_line(OutputStream::Stdout, line).unwrap();
+        }
+
+        assert_eq!(read(&path), "four\n");
+        assert_eq!(read(&tmp.path().join("output.log.1")), "three\n");
+        assert_eq!(read(&tmp.path().join("output.log.2")), "two\n");
+        assert!(!tmp.path().join("output.log.3").exists());
+    }
+
+    #[test]
+    fn service_log_reopened_after_its_age_rotates() {
+        let tmp = TempDir::new("service-log").unwrap();
+        let path = tmp.path().join("output.log");
+        let config = LogConfig { rotate_secs: 3600, ..log_config(LogFormat::Text, 0) };
+        {
+            let mut log = ServiceLog::open(&path, "redis.default", config.clone()).unwrap();
+            log.write_line(OutputStream::Stdout, "old").unwrap();
+        }
+        record_started_secs_ago(&path, 7200);
+
+        let mut log = ServiceLog::open(&path, "redis.default", config).unwrap();
+        log.write_line(OutputStream::Stdout, "new").unwrap();
+
+        assert_eq!(read(&path), "new\n");
+        assert_eq!(read(&tmp.path().join("output.log.1")), "old\n");
+    }
+
+    #[test]
+    fn service_log_reopened_before_its_age_does_not_rotate() {
+        let tmp = TempDir::new("service-log").unwrap();
+        let path = tmp.path().join("output.log");
+        let config = LogConfig { rotate_secs: 3600, ..log_config(LogFormat::Text, 0) };
+        {
+            let mut log = ServiceLog::open(&path, "redis.default", config.clone()).unwrap();
+            log.write_line(OutputStream::Stdout, "old").unwrap();
+        }
+        record_started_secs_ago(&path, 60);
+
+        let mut log = ServiceLog::open(&path, "redis.default", config).unwrap();
+        log.write_line(OutputStream::Stdout, "new").unwrap();
+
+        assert_eq!(read(&path), "old\nnew\n");
+        assert!(!tmp.path().join("output.log.1").exists());
+    }
 }
@@ -25,21 +25,22 @@ use std::fs::File;
 use std::io::BufReader;
 use std::io::prelude::*;
 use std::path::PathBuf;
-use std::process::Child;
 use std::result;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 use std::thread;
 
-use hcore::os::process::{HabChild, ExitStatusExt};
+use hcore::os::process::{HabChild, ExitStatusExt, Isolation, ResourceLimits};
 use hcore::util::perm::set_owner;
 use hcore::package::PackageInstall;
 use hcore::service::ServiceGroup;
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
-use time::SteadyTime;
+use time::{self, SteadyTime};
 
 use error::{Result, Error};
 use fs;
+use manager::metrics;
+use output::{LogConfig, OutputStream, ServiceLog, SERVICE_LOG_FILE};
 use util;
 
 static LOGKEY: &'static str = "SV";
@@ -50,6 +51,7 @@ pub enum ProcessState {
     Up,
     Start,
     Restart,
+    Failed,
 }
 
 impl fmt::Display for ProcessState {
@@ -59,6 +61,7 @@ impl fmt::Display for ProcessState {
             &ProcessState::Up => "up",
             &ProcessState::Start => "start",
             &ProcessState::Restart => "restart",
+            &ProcessState::Failed => "failed",
         };
         write!(f, "{}", state)
     }
@@ -107,12 +110,28 @@ pub struct Supervisor {
     pub state_entered: SteadyTime,
     pub has_started: bool,
     pub runtime_config: RuntimeConfig,
+    pub resource_limits: ResourceLimits,
+    /// Name of the process's cgroup, `<supervisor>/<service group>`, which is unique on the host.
+    pub cgroup: String,
+    /// Namespaces the process is isolated in, or `None` if it runs in the Supervisor's.
+    pub isolation: Option<Isolation>,
+    /// How the process's output is written to the service's log file, or `None` if it is not.
+    pub log_config: Option<LogConfig>,
+    /// Seconds the process is given to exit after SIGTERM before it is sent SIGKILL.
+    pub shutdown_timeout_secs: u64,
+    /// Exit code of the last process to exit, or `None` if it was terminated by a signal.
+    pub exit_code: Option<u32>,
 }
 
 impl Supervisor {
     pub fn new(package: Arc<RwLock<PackageInstall>>,
+               sup_name: &str,
                service_group: &ServiceGroup,
-               runtime_config: RuntimeConfig)
+               runtime_config: RuntimeConfig,
+               resource_limits: ResourceLimits,
+               isolation: Option<Isolation>,
+               log_config: Option<LogConfig>,
+               shutdown_timeout_secs: u64)
                -> Supervisor {
         Supervisor {
             child: None,
@@ -122,10 +141,21 @@ impl Supervisor {
             state_entered: SteadyTime::now(),
             has_started: false,
             runtime_config: runtime_config,
+            resource_limits: resource_limits,
+            cgroup: format!("{}/{}", sup_name, service_group),
+            isolation: isolation,
+            log_config: log_config,
+            shutdown_timeout_secs: shutdown_timeout_secs,
+            exit_code: None,
         }
     }
 
     fn enter_state(&mut self, state: ProcessState) {
+        metrics::set_service_up(&self.preamble,
+                                match state {
+                                    ProcessState::Up => true,
+                                    _ => false,
+                                });
         self.state = state;
         self.state_entered = SteadyTime::now();
     }
@@ -137,7 +167,7 @@ impl Supervisor {
                              SteadyTime::now() - self.state_entered);
         let healthy = match self.state {
             ProcessState::Up | ProcessState::Start | ProcessState::Restart => true,
-            ProcessState::Down => false,
+            ProcessState::Down | ProcessState::Failed => false,
         };
         (healthy, status)
     }
@@ -156,15 +186,34 @@ impl Supervisor {
                       &self.runtime_config.svc_user,
                       &self.runtime_config.svc_group);
             self.enter_state(ProcessState::Start);
-            let mut child = try!(try!(util::create_command(self.run_cmd(), &self.runtime_config))
+            self.exit_code = None;
+            let mut child = try!(try!(util::create_limited_command(self.run_cmd(),
+                                                                   &self.runtime_config,
+                                                                   &self.cgroup,
+                                                                   &self.resource_limits,
+                                                                   self.isolation.as_ref()))
                                      .spawn());
             let hab_child = try!(HabChild::from(&mut child));
             self.child = Some(hab_child);
             try!(self.create_pidfile());
-            let package_name = self.preamble.clone();
-            try!(thread::Builder::new()
-                     .name(String::from("sup-service-read"))
-                     .spawn(move || -> Result<()> { child_reader(&mut child, package_name) }));
+            let log = self.open_log();
+            if let Some(stdout) = child.stdout.take() {
+                let package_name = self.preamble.clone();
+                let log = log.clone();
+                try!(thread::Builder::new()
+                         .name(String::from("sup-service-read"))
+                         .spawn(move || -> Result<()> {
+                                    child_reader(stdout, OutputStream::Stdout, package_name, log)
+                                }));
+            }
+            if let Some(stderr) = child.stderr.take() {
+                let package_name = self.preamble.clone();
+                try!(thread::Builder::new()
+                         .name(String::from("sup-service-read-err"))
+                         .spawn(move || -> Result<()> {
+                                    child_reader(stderr, OutputStream::Stderr, package_name, log)
+                                }));
+            }
             self.enter_state(ProcessState::Up);
             self.has_started = true;
         } else {
@@ -173,12 +222,12 @@ impl Supervisor {
         Ok(())
     }
 
-    /// Send a SIGTERM to a process, wait 8 seconds, then send SIGKILL
+    /// Send a SIGTERM to a process, wait `shutdown_timeout_secs` seconds, then send SIGKILL
     pub fn stop(&mut self) -> Result<()> {
         match self.child {
             Some(ref mut child) => {
                 outputln!(preamble & self.preamble, "Stopping...");
-                let shutdown = try!(child.kill());
+                let shutdown = try!(child.kill_with_timeout(self.shutdown_timeout_secs));
                 outputln!("{} - Shutdown method: {}", self.preamble, shutdown);
             }
             None => {}
@@ -203,6 +252,20 @@ impl Supervisor {
         }
     }
 
+    pub fn is_failed(&self) -> bool {
+        if let ProcessState::Failed = self.state {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Mark a process which has exited as one which will not be restarted.
+    pub fn fail(&mut self) {
+        self.enter_state(ProcessState::Failed);
+        self.cleanup_pidfile();
+    }
+
     pub fn down(&mut self) -> Result<()> {
         self.enter_state(ProcessState::Down);
         try!(self.stop());
@@ -225,6 +288,7 @@ impl Supervisor {
                 match child.status() {
                     Ok(ref status) if status.no_status() => false,
                     Ok(ref status) => {
+                        self.exit_code = status.code();
                         if status.code().is_some() {
                             outputln!("{} - process {} died with exit code {}",
                                       self.preamble,
@@ -246,12 +310,13 @@ impl Supervisor {
             }
         };
         if changed {
+            util::release_resource_limits(&self.cgroup, &self.resource_limits);
             match self.state {
                 ProcessState::Up | ProcessState::Start | ProcessState::Restart => {
                     outputln!("{} - Service exited", self.preamble);
                     self.child = None;
                 }
-                ProcessState::Down => {
+                ProcessState::Down | ProcessState::Failed => {
                     self.enter_state(ProcessState::Down);
                     self.child = None;
                 }
@@ -259,6 +324,35 @@ impl Supervisor {
         }
     }
 
+    /// Open the service's log file, if its output is logged. Output is still printed if the log
+    /// cannot be opened.
+    fn open_log(&self) -> Option<Arc<Mutex<ServiceLog>>> {
+        let config = match self.log_config {
+            Some(ref config) => config.clone(),
+            None => return None,
+        };
+        let path = fs::svc_logs_path(self.service_name()).join(SERVICE_LOG_FILE);
+        match ServiceLog::open(&path, &self.preamble, config) {
+            Ok(log) => Some(Arc::new(Mutex::new(log))),
+            Err(err) => {
+                outputln!(preamble self.preamble,
+                          "Unable to open log file {}, {}",
+                          path.display(),
+                          err);
+                None
+            }
+        }
+    }
+
+    fn service_name(&self) -> String {
+        self.package
+            .read()
+            .expect("Package lock poisoned")
+            .ident()
+            .name
+            .clone()
+    }
+
     pub fn run_cmd(&self) -> PathBuf {
         self.service_dir().join("run")
     }
@@ -346,7 +440,10 @@ impl Serialize for Supervisor {
             Some(ref child) => Some(child.id()),
             None => None,
         };
-        let mut strukt = try!(serializer.serialize_struct("supervisor", 7));
+        // The steady clock is only meaningful within this process, so the wall clock time at
+        // which the state was entered is recorded for other processes.
+        let state_entered_at = time::get_time() - (SteadyTime::now() - self.state_entered);
+        let mut strukt = try!(serializer.serialize_struct("supervisor", 13));
         try!(strukt.serialize_field("pid", &pid));
         try!(strukt.serialize_field("package",
                                     &self.package
@@ -356,8 +453,14 @@ impl Serialize for Supervisor {
         try!(strukt.serialize_field("preamble", &self.preamble));
         try!(strukt.serialize_field("state", &self.state));
         try!(strukt.serialize_field("state_entered", &self.state_entered.to_string()));
+        try!(strukt.serialize_field("state_entered_at", &state_entered_at.sec));
         try!(strukt.serialize_field("started", &self.has_started));
         try!(strukt.serialize_field("runtime_config", &self.runtime_config));
+        try!(strukt.serialize_field("resource_limits", &self.resource_limits));
+        try!(strukt.serialize_field("isolation", &self.isolation));
+        try!(strukt.serialize_field("log_config", &self.log_config));
+        try!(strukt.serialize_field("shutdown_timeout_secs", &self.shutdown_timeout_secs));
+        try!(strukt.serialize_field("exit_code", &self.exit_code));
         strukt.end()
     }
 }
@@ -368,20 +471,29 @@ impl Drop for Supervisor {
     }
 }
 
-/// Consume output from a child process until EOF, then finish
-fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
-    let c_stdout = match child.stdout {
-        Some(ref mut s) => s,
-        None => return Err(sup_error!(Error::UnpackFailed)),
-    };
-
-    let mut reader = BufReader::new(c_stdout);
+/// Consume output from one of a child process's streams until EOF, then finish
+fn child_reader<R: Read>(output: R,
+                         stream: OutputStream,
+                         package_name: String,
+                         log: Option<Arc<Mutex<ServiceLog>>>)
+                         -> Result<()> {
+    let mut reader = BufReader::new(output);
     let mut buffer = String::new();
+    let logkey = match stream {
+        OutputStream::Stdout => "O",
+        OutputStream::Stderr => "E",
+    };
 
     while reader.read_line(&mut buffer).unwrap() > 0 {
-        let mut line = output_format!(preamble &package_name, logkey "O");
+        let mut line = output_format!(preamble &package_name, logkey logkey);
         line.push_str(&buffer);
         print!("{}", line);
+        if let Some(ref log) = log {
+            let mut log = log.lock().expect("Service log lock poisoned");
+            if let Err(err) = log.write_line(stream, buffer.trim_right_matches(&['\r', '\n'][..])) {
+                debug!("Failed to write to log file of {}, {}", package_name, err);
+            }
+        }
         buffer.clear();
     }
     debug!("child_reader exiting");
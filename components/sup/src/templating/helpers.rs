@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
+use std::iter;
 use std::str::FromStr;
 use std::string::ToString;
 use std::collections::HashMap;
@@ -19,11 +21,15 @@ use std::collections::HashMap;
 use hcore::package::{PackageIdent, Identifiable};
 use hcore::fs;
 use manager::service::Pkg;
-use handlebars::{Handlebars, Helper, Renderable, RenderContext, RenderError, Context};
+use base64;
+use handlebars::{Handlebars, Helper, HelperDef, Renderable, RenderContext, RenderError, Context,
+                 Template};
 use serde_json;
 use serde_json::map::Map;
 use toml;
 
+use util::convert;
+
 
 type RenderResult = Result<(), RenderError>;
 
@@ -36,39 +42,151 @@ pub fn each_alive(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderR
     let template =
         try!(h.template()
         .ok_or_else(|| RenderError::new("No content to render inside the helper \"eachAlive\"")));
+    for member in try!(alive_members(value)) {
+        try!(render_with(h, r, rc, template, member));
+    }
+    Ok(())
+}
+
+/// Like `eachAlive`, but renders the alive members ordered by the value found at the dotted path
+/// given as the second parameter, `member_id` if omitted. Members with equal keys keep their
+/// census order so that the rendered output is stable between census updates.
+pub fn each_alive_sorted(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let value =
+        try!(h.param(0)
+        .ok_or_else(|| RenderError::new("Param not found for helper \"eachAliveSorted\"")))
+                .value();
+    let key = match h.param(1) {
+        Some(param) => {
+            try!(param.value()
+                .as_str()
+                .ok_or_else(|| {
+                    RenderError::new("Expected a string sort key for \"eachAliveSorted\"")
+                }))
+        }
+        None => "member_id",
+    };
+    let template =
+        try!(h.template()
+        .ok_or_else(|| {
+            RenderError::new("No content to render inside the helper \"eachAliveSorted\"")
+        }));
+    let pointer = format!("/{}", key.replace(".", "/"));
+    let mut members = try!(alive_members(value));
+    members.sort_by(|a, b| compare_values(a.pointer(&pointer), b.pointer(&pointer)));
+    for member in members {
+        try!(render_with(h, r, rc, template, member));
+    }
+    Ok(())
+}
+
+/// Renders the block with the first alive member of a collection, or the `{{else}}` block if no
+/// member is alive.
+pub fn first_alive(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let value =
+        try!(h.param(0)
+                 .ok_or_else(|| RenderError::new("Param not found for helper \"firstAlive\"")))
+                .value();
+    let members = try!(alive_members(value));
+    match members.first() {
+        Some(member) => {
+            let template =
+                try!(h.template()
+                .ok_or_else(|| {
+                    RenderError::new("No content to render inside the helper \"firstAlive\"")
+                }));
+            render_with(h, r, rc, template, member)
+        }
+        None => {
+            match h.inverse() {
+                Some(template) => template.render(r, rc),
+                None => Ok(()),
+            }
+        }
+    }
+}
+
+/// Renders the number of alive members in a collection.
+pub fn member_count(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let value =
+        try!(h.param(0)
+                 .ok_or_else(|| RenderError::new("Param not found for helper \"memberCount\"")))
+                .value();
+    let count = try!(alive_members(value)).len();
+    try!(rc.writer.write(count.to_string().into_bytes().as_ref()));
+    Ok(())
+}
+
+/// Returns the members of a census member collection which are marked alive, in their original
+/// order. A missing collection has no alive members.
+fn alive_members(value: &serde_json::Value) -> Result<Vec<&serde_json::Value>, RenderError> {
     let default_array = Vec::default();
-    let value_array = value.as_array().unwrap_or(&default_array);
-    for i in 0..value_array.len() {
-        let member = try!(value_array[i]
-            .as_object()
+    let mut alive = Vec::new();
+    for value in value.as_array().unwrap_or(&default_array) {
+        let member = try!(value.as_object()
             .ok_or_else(|| {
                 RenderError::new(format!("Param value is not a valid census member.  Parameter \
                                           content is: {:?}",
-                                         value_array[i]))
+                                         value))
             }));
         if member.contains_key("alive") && member["alive"].as_bool().unwrap_or(false) {
-            debug!("Alive! {:?}", value_array[i]);
-            let mut map = HashMap::default();
-
-            // Check for those tricky people who use as |blah| inside their helpers.
-            // I'm looking at you Smith!
-            let local_context_data = match h.block_param() {
-                Some(name) => {
-                    let mut json_map = Map::new();
-                    json_map.insert(name.to_string(), value_array[i].clone());
-                    serde_json::Value::Object(json_map)
-                }
-                None => value_array[i].clone(),
-            };
-            let mut local_context = Context::wraps(&local_context_data);
-            let mut writer = rc.writer();
-            let mut local_rc = RenderContext::new(&mut local_context, &mut map, &mut writer);
-            try!(template.render(r, &mut local_rc));
+            debug!("Alive! {:?}", value);
+            alive.push(value);
         } else {
-            debug!("Dead! {:?}", value_array[i]);
+            debug!("Dead! {:?}", value);
         }
     }
-    Ok(())
+    Ok(alive)
+}
+
+/// Renders a block helper's template with the given value as its context.
+fn render_with(h: &Helper,
+               r: &Handlebars,
+               rc: &mut RenderContext,
+               template: &Template,
+               value: &serde_json::Value)
+               -> RenderResult {
+    let mut map = HashMap::default();
+
+    // Check for those tricky people who use as |blah| inside their helpers.
+    // I'm looking at you Smith!
+    let local_context_data = match h.block_param() {
+        Some(name) => {
+            let mut json_map = Map::new();
+            json_map.insert(name.to_string(), value.clone());
+            serde_json::Value::Object(json_map)
+        }
+        None => value.clone(),
+    };
+    let mut local_context = Context::wraps(&local_context_data);
+    let mut writer = rc.writer();
+    let mut local_rc = RenderContext::new(&mut local_context, &mut map, &mut writer);
+    template.render(r, &mut local_rc)
+}
+
+/// Orders two optional JSON values: missing values first, then numbers numerically and anything
+/// else by its rendered form.
+fn compare_values(a: Option<&serde_json::Value>, b: Option<&serde_json::Value>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => {
+            match (a.as_f64(), b.as_f64()) {
+                (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+                _ => render_value(a).cmp(&render_value(b)),
+            }
+        }
+    }
+}
+
+/// Renders a JSON value the way it should appear in a template: strings without their quotes
+/// and everything else in its JSON form.
+fn render_value(value: &serde_json::Value) -> String {
+    match *value {
+        serde_json::Value::String(ref s) => s.clone(),
+        ref other => other.to_string(),
+    }
 }
 
 pub fn pkg_path_for(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
@@ -101,6 +219,32 @@ pub fn pkg_path_for(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> Rende
     Ok(())
 }
 
+/// Renders a value from a service's decrypted secrets, addressed by a dotted path such as
+/// `{{secret "db.password"}}`. The secrets are held by the helper rather than the render data so
+/// that they can only be reached through it.
+pub struct Secret(serde_json::Value);
+
+impl Secret {
+    pub fn new(secrets: &toml::value::Table) -> Self {
+        Secret(convert::toml_to_json(toml::Value::Table(secrets.clone())))
+    }
+}
+
+impl HelperDef for Secret {
+    fn call(&self, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+        let param =
+            try!(h.param(0)
+            .and_then(|v| v.value().as_str())
+            .ok_or_else(|| RenderError::new("Expected a string parameter for \"secret\"")));
+        let pointer = format!("/{}", param.replace(".", "/"));
+        let value = try!(self.0
+            .pointer(&pointer)
+            .ok_or_else(|| RenderError::new(format!("Secret \"{}\" not found", param))));
+        try!(rc.writer.write(render_value(value).into_bytes().as_ref()));
+        Ok(())
+    }
+}
+
 pub fn to_uppercase(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
     let param =
         try!(h.param(0)
@@ -160,3 +304,239 @@ pub fn to_toml(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResu
     try!(rc.writer.write_all(bytes.as_ref()));
     Ok(())
 }
+
+pub fn to_yaml(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let param = try!(h.param(0)
+                         .ok_or_else(|| RenderError::new("Expected 1 parameter for \"toYaml\"")))
+            .value();
+    let mut yaml = String::new();
+    write_yaml(param, 0, &mut yaml);
+    try!(rc.writer.write(yaml.into_bytes().as_ref()));
+    Ok(())
+}
+
+/// Writes a JSON value as block style YAML, indenting nested collections by two spaces.
+fn write_yaml(value: &serde_json::Value, indent: usize, out: &mut String) {
+    let padding = iter::repeat(" ").take(indent).collect::<String>();
+    match *value {
+        serde_json::Value::Object(ref map) if !map.is_empty() => {
+            for (key, value) in map {
+                out.push_str(&padding);
+                out.push_str(&yaml_string(key));
+                out.push(':');
+                if is_yaml_block(value) {
+                    out.push('\n');
+                    write_yaml(value, indent + 2, out);
+                } else {
+                    out.push(' ');
+                    out.push_str(&yaml_scalar(value));
+                    out.push('\n');
+                }
+            }
+        }
+        serde_json::Value::Array(ref items) if !items.is_empty() => {
+            for item in items {
+                out.push_str(&padding);
+                out.push('-');
+                match *item {
+                    serde_json::Value::Object(_) if is_yaml_block(item) => {
+                        // The first key of a mapping shares its line with the dash.
+                        let mut nested = String::new();
+                        write_yaml(item, indent + 2, &mut nested);
+                        out.push(' ');
+                        out.push_str(&nested[indent + 2..]);
+                    }
+                    _ if is_yaml_block(item) => {
+                        out.push('\n');
+                        write_yaml(item, indent + 2, out);
+                    }
+                    _ => {
+                        out.push(' ');
+                        out.push_str(&yaml_scalar(item));
+                        out.push('\n');
+                    }
+                }
+            }
+        }
+        ref other => {
+            out.push_str(&padding);
+            out.push_str(&yaml_scalar(other));
+            out.push('\n');
+        }
+    }
+}
+
+fn is_yaml_block(value: &serde_json::Value) -> bool {
+    match *value {
+        serde_json::Value::Object(ref map) => !map.is_empty(),
+        serde_json::Value::Array(ref items) => !items.is_empty(),
+        _ => false,
+    }
+}
+
+fn yaml_scalar(value: &serde_json::Value) -> String {
+    match *value {
+        serde_json::Value::Null => "null".to_string(),
+        serde_json::Value::String(ref s) => yaml_string(s),
+        serde_json::Value::Object(_) => "{}".to_string(),
+        serde_json::Value::Array(_) => "[]".to_string(),
+        ref other => other.to_string(),
+    }
+}
+
+/// Returns a string as a plain YAML scalar, or double quoted if a YAML parser would otherwise
+/// read it as something other than the same string.
+fn yaml_string(s: &str) -> String {
+    let reserved = ["", "~", "null", "Null", "NULL", "true", "True", "TRUE", "false", "False",
+                    "FALSE", "yes", "Yes", "YES", "no", "No", "NO", "on", "On", "ON", "off",
+                    "Off", "OFF"];
+    let needs_quotes = reserved.contains(&s) || s.parse::<f64>().is_ok() ||
+                       s.trim() != s ||
+                       s.starts_with(|c: char| "-?:,[]{}#&*!|>'\"%@`".contains(c)) ||
+                       s.contains(": ") || s.contains(" #") ||
+                       s.chars().any(|c| c.is_control());
+    if needs_quotes {
+        serde_json::Value::String(s.to_string()).to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+pub fn base64_encode(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let param =
+        try!(h.param(0)
+        .and_then(|v| v.value().as_str())
+        .ok_or_else(|| RenderError::new("Expected a string parameter for \"base64Encode\"")));
+    try!(rc.writer
+             .write(base64::encode(param.as_bytes()).into_bytes().as_ref()));
+    Ok(())
+}
+
+pub fn base64_decode(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let param =
+        try!(h.param(0)
+        .and_then(|v| v.value().as_str())
+        .ok_or_else(|| RenderError::new("Expected a string parameter for \"base64Decode\"")));
+    let bytes = try!(base64::decode(param.trim()).map_err(|e| {
+            RenderError::new(format!("Can't decode base64 parameter for \"base64Decode\", {}", e))
+        }));
+    try!(rc.writer.write_all(bytes.as_ref()));
+    Ok(())
+}
+
+/// Renders the first parameter, or the second if the first is missing or null.
+pub fn default(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let null = serde_json::Value::Null;
+    let value = h.param(0).map(|v| v.value()).unwrap_or(&null);
+    let rendered = if value.is_null() {
+        try!(h.param(1)
+                 .ok_or_else(|| RenderError::new("Expected 2 parameters for \"default\"")))
+                .value()
+    } else {
+        value
+    };
+    try!(rc.writer.write(render_value(rendered).into_bytes().as_ref()));
+    Ok(())
+}
+
+pub fn add(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    arithmetic(h, rc, "add", |a, b| a.checked_add(b), |a, b| Some(a + b))
+}
+
+pub fn sub(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    arithmetic(h, rc, "sub", |a, b| a.checked_sub(b), |a, b| Some(a - b))
+}
+
+pub fn mul(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    arithmetic(h, rc, "mul", |a, b| a.checked_mul(b), |a, b| Some(a * b))
+}
+
+pub fn div(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    arithmetic(h,
+               rc,
+               "div",
+               |a, b| a.checked_div(b),
+               |a, b| if b == 0.0 { None } else { Some(a / b) })
+}
+
+pub fn rem(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    arithmetic(h,
+               rc,
+               "mod",
+               |a, b| a.checked_rem(b),
+               |a, b| if b == 0.0 { None } else { Some(a % b) })
+}
+
+/// Applies an arithmetic operation to the helper's two numeric parameters. Integers stay integers
+/// unless either parameter is a float.
+fn arithmetic<I, F>(h: &Helper,
+                    rc: &mut RenderContext,
+                    name: &str,
+                    int_op: I,
+                    float_op: F)
+                    -> RenderResult
+    where I: Fn(i64, i64) -> Option<i64>,
+          F: Fn(f64, f64) -> Option<f64>
+{
+    let mut params = Vec::with_capacity(2);
+    for i in 0..2 {
+        let param = try!(h.param(i)
+            .map(|v| v.value())
+            .and_then(|v| if v.is_number() { Some(v) } else { None })
+            .ok_or_else(|| {
+                RenderError::new(format!("Expected 2 number parameters for \"{}\"", name))
+            }));
+        params.push(param);
+    }
+    let result = match (params[0].as_i64(), params[1].as_i64()) {
+        (Some(a), Some(b)) => int_op(a, b).map(|n| n.to_string()),
+        _ => {
+            float_op(params[0].as_f64().unwrap(), params[1].as_f64().unwrap())
+                .map(|n| n.to_string())
+        }
+    };
+    let result = try!(result.ok_or_else(|| {
+            RenderError::new(format!("Overflow or division by zero in \"{}\"", name))
+        }));
+    try!(rc.writer.write(result.into_bytes().as_ref()));
+    Ok(())
+}
+
+pub fn str_join(h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let param =
+        try!(h.param(0)
+        .and_then(|v| v.value().as_array())
+        .ok_or_else(|| RenderError::new("Expected an array and a string for \"strJoin\"")));
+    let separator =
+        try!(h.param(1)
+        .and_then(|v| v.value().as_str())
+        .ok_or_else(|| RenderError::new("Expected an array and a string for \"strJoin\"")));
+    let joined = param.iter()
+        .map(render_value)
+        .collect::<Vec<String>>()
+        .join(separator);
+    try!(rc.writer.write(joined.into_bytes().as_ref()));
+    Ok(())
+}
+
+/// Splits a string by a separator and renders the block once for each piece.
+pub fn str_split(h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult {
+    let param =
+        try!(h.param(0)
+        .and_then(|v| v.value().as_str())
+        .ok_or_else(|| RenderError::new("Expected 2 string parameters for \"strSplit\"")));
+    let separator =
+        try!(h.param(1)
+        .and_then(|v| v.value().as_str())
+        .ok_or_else(|| RenderError::new("Expected 2 string parameters for \"strSplit\"")));
+    let template =
+        try!(h.template()
+        .ok_or_else(|| RenderError::new("No content to render inside the helper \"strSplit\"")));
+    if param.is_empty() {
+        return Ok(());
+    }
+    for piece in param.split(separator) {
+        try!(render_with(h, r, rc, template, &serde_json::Value::String(piece.to_string())));
+    }
+    Ok(())
+}
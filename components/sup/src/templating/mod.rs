@@ -24,11 +24,25 @@ impl Template {
         let mut handlebars = Handlebars::new();
         handlebars.register_helper("pkgPathFor", Box::new(helpers::pkg_path_for));
         handlebars.register_helper("eachAlive", Box::new(helpers::each_alive));
+        handlebars.register_helper("eachAliveSorted", Box::new(helpers::each_alive_sorted));
+        handlebars.register_helper("firstAlive", Box::new(helpers::first_alive));
+        handlebars.register_helper("memberCount", Box::new(helpers::member_count));
         handlebars.register_helper("toUppercase", Box::new(helpers::to_uppercase));
         handlebars.register_helper("toLowercase", Box::new(helpers::to_lowercase));
         handlebars.register_helper("strReplace", Box::new(helpers::str_replace));
+        handlebars.register_helper("strJoin", Box::new(helpers::str_join));
+        handlebars.register_helper("strSplit", Box::new(helpers::str_split));
+        handlebars.register_helper("default", Box::new(helpers::default));
+        handlebars.register_helper("add", Box::new(helpers::add));
+        handlebars.register_helper("sub", Box::new(helpers::sub));
+        handlebars.register_helper("mul", Box::new(helpers::mul));
+        handlebars.register_helper("div", Box::new(helpers::div));
+        handlebars.register_helper("mod", Box::new(helpers::rem));
+        handlebars.register_helper("base64Encode", Box::new(helpers::base64_encode));
+        handlebars.register_helper("base64Decode", Box::new(helpers::base64_decode));
         handlebars.register_helper("toJson", Box::new(helpers::to_json));
         handlebars.register_helper("toToml", Box::new(helpers::to_toml));
+        handlebars.register_helper("toYaml", Box::new(helpers::to_yaml));
 
         // JW TODO: remove these at a later date, these are an alias for toJson/toToml
         handlebars.register_helper("json", Box::new(helpers::to_json));
@@ -152,6 +166,101 @@ mod test {
         assert_eq!(rendered, "this is new".to_string());
     }
 
+    #[test]
+    fn secret_helper() {
+        let content = "{{secret \"db.password\"}}:{{secret \"port\"}}".to_string();
+        let secrets = toml::de::from_str("port = 5432\n[db]\npassword = \"hunter2\"").unwrap();
+        let mut template = Template::new();
+        template.register_helper("secret", Box::new(helpers::Secret::new(&secrets)));
+        template.register_template_string("t", content).unwrap();
+
+        let m: BTreeMap<String, String> = BTreeMap::new();
+        let rendered = template.render("t", &m).unwrap();
+        assert_eq!(rendered, "hunter2:5432".to_string());
+    }
+
+    #[test]
+    fn secret_helper_missing_secret() {
+        let content = "{{secret \"db.password\"}}".to_string();
+        let mut template = Template::new();
+        template.register_helper("secret",
+                                 Box::new(helpers::Secret::new(&toml::value::Table::new())));
+        template.register_template_string("t", content).unwrap();
+
+        let m: BTreeMap<String, String> = BTreeMap::new();
+        assert!(template.render("t", &m).is_err());
+    }
+
+    #[test]
+    fn to_yaml_helper() {
+        let content = "{{toYaml x}}".to_string();
+        let mut template = Template::new();
+        template.register_template_string("t", content).unwrap();
+
+        let data: serde_json::Value =
+            serde_json::from_str(r#"{"x": {"port": 80, "name": "yes", "empty": [],
+                                           "servers": [{"host": "a", "tags": ["b", "c"]}]}}"#)
+                .unwrap();
+        let rendered = template.render("t", &data).unwrap();
+        assert_eq!(rendered,
+                   "empty: []\nname: \"yes\"\nport: 80\nservers:\n  - host: a\n    tags:\n      \
+                    - b\n      - c\n"
+                           .to_string());
+    }
+
+    #[test]
+    fn base64_helpers() {
+        let content = "{{base64Encode var}}:{{base64Decode (base64Encode var)}}".to_string();
+        let mut template = Template::new();
+        template.register_template_string("t", content).unwrap();
+
+        let mut m: BTreeMap<String, String> = BTreeMap::new();
+        m.insert("var".into(), "hello".into());
+        let rendered = template.render("t", &m).unwrap();
+        assert_eq!(rendered, "aGVsbG8=:hello".to_string());
+    }
+
+    #[test]
+    fn default_helper() {
+        let content = "{{default var \"x\"}}:{{default missing \"y\"}}".to_string();
+        let mut template = Template::new();
+        template.register_template_string("t", content).unwrap();
+
+        let mut m: BTreeMap<String, String> = BTreeMap::new();
+        m.insert("var".into(), "value".into());
+        let rendered = template.render("t", &m).unwrap();
+        assert_eq!(rendered, "value:y".to_string());
+    }
+
+    #[test]
+    fn arithmetic_helpers() {
+        let content = "{{add a 2}} {{sub a 2}} {{mul a 2}} {{div a 2}} {{mod a 2}} {{mul a 0.5}}"
+            .to_string();
+        let mut template = Template::new();
+        template.register_template_string("t", content).unwrap();
+
+        let mut m: BTreeMap<String, i64> = BTreeMap::new();
+        m.insert("a".into(), 7);
+        let rendered = template.render("t", &m).unwrap();
+        assert_eq!(rendered, "9 5 14 3 1 3.5".to_string());
+
+        template.register_template_string("z", "{{div a 0}}".to_string()).unwrap();
+        assert!(template.render("z", &m).is_err());
+    }
+
+    #[test]
+    fn str_join_and_split_helpers() {
+        let content = "{{strJoin hosts \",\"}};{{#strSplit list \",\"}}[{{this}}]{{/strSplit}}"
+            .to_string();
+        let mut template = Template::new();
+        template.register_template_string("t", content).unwrap();
+
+        let data: serde_json::Value =
+            serde_json::from_str(r#"{"hosts": ["a", "b", 3], "list": "x,y"}"#).unwrap();
+        let rendered = template.render("t", &data).unwrap();
+        assert_eq!(rendered, "a,b,3;[x][y]".to_string());
+    }
+
     pub fn root() -> PathBuf {
         PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests")
     }
@@ -267,4 +376,43 @@ mod test {
 
         assert_eq!(each_alive_render, each_if_render);
     }
+
+    #[test]
+    fn each_alive_sorted_helper() {
+        let content = "{{#eachAliveSorted svc.members}}{{member_id}} {{/eachAliveSorted}}"
+            .to_string();
+        let mut template = Template::new();
+        template.register_template_string("t", content).unwrap();
+
+        let data = service_config_json_from_toml_file("multiple_supervisors_config.toml");
+        let rendered = template.render("t", &data).unwrap();
+        assert_eq!(rendered,
+                   "8325c1d9c12543dc83a99f196500f44c b162bfc10cf54eb4bce93689a8023eb9 ");
+    }
+
+    #[test]
+    fn first_alive_helper() {
+        let content = "{{#firstAlive svc.members}}{{member_id}}{{else}}none{{/firstAlive}}"
+            .to_string();
+        let mut template = Template::new();
+        template.register_template_string("t", content).unwrap();
+
+        let data = service_config_json_from_toml_file("multiple_supervisors_config.toml");
+        let rendered = template.render("t", &data).unwrap();
+        assert_eq!(rendered, "b162bfc10cf54eb4bce93689a8023eb9");
+
+        let m: BTreeMap<String, String> = BTreeMap::new();
+        assert_eq!(template.render("t", &m).unwrap(), "none");
+    }
+
+    #[test]
+    fn member_count_helper() {
+        let content = "{{memberCount svc.members}}".to_string();
+        let mut template = Template::new();
+        template.register_template_string("t", content).unwrap();
+
+        let data = service_config_json_from_toml_file("multiple_supervisors_config.toml");
+        let rendered = template.render("t", &data).unwrap();
+        assert_eq!(rendered, "2");
+    }
 }
@@ -24,6 +24,7 @@ use std::str::FromStr;
 use std::process::{Command, Stdio};
 
 use hcore::os;
+use hcore::os::process::{Isolation, ResourceLimits};
 use time;
 
 use error::{Error, Result};
@@ -70,24 +71,82 @@ pub fn parse_ip_port_with_defaults(s: Option<&str>,
 
 #[cfg(any(target_os="linux", target_os="macos"))]
 pub fn create_command<S: AsRef<OsStr>>(path: S, cfg: &RuntimeConfig) -> Result<Command> {
-    let mut cmd = Command::new(path);
     use std::os::unix::process::CommandExt;
+    let (uid, gid) = svc_ids(cfg)?;
+    let mut cmd = service_command(path, cfg);
+    cmd.uid(uid).gid(gid);
+    Ok(cmd)
+}
+
+/// Create a command whose process is held to the given resource limits and, if given, isolated
+/// in its own namespaces. `name` identifies the process's cgroup.
+#[cfg(any(target_os="linux", target_os="macos"))]
+pub fn create_limited_command<S: AsRef<OsStr>>(path: S,
+                                               cfg: &RuntimeConfig,
+                                               name: &str,
+                                               limits: &ResourceLimits,
+                                               isolation: Option<&Isolation>)
+                                               -> Result<Command> {
+    if limits.is_empty() && isolation.is_none() {
+        return create_command(path, cfg);
+    }
+    let (uid, gid) = svc_ids(cfg)?;
+    // The user and group are switched to by `apply_resource_limits`, once the limits are set.
+    let mut cmd = service_command(path, cfg);
+    // The namespaces must be entered while the process still runs as root, before the resource
+    // limits switch it to the service's user and group.
+    if let Some(isolation) = isolation {
+        apply_isolation(&mut cmd, isolation)?;
+    }
+    os::process::apply_resource_limits(&mut cmd, name, limits, uid, gid)?;
+    Ok(cmd)
+}
+
+/// Create a command with the service's environment and its output piped back to us.
+#[cfg(any(target_os="linux", target_os="macos"))]
+fn service_command<S: AsRef<OsStr>>(path: S, cfg: &RuntimeConfig) -> Command {
+    let mut cmd = Command::new(path);
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    for (key, val) in &cfg.env_vars {
+        cmd.env(key, val);
+    }
+    cmd
+}
+
+/// Look up the ids of the user and group the service runs as.
+#[cfg(any(target_os="linux", target_os="macos"))]
+fn svc_ids(cfg: &RuntimeConfig) -> Result<(u32, u32)> {
     let uid = os::users::get_uid_by_name(&cfg.svc_user)
         .ok_or(sup_error!(Error::Permissions(format!("No uid for user '{}' could be found",
                                                      &cfg.svc_user))))?;
     let gid = os::users::get_gid_by_name(&cfg.svc_group)
         .ok_or(sup_error!(Error::Permissions(format!("No gid for group '{}' could be found",
                                                      &cfg.svc_group))))?;
+    Ok((uid, gid))
+}
 
-    cmd.stdin(Stdio::null())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .uid(uid)
-        .gid(gid);
-    for (key, val) in &cfg.env_vars {
-        cmd.env(key, val);
+#[cfg(target_os = "linux")]
+fn apply_isolation(cmd: &mut Command, isolation: &Isolation) -> Result<()> {
+    os::process::apply_isolation(cmd, isolation)?;
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn apply_isolation(_cmd: &mut Command, _isolation: &Isolation) -> Result<()> {
+    Err(sup_error!(Error::IsolationNotSupported))
+}
+
+/// Remove the cgroup of a limited process after it has exited.
+#[cfg(any(target_os="linux", target_os="macos"))]
+pub fn release_resource_limits(name: &str, limits: &ResourceLimits) {
+    if !limits.needs_cgroup() {
+        return;
+    }
+    if let Err(err) = os::process::release_resource_limits(name) {
+        debug!("Failed to remove cgroup for {}, {}", name, err);
     }
-    Ok(cmd)
 }
 
 #[cfg(target_os = "windows")]
@@ -105,6 +164,26 @@ pub fn create_command<S: AsRef<OsStr>>(path: S, cfg: &RuntimeConfig) -> Result<C
     Ok(cmd)
 }
 
+#[cfg(target_os = "windows")]
+pub fn create_limited_command<S: AsRef<OsStr>>(path: S,
+                                               cfg: &RuntimeConfig,
+                                               name: &str,
+                                               limits: &ResourceLimits,
+                                               isolation: Option<&Isolation>)
+                                               -> Result<Command> {
+    if !limits.is_empty() {
+        warn!("Resource limits are not supported on Windows, ignoring them for {}",
+              name);
+    }
+    if isolation.is_some() {
+        return Err(sup_error!(Error::IsolationNotSupported));
+    }
+    create_command(path, cfg)
+}
+
+#[cfg(target_os = "windows")]
+pub fn release_resource_limits(_name: &str, _limits: &ResourceLimits) {}
+
 #[cfg(test)]
 mod tests {
     use super::parse_ip_port_with_defaults;
@@ -127,4 +206,34 @@ mod tests {
         // pass in something unparseable
         assert!(parse_ip_port_with_defaults(Some("foo"), default_ip, default_port).is_err());
     }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn limited_command_applies_open_files_limit() {
+        use std::collections::HashMap;
+
+        use hcore::os::process::ResourceLimits;
+        use hcore::os::users;
+
+        use super::create_limited_command;
+        use supervisor::RuntimeConfig;
+
+        let cfg = RuntimeConfig::new(users::get_current_username().unwrap(),
+                                     users::get_current_groupname().unwrap(),
+                                     HashMap::new());
+        let limits = ResourceLimits { open_files_max: Some(64), ..Default::default() };
+        let output = create_limited_command("sh", &cfg, "limited-test", &limits, None)
+            .unwrap()
+            .arg("-c")
+            .arg("ulimit -n; id -u")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let mut lines = stdout.lines();
+        assert_eq!(lines.next(), Some("64"));
+        assert_eq!(lines.next(),
+                   Some(users::get_uid_by_name(&cfg.svc_user).unwrap().to_string().as_str()));
+    }
 }
@@ -35,6 +35,9 @@ pub fn install(ui: &mut UI, depot_url: &str, ident: &PackageIdent) -> Result<Pac
                                                                    VERSION,
                                                                    fs_root_path,
                                                                    &fs::cache_artifact_path(None),
+                                                                   None,
+                                                                   None,
+                                                                   None,
                                                                    false)?;
     Ok(PackageInstall::load(&installed_ident, Some(&fs_root_path))?)
 }
@@ -46,7 +49,7 @@ pub fn maybe_install_newer(ui: &mut UI,
     let latest_ident: PackageIdent = {
         let depot_client = Client::new(&spec.depot_url, PRODUCT, VERSION, None)?;
         depot_client
-            .show_package(&spec.ident)?
+            .show_package(&spec.ident, spec.channel.as_ref().map(String::as_str))?
             .get_ident()
             .clone()
             .into()
@@ -21,6 +21,8 @@ extern crate habitat_sup as sup;
 extern crate habitat_common as common;
 extern crate habitat_core as hcore;
 
+mod isolation;
+
 // NOTE: These are temporarily disabled while we refactor the test suite around the new supervisor
 // design. - Adam
 
@@ -0,0 +1,132 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Isolating a service's run hook in its own namespaces needs root, so these tests are ignored
+//! by default. Run them as root with `cargo test -- --ignored`.
+
+#![cfg(target_os = "linux")]
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::Write;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+use hcore::fs::{pkg_root_path, FS_ROOT_PATH};
+use hcore::os::process::ResourceLimits;
+use hcore::os::users;
+use hcore::package::{PackageIdent, PackageInstall};
+use hcore::service::ServiceGroup;
+use sup::fs::svc_path;
+use sup::manager::service::ServiceSpec;
+use sup::supervisor::{RuntimeConfig, Supervisor};
+use sup::util::create_limited_command;
+use tempdir::TempDir;
+
+fn assert_root() {
+    assert_eq!(users::get_effective_uid(),
+               0,
+               "isolating a run hook must be tested as root");
+}
+
+fn root_config() -> RuntimeConfig {
+    RuntimeConfig::new(users::root_level_account(),
+                       users::root_level_account(),
+                       HashMap::new())
+}
+
+fn isolated_spec(name: &str) -> ServiceSpec {
+    let mut spec = ServiceSpec::default_for(PackageIdent::from_str(&format!("test/{}", name))
+                                                .unwrap());
+    spec.isolate = true;
+    spec
+}
+
+fn write_hook(path: &Path, body: &str) {
+    {
+        let mut file = File::create(path).unwrap();
+        write!(file, "#!/bin/sh\n{}", body).unwrap();
+    }
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+#[ignore]
+#[test]
+fn isolated_run_hook_runs_under_init_with_read_only_packages() {
+    assert_root();
+    let pkgs = pkg_root_path(Some(&*FS_ROOT_PATH));
+    fs::create_dir_all(&pkgs).unwrap();
+    let tmpdir = TempDir::new("isolation").unwrap();
+    let hook = tmpdir.path().join("run");
+    write_hook(&hook,
+               &format!("echo $$ $PPID\n\
+                         if touch {pkgs}/isolation-test 2>/dev/null; then\n\
+                           rm -f {pkgs}/isolation-test\n\
+                           echo writable\n\
+                         else\n\
+                           echo read-only\n\
+                         fi\n",
+                        pkgs = pkgs.display()));
+    let spec = isolated_spec("isolation");
+
+    let output = create_limited_command(&hook,
+                                        &root_config(),
+                                        "isolation-test",
+                                        &spec.resource_limits(),
+                                        spec.isolation().as_ref())
+            .unwrap()
+            .output()
+            .unwrap();
+
+    assert!(output.status.success());
+    // The hook is forked by the namespace's init, which is the first process in it.
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "2 1\nread-only\n");
+}
+
+#[ignore]
+#[test]
+fn isolated_run_hook_without_sigterm_trap_stops_promptly() {
+    assert_root();
+    let spec = isolated_spec("isolation-stop");
+    let service_dir = svc_path(&spec.ident.name);
+    fs::create_dir_all(&service_dir).unwrap();
+    write_hook(&service_dir.join("run"), "sleep 300\n");
+    let package = PackageInstall::new_from_parts(spec.ident.clone(),
+                                                 PathBuf::from("/"),
+                                                 PathBuf::from("/"),
+                                                 PathBuf::from("/"));
+    let mut supervisor = Supervisor::new(Arc::new(RwLock::new(package)),
+                                         "isolation-test",
+                                         &ServiceGroup::from_str("isolation-stop.default")
+                                              .unwrap(),
+                                         root_config(),
+                                         ResourceLimits::default(),
+                                         spec.isolation(),
+                                         None,
+                                         60);
+    supervisor.start().unwrap();
+
+    let stopping = Instant::now();
+    supervisor.stop().unwrap();
+    let elapsed = stopping.elapsed();
+    fs::remove_dir_all(&service_dir).unwrap();
+
+    assert!(elapsed.as_secs() < 10,
+            "stopping took {:?}, so the hook was killed rather than terminated",
+            elapsed);
+    assert!(supervisor.child.is_none());
+}